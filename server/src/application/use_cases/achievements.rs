@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::use_cases::notification_service::NotificationService,
+    domain::{
+        entities::brawler_achievements::AddBrawlerAchievementEntity,
+        repositories::{
+            achievements::AchievementRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            achievements::{ACHIEVEMENTS, AchievementEvents, AchievementModel},
+            notification_events::NotificationEvent,
+        },
+    },
+};
+
+pub struct AchievementUseCase {
+    achievement_repository: Arc<dyn AchievementRepository>,
+    mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl AchievementUseCase {
+    pub fn new(
+        achievement_repository: Arc<dyn AchievementRepository>,
+        mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            achievement_repository,
+            mission_viewing_repository,
+            notification_service,
+        }
+    }
+
+    /// Checks the badges `event` can affect and awards any the brawler now qualifies for
+    pub async fn evaluate(
+        &self,
+        brawler_id: i32,
+        event: AchievementEvents,
+    ) -> Result<Vec<AchievementModel>> {
+        let progress = self.achievement_repository.get_progress(brawler_id).await?;
+
+        let awards: Vec<AddBrawlerAchievementEntity> = ACHIEVEMENTS
+            .iter()
+            .filter(|a| a.criteria.is_triggered_by(event) && a.criteria.is_met(&progress))
+            .map(|a| AddBrawlerAchievementEntity {
+                brawler_id,
+                achievement_code: a.code.to_string(),
+            })
+            .collect();
+
+        if awards.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let unlocked: Vec<AchievementModel> = self
+            .achievement_repository
+            .award(awards)
+            .await?
+            .into_iter()
+            .filter_map(AchievementModel::from_entity)
+            .collect();
+
+        for achievement in &unlocked {
+            self.notification_service
+                .notify(NotificationEvent::AchievementUnlocked {
+                    brawler_id,
+                    achievement: achievement.clone(),
+                })
+                .await;
+        }
+
+        Ok(unlocked)
+    }
+
+    /// Evaluates `MissionCompleted` for the chief and every crew member. One brawler failing
+    /// doesn't keep the others from their badges.
+    pub async fn evaluate_mission_completed(&self, mission_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        let crew_ids = crew
+            .iter()
+            .map(|member| member.id)
+            .filter(|id| *id != mission.chief_id);
+
+        for brawler_id in std::iter::once(mission.chief_id).chain(crew_ids) {
+            if let Err(e) = self
+                .evaluate(brawler_id, AchievementEvents::MissionCompleted)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to evaluate achievements for brawler {}: {:?}",
+                    brawler_id,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_badges(&self, brawler_id: i32) -> Result<Vec<AchievementModel>> {
+        let awarded = self
+            .achievement_repository
+            .find_by_brawler(brawler_id)
+            .await?;
+
+        Ok(awarded
+            .into_iter()
+            .filter_map(AchievementModel::from_entity)
+            .collect())
+    }
+}
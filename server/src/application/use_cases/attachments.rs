@@ -0,0 +1,132 @@
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    config::config_loader::get_storage_env,
+    domain::{
+        entities::attachments::{AddAttachmentEntity, AttachmentEntity},
+        repositories::{attachments::AttachmentRepository, blob_storage::BlobStorage},
+        value_objects::attachment_model::{ALLOWED_ATTACHMENT_TYPES, AttachmentModel},
+    },
+};
+
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+pub struct AttachmentUseCase {
+    repo: Arc<dyn AttachmentRepository>,
+    storage: Arc<dyn BlobStorage>,
+}
+
+impl AttachmentUseCase {
+    pub fn new(repo: Arc<dyn AttachmentRepository>, storage: Arc<dyn BlobStorage>) -> Self {
+        Self { repo, storage }
+    }
+
+    /// Stores the file unlinked; it becomes visible to others once sent with a comment or message
+    pub async fn upload(
+        &self,
+        uploader_id: i32,
+        file_name: Option<String>,
+        bytes: Vec<u8>,
+    ) -> Result<AttachmentModel> {
+        let max_bytes = get_storage_env()?.max_attachment_bytes;
+        if bytes.is_empty() {
+            return Err(anyhow!("The file is empty!"));
+        }
+        if bytes.len() > max_bytes {
+            return Err(anyhow!("The file is larger than {} bytes!", max_bytes));
+        }
+
+        let kind = infer::get(&bytes)
+            .filter(|kind| ALLOWED_ATTACHMENT_TYPES.contains(&kind.mime_type()))
+            .ok_or_else(|| anyhow!("This file type is not supported!"))?;
+
+        let storage_key = format!(
+            "attachments/{}/{}.{}",
+            Utc::now().format("%Y/%m"),
+            Uuid::new_v4(),
+            kind.extension()
+        );
+        let file_name = sanitize_file_name(file_name, kind.extension());
+        let size_bytes = bytes.len() as i64;
+
+        self.storage
+            .put(&storage_key, bytes, kind.mime_type())
+            .await?;
+
+        let attachment = match self
+            .repo
+            .add(AddAttachmentEntity {
+                uploader_id,
+                storage_key: storage_key.clone(),
+                file_name,
+                content_type: kind.mime_type().to_string(),
+                size_bytes,
+            })
+            .await
+        {
+            Ok(attachment) => attachment,
+            Err(e) => {
+                let _ = self.storage.delete(&storage_key).await;
+                return Err(e);
+            }
+        };
+
+        Ok(attachment.into())
+    }
+
+    /// Unauthorized callers get the same error as for a missing file
+    pub async fn download(
+        &self,
+        attachment_id: i32,
+        brawler_id: i32,
+    ) -> Result<(AttachmentEntity, Vec<u8>)> {
+        if !self.repo.can_access(attachment_id, brawler_id).await? {
+            return Err(anyhow!("Attachment not found!"));
+        }
+        let attachment = self
+            .repo
+            .find_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| anyhow!("Attachment not found!"))?;
+
+        let bytes = self.storage.get(&attachment.storage_key).await?;
+        Ok((attachment, bytes))
+    }
+
+    /// Only uploads that were never sent can be deleted, and only by their uploader
+    pub async fn delete(&self, attachment_id: i32, brawler_id: i32) -> Result<()> {
+        let attachment = self
+            .repo
+            .find_by_id(attachment_id)
+            .await?
+            .filter(|attachment| attachment.uploader_id == brawler_id)
+            .ok_or_else(|| anyhow!("Attachment not found!"))?;
+        if attachment.mission_comment_id.is_some() || attachment.private_message_id.is_some() {
+            return Err(anyhow!("This attachment was already sent!"));
+        }
+
+        self.storage.delete(&attachment.storage_key).await?;
+        self.repo.delete(attachment_id).await
+    }
+}
+
+/// Keeps only the base name of what the client sent, falling back to a generic name
+fn sanitize_file_name(file_name: Option<String>, extension: &str) -> String {
+    let name: String = file_name
+        .as_deref()
+        .and_then(|name| name.rsplit(['/', '\\']).next())
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_FILE_NAME_LENGTH)
+        .collect();
+
+    if name.trim().is_empty() {
+        format!("attachment.{}", extension)
+    } else {
+        name
+    }
+}
@@ -1,31 +1,63 @@
 use crate::{
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        repositories::{
+            achievements::AchievementRepository, blob_storage::BlobStorage,
+            brawler_stats::BrawlerStatsRepository, brawlers::BrawlerRepository,
+            mission_ratings::MissionRatingRepository,
+        },
         value_objects::{
+            achievements::AchievementModel,
             base64_img::Base64Img,
-            brawler_model::{RegisterBrawlerModel, UpdateBrawlerModel},
+            brawler_model::{BrawlerProfileModel, RegisterBrawlerModel, UpdateBrawlerModel},
+            brawler_stats_model::{BrawlerStatsModel, WeeklyActivityModel},
             mission_model::MissionModel,
             uploaded_img::UploadedImg,
         },
     },
-    infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::{
+        argon2::hash,
+        image_processing::{self, AVATAR_SIZES, ImageShape},
+        jwt::jwt_model::Passport,
+    },
 };
 use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use std::sync::Arc;
 
+/// How many recent weeks the activity chart covers
+const STATS_ACTIVITY_WEEKS: i64 = 12;
+const STATS_TOP_CATEGORIES: i64 = 3;
+const STATS_TOP_CREWMATES: i64 = 5;
+
 pub struct BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
     brawler_repository: Arc<T>,
+    mission_rating_repository: Arc<dyn MissionRatingRepository>,
+    brawler_stats_repository: Arc<dyn BrawlerStatsRepository>,
+    achievement_repository: Arc<dyn AchievementRepository>,
+    storage: Arc<dyn BlobStorage>,
 }
 
 impl<T> BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T>,
+        mission_rating_repository: Arc<dyn MissionRatingRepository>,
+        brawler_stats_repository: Arc<dyn BrawlerStatsRepository>,
+        achievement_repository: Arc<dyn AchievementRepository>,
+        storage: Arc<dyn BlobStorage>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            mission_rating_repository,
+            brawler_stats_repository,
+            achievement_repository,
+            storage,
+        }
     }
     pub async fn register(
         &self,
@@ -47,31 +79,57 @@ where
         user_id: i32,
         base64string: String,
     ) -> Result<UploadedImg> {
-        let opt = UploadImageOptions {
-            folder: Some("avatar".to_string()),
-            public_id: Some(user_id.to_string()),
-            transformation: Some("c_scale,w_256".to_string()),
-        };
+        let bytes = Base64Img::new(base64string)?.to_bytes()?;
 
-        let base64img = Base64Img::new(base64string)?;
+        let folder = format!("avatars/{}", user_id);
+        image_processing::store_variants(
+            self.storage.as_ref(),
+            bytes,
+            ImageShape::Crop(1, 1),
+            &AVATAR_SIZES,
+            &folder,
+        )
+        .await?;
 
-        let uploaded = self
-            .brawler_repository
-            .upload_base64img(user_id, base64img, opt)
+        // Every size shares the folder, so clients can swap the width in the URL;
+        // the version busts caches of the previous avatar
+        let largest = AVATAR_SIZES[AVATAR_SIZES.len() - 1];
+        let url = format!(
+            "{}?v={}",
+            UploadedImg::media_url(&image_processing::variant_key(&folder, largest)),
+            Utc::now().timestamp()
+        );
+
+        self.brawler_repository
+            .update_avatar(user_id, url.clone(), folder.clone())
             .await?;
 
-        Ok(uploaded)
+        Ok(UploadedImg::new(url, folder))
     }
 
     pub async fn get_my_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
         self.brawler_repository.get_missions(brawler_id).await
     }
 
-    pub async fn get_brawler_by_id(
-        &self,
-        brawler_id: i32,
-    ) -> Result<crate::domain::entities::brawlers::BrawlerEntity> {
-        self.brawler_repository.find_by_id(brawler_id).await
+    pub async fn get_brawler_by_id(&self, brawler_id: i32) -> Result<BrawlerProfileModel> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        let reputation = self
+            .mission_rating_repository
+            .get_reputation(brawler_id)
+            .await?;
+        let badges = self
+            .achievement_repository
+            .find_by_brawler(brawler_id)
+            .await?
+            .into_iter()
+            .filter_map(AchievementModel::from_entity)
+            .collect();
+
+        Ok(BrawlerProfileModel {
+            brawler,
+            reputation,
+            badges,
+        })
     }
 
     pub async fn update_profile(
@@ -83,4 +141,88 @@ where
             .update_profile(brawler_id, model)
             .await
     }
+
+    pub async fn get_stats(&self, brawler_id: i32) -> Result<BrawlerStatsModel> {
+        // Make sure the brawler exists instead of answering with all-zero stats
+        self.brawler_repository.find_by_id(brawler_id).await?;
+
+        let summary = self
+            .brawler_stats_repository
+            .get_summary(brawler_id)
+            .await?;
+        let favourite_categories = self
+            .brawler_stats_repository
+            .get_top_categories(brawler_id, STATS_TOP_CATEGORIES)
+            .await?;
+        let active_weeks = self
+            .brawler_stats_repository
+            .get_weekly_activity(brawler_id)
+            .await?;
+        let top_crewmates = self
+            .brawler_stats_repository
+            .get_top_crewmates(brawler_id, STATS_TOP_CREWMATES)
+            .await?;
+
+        let finished = summary.completed_count + summary.failed_count;
+        let completion_rate =
+            (finished > 0).then(|| summary.completed_count as f64 / finished as f64);
+
+        let this_week = week_start(Utc::now().date_naive());
+        let (current_streak_weeks, longest_streak_weeks) = streaks(&active_weeks, this_week);
+
+        // Fill in empty weeks so the chart always has STATS_ACTIVITY_WEEKS points
+        let weekly_activity = (0..STATS_ACTIVITY_WEEKS)
+            .rev()
+            .map(|weeks_ago| {
+                let week = this_week - Duration::weeks(weeks_ago);
+                WeeklyActivityModel {
+                    week_start: week,
+                    mission_count: active_weeks
+                        .iter()
+                        .find(|w| w.week_start == week)
+                        .map(|w| w.mission_count)
+                        .unwrap_or(0),
+                }
+            })
+            .collect();
+
+        Ok(BrawlerStatsModel {
+            brawler_id,
+            summary,
+            completion_rate,
+            favourite_categories,
+            weekly_activity,
+            current_streak_weeks,
+            longest_streak_weeks,
+            top_crewmates,
+        })
+    }
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Returns (current, longest) runs of consecutive active weeks.
+/// The current streak is still alive if the last active week is this week or the one before.
+fn streaks(active_weeks: &[WeeklyActivityModel], this_week: NaiveDate) -> (i64, i64) {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+
+    for week in active_weeks {
+        run = match previous {
+            Some(prev) if week.week_start - prev == Duration::weeks(1) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(week.week_start);
+    }
+
+    let current = match previous {
+        Some(last) if this_week - last <= Duration::weeks(1) => run,
+        _ => 0,
+    };
+
+    (current, longest)
 }
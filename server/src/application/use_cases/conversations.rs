@@ -0,0 +1,334 @@
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::privacy::PrivacyUseCase,
+    domain::{
+        entities::conversations::{ConversationEntity, ConversationParticipantEntity},
+        repositories::{conversations::ConversationRepository, privacy::PrivacyRepository},
+        value_objects::{
+            conversation_model::{
+                ConversationDetailModel, ConversationMessageModel, ConversationSummaryModel,
+            },
+            conversation_roles::ConversationRoles,
+        },
+    },
+};
+
+/// Largest group, owner included
+pub const MAX_GROUP_PARTICIPANTS: usize = 50;
+const MAX_CONVERSATION_NAME_LENGTH: usize = 100;
+
+pub struct ConversationUseCase {
+    repo: Arc<dyn ConversationRepository>,
+    privacy_repo: Arc<dyn PrivacyRepository>,
+    privacy_use_case: Arc<PrivacyUseCase>,
+}
+
+impl ConversationUseCase {
+    pub fn new(
+        repo: Arc<dyn ConversationRepository>,
+        privacy_repo: Arc<dyn PrivacyRepository>,
+        privacy_use_case: Arc<PrivacyUseCase>,
+    ) -> Self {
+        Self {
+            repo,
+            privacy_repo,
+            privacy_use_case,
+        }
+    }
+
+    pub async fn create_group(
+        &self,
+        creator_id: i32,
+        name: String,
+        participant_ids: Vec<i32>,
+    ) -> Result<ConversationDetailModel> {
+        let name = validate_name(&name)?;
+
+        let mut invited: Vec<i32> = Vec::new();
+        for id in participant_ids {
+            if id != creator_id && !invited.contains(&id) {
+                invited.push(id);
+            }
+        }
+        if invited.is_empty() {
+            return Err(anyhow!("A group needs at least one other participant!"));
+        }
+        if invited.len() + 1 > MAX_GROUP_PARTICIPANTS {
+            return Err(anyhow!(
+                "A group can have at most {} participants!",
+                MAX_GROUP_PARTICIPANTS
+            ));
+        }
+        self.ensure_not_blocked(creator_id, &invited).await?;
+
+        let conversation = self.repo.create_group(creator_id, name, invited).await?;
+        self.get_detail(conversation.id, creator_id).await
+    }
+
+    pub async fn list(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>> {
+        self.repo.list_for_brawler(brawler_id).await
+    }
+
+    pub async fn get_detail(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<ConversationDetailModel> {
+        let (conversation, _) = self
+            .load_as_participant(conversation_id, brawler_id)
+            .await?;
+        let participants = self.repo.get_participants(conversation_id).await?;
+
+        Ok(ConversationDetailModel {
+            conversation,
+            participants,
+        })
+    }
+
+    pub async fn participant_ids(&self, conversation_id: i32) -> Result<Vec<i32>> {
+        self.repo.get_participant_ids(conversation_id).await
+    }
+
+    pub async fn rename(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        name: String,
+    ) -> Result<ConversationEntity> {
+        let name = validate_name(&name)?;
+        self.load_group_as_manager(conversation_id, brawler_id)
+            .await?;
+
+        self.repo.rename(conversation_id, name).await
+    }
+
+    /// Returns the brawlers that were actually added
+    pub async fn add_participants(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        brawler_ids: Vec<i32>,
+    ) -> Result<Vec<i32>> {
+        self.load_group_as_manager(conversation_id, brawler_id)
+            .await?;
+
+        let current = self.repo.get_participant_ids(conversation_id).await?;
+        let mut invited: Vec<i32> = Vec::new();
+        for id in brawler_ids {
+            if !current.contains(&id) && !invited.contains(&id) {
+                invited.push(id);
+            }
+        }
+        if invited.is_empty() {
+            return Ok(Vec::new());
+        }
+        if current.len() + invited.len() > MAX_GROUP_PARTICIPANTS {
+            return Err(anyhow!(
+                "A group can have at most {} participants!",
+                MAX_GROUP_PARTICIPANTS
+            ));
+        }
+        self.ensure_not_blocked(brawler_id, &invited).await?;
+
+        self.repo.add_participants(conversation_id, invited).await
+    }
+
+    /// Anyone may leave; owners and admins may remove members, and only the owner may
+    /// remove an admin. When the owner leaves, the longest-standing participant takes over.
+    pub async fn remove_participant(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        target_id: i32,
+    ) -> Result<()> {
+        let (conversation, participant) = self
+            .load_as_participant(conversation_id, brawler_id)
+            .await?;
+        if !conversation.is_group {
+            return Err(anyhow!("You can't leave a direct conversation!"));
+        }
+
+        let target_role = if target_id == brawler_id {
+            participant_role(&participant)?
+        } else {
+            let actor_role = participant_role(&participant)?;
+            let target = self
+                .repo
+                .find_participant(conversation_id, target_id)
+                .await?
+                .ok_or_else(|| anyhow!("This brawler is not in the conversation!"))?;
+            let target_role = participant_role(&target)?;
+
+            let allowed = match actor_role {
+                ConversationRoles::Owner => true,
+                ConversationRoles::Admin => target_role == ConversationRoles::Member,
+                ConversationRoles::Member => false,
+            };
+            if !allowed {
+                return Err(anyhow!(
+                    "You don't have permission to remove this participant!"
+                ));
+            }
+            target_role
+        };
+
+        self.repo
+            .remove_participant(conversation_id, target_id)
+            .await?;
+
+        if target_role == ConversationRoles::Owner
+            && let Some(successor) = self
+                .repo
+                .get_participant_ids(conversation_id)
+                .await?
+                .first()
+        {
+            self.repo
+                .set_role(conversation_id, *successor, ConversationRoles::Owner)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        content: String,
+        attachment_ids: Vec<i32>,
+    ) -> Result<ConversationMessageModel> {
+        if content.trim().is_empty() && attachment_ids.is_empty() {
+            return Err(anyhow!("Message cannot be empty!"));
+        }
+
+        let (conversation, _) = self.load_as_participant(conversation_id, sender_id).await?;
+
+        // One-to-one messages keep a receiver so the DM endpoints see them too
+        let receiver_id = if conversation.is_group {
+            None
+        } else {
+            let receiver_id = self
+                .repo
+                .get_participant_ids(conversation_id)
+                .await?
+                .into_iter()
+                .find(|id| *id != sender_id)
+                .ok_or_else(|| anyhow!("This conversation has no one to message!"))?;
+            self.privacy_use_case
+                .ensure_can_message(sender_id, receiver_id)
+                .await?;
+            Some(receiver_id)
+        };
+
+        self.repo
+            .add_message(
+                conversation_id,
+                sender_id,
+                receiver_id,
+                content,
+                attachment_ids,
+            )
+            .await
+    }
+
+    pub async fn get_messages(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<ConversationMessageModel>> {
+        self.load_as_participant(conversation_id, brawler_id)
+            .await?;
+
+        self.repo
+            .get_messages(conversation_id, brawler_id, before_id, limit)
+            .await
+    }
+
+    pub async fn mark_read(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        last_read_message_id: Option<i32>,
+    ) -> Result<Option<i32>> {
+        self.load_as_participant(conversation_id, brawler_id)
+            .await?;
+
+        self.repo
+            .mark_read(conversation_id, brawler_id, last_read_message_id)
+            .await
+    }
+
+    async fn load_as_participant(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<(ConversationEntity, ConversationParticipantEntity)> {
+        let conversation = self
+            .repo
+            .find_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| anyhow!("Conversation not found!"))?;
+        let participant = self
+            .repo
+            .find_participant(conversation_id, brawler_id)
+            .await?
+            .ok_or_else(|| anyhow!("You are not part of this conversation!"))?;
+
+        Ok((conversation, participant))
+    }
+
+    async fn load_group_as_manager(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<ConversationEntity> {
+        let (conversation, participant) = self
+            .load_as_participant(conversation_id, brawler_id)
+            .await?;
+        if !conversation.is_group {
+            return Err(anyhow!("Direct conversations can't be changed!"));
+        }
+
+        if !participant_role(&participant)?.can_manage() {
+            return Err(anyhow!("Only the owner or an admin can do this!"));
+        }
+
+        Ok(conversation)
+    }
+
+    async fn ensure_not_blocked(&self, brawler_id: i32, others: &[i32]) -> Result<()> {
+        for other in others {
+            if self
+                .privacy_repo
+                .is_blocked_between(brawler_id, *other)
+                .await?
+            {
+                return Err(anyhow!("You can't add brawler {} to a group!", other));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn participant_role(participant: &ConversationParticipantEntity) -> Result<ConversationRoles> {
+    participant.role.parse()
+}
+
+fn validate_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(anyhow!("Conversation name cannot be empty!"));
+    }
+    if name.chars().count() > MAX_CONVERSATION_NAME_LENGTH {
+        return Err(anyhow!(
+            "Conversation name can be at most {} characters!",
+            MAX_CONVERSATION_NAME_LENGTH
+        ));
+    }
+    Ok(name.to_string())
+}
@@ -1,13 +1,28 @@
-use crate::domain::{
-    entities::crew_memberships::CrewMemberShips,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::{
+        mission_media::MissionMediaUseCase, mission_transcript::MissionTranscriptUseCase,
+    },
+    domain::{
+        entities::crew_memberships::CrewMemberShips,
+        repositories::{
+            crew_operation::CrewOperationRepository, mission_ratings::MissionRatingRepository,
+            mission_viewing::MissionViewingRepository, privacy::PrivacyRepository,
+        },
+        value_objects::{
+            attendance_statuses::AttendanceStatuses, mission_model::JoinedMissionModel,
+            mission_statuses::MissionStatuses, mission_transcript_model::TranscriptArchiveReasons,
+        },
     },
-    value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
 };
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use std::sync::Arc;
 
+/// How early before `scheduled_at` crew members may check themselves in
+pub const CHECK_IN_OPENS_BEFORE_MINUTES: i64 = 30;
+/// How long after `scheduled_at` self check-in stays open
+pub const CHECK_IN_CLOSES_AFTER_MINUTES: i64 = 60;
+
 pub struct CrewOperationUseCase<T1, T2>
 where
     T1: CrewOperationRepository + Send + Sync,
@@ -15,6 +30,10 @@ where
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    mission_rating_repository: Arc<dyn MissionRatingRepository>,
+    privacy_repository: Arc<dyn PrivacyRepository>,
+    transcript_use_case: Arc<MissionTranscriptUseCase>,
+    media_use_case: Arc<MissionMediaUseCase>,
 }
 
 impl<T1, T2> CrewOperationUseCase<T1, T2>
@@ -22,10 +41,21 @@ where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        mission_rating_repository: Arc<dyn MissionRatingRepository>,
+        privacy_repository: Arc<dyn PrivacyRepository>,
+        transcript_use_case: Arc<MissionTranscriptUseCase>,
+        media_use_case: Arc<MissionMediaUseCase>,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            mission_rating_repository,
+            privacy_repository,
+            transcript_use_case,
+            media_use_case,
         }
     }
 
@@ -38,6 +68,14 @@ where
             ));
         }
 
+        if self
+            .privacy_repository
+            .is_blocked(mission.chief_id, brawler_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You can't join this mission"));
+        }
+
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
@@ -52,6 +90,19 @@ where
             return Err(anyhow::anyhow!("Mission is full"));
         }
 
+        if let Some(min_reputation) = mission.min_reputation {
+            let reputation = self
+                .mission_rating_repository
+                .get_reputation(brawler_id)
+                .await?;
+            if reputation.reputation_score < min_reputation {
+                return Err(anyhow::anyhow!(
+                    "This mission requires a reputation of at least {:.1}",
+                    min_reputation
+                ));
+            }
+        }
+
         self.crew_operation_repository
             .join(CrewMemberShips {
                 mission_id,
@@ -78,13 +129,124 @@ where
             })
             .await?;
 
+        // The last crew member out of a removed mission takes it with them, so keep the
+        // chat and clear its images like `MissionManagementUseCase::remove` does
+        if mission.deleted_at.is_some()
+            && self
+                .mission_viewing_repository
+                .crew_counting(mission_id)
+                .await?
+                == 0
+        {
+            self.transcript_use_case
+                .archive(mission_id, TranscriptArchiveReasons::MissionRemoved)
+                .await?;
+            let media_files = self.media_use_case.collect_files(mission_id).await?;
+
+            self.crew_operation_repository
+                .purge_removed_mission(mission_id)
+                .await?;
+
+            self.media_use_case.delete_files(media_files).await;
+        }
+
         Ok(())
     }
 
     /// ดึงรายการภารกิจที่ผู้ใช้เข้าร่วมอยู่ (เป็น crew member)
-    pub async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    pub async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<JoinedMissionModel>> {
         self.crew_operation_repository
             .get_my_joined_missions(brawler_id)
             .await
     }
+
+    pub async fn check_in(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        if mission.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("This mission has been removed"));
+        }
+
+        let now = Utc::now().naive_utc();
+        let check_in_condition = match mission.scheduled_at {
+            Some(scheduled_at) => {
+                let is_active_status = mission.status == MissionStatuses::Open.to_string()
+                    || mission.status == MissionStatuses::InProgress.to_string();
+                is_active_status
+                    && now >= scheduled_at - Duration::minutes(CHECK_IN_OPENS_BEFORE_MINUTES)
+                    && now <= scheduled_at + Duration::minutes(CHECK_IN_CLOSES_AFTER_MINUTES)
+            }
+            // Unscheduled missions can only be checked into once the chief starts them
+            None => mission.status == MissionStatuses::InProgress.to_string(),
+        };
+        if !check_in_condition {
+            return Err(anyhow::anyhow!("Check-in is not open for this mission"));
+        }
+
+        self.crew_operation_repository
+            .set_attendance(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                AttendanceStatuses::Present,
+            )
+            .await
+    }
+
+    pub async fn update_attendance(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        brawler_id: i32,
+        status: AttendanceStatuses,
+    ) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can update attendance!"));
+        }
+
+        // Attendance is taken once the mission is underway, either started by the chief or
+        // past its scheduled time
+        let has_started = mission.status == MissionStatuses::InProgress.to_string()
+            || mission.status == MissionStatuses::Completed.to_string()
+            || mission
+                .scheduled_at
+                .is_some_and(|scheduled_at| scheduled_at <= Utc::now().naive_utc());
+        if !has_started {
+            return Err(anyhow::anyhow!(
+                "Attendance can only be taken once the mission starts!"
+            ));
+        }
+
+        self.crew_operation_repository
+            .set_attendance(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                status,
+            )
+            .await
+    }
+
+    /// Chief closes the roll call: everyone who has not checked in becomes a no-show
+    pub async fn mark_no_shows(&self, mission_id: i32, chief_id: i32) -> Result<Vec<i32>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can mark no-shows!"));
+        }
+
+        if mission.status == MissionStatuses::Open.to_string() {
+            return Err(anyhow::anyhow!(
+                "No-shows can only be marked once the mission starts!"
+            ));
+        }
+
+        self.crew_operation_repository
+            .mark_pending_as_no_show(mission_id)
+            .await
+    }
 }
@@ -0,0 +1,198 @@
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use std::{str::FromStr, sync::Arc};
+use tracing::{info, warn};
+
+use crate::{
+    config::config_loader::get_mailer_env,
+    domain::{
+        entities::email_digest_subscriptions::UpsertEmailDigestSubscriptionEntity,
+        repositories::{
+            brawlers::BrawlerRepository, email_digests::EmailDigestRepository, mailer::Mailer,
+            notification_preferences::NotificationPreferenceRepository,
+            notifications::NotificationRepository,
+        },
+        value_objects::{
+            digest_frequencies::DigestFrequencies,
+            email_digest_model::{
+                DigestModel, DigestNotificationModel, DigestRecipientModel,
+                EmailDigestSettingsModel, UpdateEmailDigestModel,
+            },
+            notification_channels::NotificationChannels,
+            notification_model::NotificationFilter,
+            notification_types::NotificationTypes,
+        },
+    },
+    infrastructure::mailer::templates::render_digest,
+};
+
+/// Subscribers handled per job run; the rest are picked up by the next one
+const DIGEST_BATCH_SIZE: i64 = 100;
+const DIGEST_NOTIFICATION_LIMIT: i64 = 50;
+
+pub struct EmailDigestUseCase {
+    digest_repo: Arc<dyn EmailDigestRepository>,
+    brawler_repo: Arc<dyn BrawlerRepository + Send + Sync>,
+    notification_repo: Arc<dyn NotificationRepository>,
+    preference_repo: Arc<dyn NotificationPreferenceRepository>,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl EmailDigestUseCase {
+    pub fn new(
+        digest_repo: Arc<dyn EmailDigestRepository>,
+        brawler_repo: Arc<dyn BrawlerRepository + Send + Sync>,
+        notification_repo: Arc<dyn NotificationRepository>,
+        preference_repo: Arc<dyn NotificationPreferenceRepository>,
+        mailer: Arc<dyn Mailer>,
+    ) -> Self {
+        Self {
+            digest_repo,
+            brawler_repo,
+            notification_repo,
+            preference_repo,
+            mailer,
+        }
+    }
+
+    pub async fn get_settings(&self, brawler_id: i32) -> Result<EmailDigestSettingsModel> {
+        let subscription = self.digest_repo.get(brawler_id).await?;
+
+        Ok(EmailDigestSettingsModel {
+            frequency: subscription.as_ref().map(|sub| sub.frequency.clone()),
+            last_sent_at: subscription.and_then(|sub| sub.last_sent_at),
+        })
+    }
+
+    /// Opting in needs a contact email; the first digest covers the first full day or week
+    pub async fn update_settings(
+        &self,
+        brawler_id: i32,
+        model: UpdateEmailDigestModel,
+    ) -> Result<EmailDigestSettingsModel> {
+        let Some(frequency) = model.frequency else {
+            self.digest_repo.remove(brawler_id).await?;
+            return self.get_settings(brawler_id).await;
+        };
+        let frequency = DigestFrequencies::from_str(&frequency)?;
+
+        let brawler = self.brawler_repo.find_by_id(brawler_id).await?;
+        if brawler
+            .contact_email
+            .as_deref()
+            .is_none_or(|email| email.trim().is_empty())
+        {
+            return Err(anyhow!("Set a contact email before turning on the digest"));
+        }
+
+        let subscription = self
+            .digest_repo
+            .upsert(UpsertEmailDigestSubscriptionEntity {
+                brawler_id,
+                frequency: frequency.to_string(),
+                last_sent_at: Some(Utc::now().naive_utc()),
+            })
+            .await?;
+
+        Ok(EmailDigestSettingsModel {
+            frequency: Some(subscription.frequency),
+            last_sent_at: subscription.last_sent_at,
+        })
+    }
+
+    /// Sends every digest that is due. One failing recipient doesn't hold up the others
+    pub async fn send_due(&self) -> Result<()> {
+        let app_url = get_mailer_env()?.app_url;
+        let now = Utc::now().naive_utc();
+        let recipients = self.digest_repo.find_due(now, DIGEST_BATCH_SIZE).await?;
+
+        let mut sent = 0;
+        for recipient in recipients {
+            let brawler_id = recipient.brawler_id;
+            match self.send_one(recipient, &app_url).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        "Failed to send email digest to brawler {}: {}",
+                        brawler_id, e
+                    );
+                    continue;
+                }
+            }
+            self.digest_repo.mark_sent(brawler_id, now).await?;
+        }
+
+        if sent > 0 {
+            info!("Sent {} email digests", sent);
+        }
+
+        Ok(())
+    }
+
+    /// `false` when there was nothing to report, so no email went out
+    async fn send_one(&self, recipient: DigestRecipientModel, app_url: &str) -> Result<bool> {
+        let frequency = DigestFrequencies::from_str(&recipient.frequency)?;
+        let now = Utc::now().naive_utc();
+
+        let digest = DigestModel {
+            display_name: recipient.display_name,
+            frequency: frequency.to_string(),
+            app_url: app_url.to_string(),
+            notifications: self.unread_notifications(recipient.brawler_id).await?,
+            upcoming_missions: self
+                .digest_repo
+                .get_upcoming_missions(recipient.brawler_id, now, now + frequency.period())
+                .await?,
+            unread_dms: self
+                .digest_repo
+                .get_unread_dms(recipient.brawler_id)
+                .await?,
+        };
+        if digest.is_empty() {
+            return Ok(false);
+        }
+
+        let email = render_digest(recipient.contact_email, &digest)?;
+        self.mailer.send(email).await?;
+
+        Ok(true)
+    }
+
+    /// Unread bell entries, minus DMs (listed per sender instead) and types muted for email
+    async fn unread_notifications(&self, brawler_id: i32) -> Result<Vec<DigestNotificationModel>> {
+        let muted: Vec<String> = self
+            .preference_repo
+            .get_by_brawler(brawler_id)
+            .await?
+            .into_iter()
+            .filter(|pref| pref.channel == NotificationChannels::Email.to_string() && !pref.enabled)
+            .map(|pref| pref.notification_type)
+            .collect();
+
+        let notifications = self
+            .notification_repo
+            .get_page(
+                brawler_id,
+                NotificationFilter {
+                    type_: None,
+                    unread_only: true,
+                },
+                None,
+                DIGEST_NOTIFICATION_LIMIT,
+            )
+            .await?
+            .into_iter()
+            .filter(|notification| {
+                notification.type_ != NotificationTypes::PrivateMessage.to_string()
+                    && !muted.contains(&notification.type_)
+            })
+            .map(|notification| DigestNotificationModel {
+                content: notification.content,
+                updated_at: notification.updated_at,
+            })
+            .collect();
+
+        Ok(notifications)
+    }
+}
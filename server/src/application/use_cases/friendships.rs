@@ -1,33 +1,39 @@
-use crate::application::use_cases::notifications::NotificationUseCase;
+use crate::application::use_cases::{
+    achievements::AchievementUseCase, notification_service::NotificationService,
+};
 use crate::domain::{
     entities::friendships::{FriendshipEntity, NewFriendshipEntity, PendingRequestDto},
-    repositories::{brawlers::BrawlerRepository, friendship_repository::FriendshipRepository},
+    repositories::{
+        brawlers::BrawlerRepository, friendship_repository::FriendshipRepository,
+        privacy::PrivacyRepository,
+    },
+    value_objects::{achievements::AchievementEvents, notification_events::NotificationEvent},
 };
-use crate::infrastructure::websocket::handler::WSMessage;
-use crate::infrastructure::websocket::manager::ConnectionManager;
 use anyhow::{Result, anyhow};
-use serde_json::json;
 use std::sync::Arc;
 
 pub struct FriendshipUseCase {
     repo: Arc<dyn FriendshipRepository>,
     brawler_repo: Arc<dyn BrawlerRepository + Send + Sync>,
-    notification_use_case: Arc<NotificationUseCase>,
-    ws_manager: Arc<ConnectionManager>,
+    notification_service: Arc<NotificationService>,
+    achievement_use_case: Arc<AchievementUseCase>,
+    privacy_repo: Arc<dyn PrivacyRepository>,
 }
 
 impl FriendshipUseCase {
     pub fn new(
         repo: Arc<dyn FriendshipRepository>,
         brawler_repo: Arc<dyn BrawlerRepository + Send + Sync>,
-        notification_use_case: Arc<NotificationUseCase>,
-        ws_manager: Arc<ConnectionManager>,
+        notification_service: Arc<NotificationService>,
+        achievement_use_case: Arc<AchievementUseCase>,
+        privacy_repo: Arc<dyn PrivacyRepository>,
     ) -> Self {
         Self {
             repo,
             brawler_repo,
-            notification_use_case,
-            ws_manager,
+            notification_service,
+            achievement_use_case,
+            privacy_repo,
         }
     }
 
@@ -40,11 +46,20 @@ impl FriendshipUseCase {
             return Err(anyhow!("Cannot add yourself as friend"));
         }
 
-        if let Some(_) = self
+        if self
+            .privacy_repo
+            .is_blocked_between(requester_id, receiver_id)
+            .await?
+        {
+            return Err(anyhow!("You can't send a friend request to this user"));
+        }
+
+        if self
             .repo
             .find_by_users(requester_id, receiver_id)
             .await
             .map_err(|e| anyhow!(e))?
+            .is_some()
         {
             return Err(anyhow!("Friendship already exists or pending"));
         }
@@ -67,26 +82,12 @@ impl FriendshipUseCase {
             .await
             .map_err(|e| anyhow!(e))?;
 
-        // Notify receiver
-        let content = format!("User {} sent you a friend request", requester_name);
-        let _ = self
-            .notification_use_case
-            .save_notification(receiver_id, "friend_request", &content, Some(requester_id))
-            .await;
-
-        self.ws_manager
-            .notify_user(
+        self.notification_service
+            .notify(NotificationEvent::FriendRequested {
+                requester_id,
+                requester_name,
                 receiver_id,
-                WSMessage {
-                    msg_type: "notification".to_string(),
-                    data: json!({
-                        "type": "friend_request",
-                        "requester_id": requester_id,
-                        "requester_name": requester_name,
-                        "content": content
-                    }),
-                },
-            )
+            })
             .await;
 
         Ok(entity)
@@ -116,31 +117,20 @@ impl FriendshipUseCase {
             Err(_) => user_id.to_string(),
         };
 
-        // Notify requester
-        let content = format!("User {} accepted your friend request", user_name);
-        let _ = self
-            .notification_use_case
-            .save_notification(
-                friendship.requester_id,
-                "friend_accepted",
-                &content,
-                Some(user_id),
-            )
+        self.notification_service
+            .notify(NotificationEvent::FriendAccepted {
+                friend_id: user_id,
+                friend_name: user_name,
+                requester_id: friendship.requester_id,
+            })
             .await;
 
-        self.ws_manager
-            .notify_user(
-                friendship.requester_id,
-                WSMessage {
-                    msg_type: "notification".to_string(),
-                    data: json!({
-                        "type": "friend_accepted",
-                        "friend_id": user_id,
-                        "content": content
-                    }),
-                },
-            )
-            .await;
+        for brawler_id in [user_id, friendship.requester_id] {
+            let _ = self
+                .achievement_use_case
+                .evaluate(brawler_id, AchievementEvents::FriendAccepted)
+                .await;
+        }
 
         Ok(updated)
     }
@@ -192,6 +182,15 @@ impl FriendshipUseCase {
             .map_err(|e| anyhow!(e))
     }
 
+    /// Drops the brawlers who blocked `viewer_id` so their presence stays hidden
+    pub async fn filter_visible(&self, viewer_id: i32, brawler_ids: Vec<i32>) -> Result<Vec<i32>> {
+        let blocker_ids = self.privacy_repo.list_blockers(viewer_id).await?;
+        Ok(brawler_ids
+            .into_iter()
+            .filter(|id| !blocker_ids.contains(id))
+            .collect())
+    }
+
     pub async fn get_friendship_status(
         &self,
         user1_id: i32,
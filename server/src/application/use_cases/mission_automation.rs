@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::use_cases::{
+        mission_events::MissionEventService, notification_service::NotificationService,
+    },
+    config::config_loader::get_mission_automation_env,
+    domain::{
+        repositories::{
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_statuses::MissionStatuses, notification_events::NotificationEvent,
+        },
+    },
+};
+
+/// Acts on `scheduled_at` once it comes around: reminders, auto-start and auto-expire.
+/// Every handler re-reads the mission first, so a job that fires after the chief already
+/// acted (or deleted the mission) is a no-op.
+pub struct MissionAutomationUseCase<T1, T2>
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_operation_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    notification_service: Arc<NotificationService>,
+    mission_events: Arc<MissionEventService>,
+}
+
+impl<T1, T2> MissionAutomationUseCase<T1, T2>
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        notification_service: Arc<NotificationService>,
+        mission_events: Arc<MissionEventService>,
+    ) -> Self {
+        Self {
+            mission_operation_repository,
+            mission_viewing_repository,
+            notification_service,
+            mission_events,
+        }
+    }
+
+    pub async fn send_reminder(&self, mission_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.deleted_at.is_some() || mission.status != MissionStatuses::Open.to_string() {
+            return Ok(());
+        }
+
+        self.notification_service
+            .notify(NotificationEvent::MissionReminder { mission_id })
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn auto_start(&self, mission_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.deleted_at.is_some() || mission.status != MissionStatuses::Open.to_string() {
+            return Ok(());
+        }
+
+        let crew_count = self
+            .mission_viewing_repository
+            .crew_counting(mission_id)
+            .await?;
+
+        // Same crew conditions the chief has to meet for a manual start
+        if crew_count == 0 || (crew_count as i32) > mission.max_crew {
+            return Ok(());
+        }
+
+        self.mission_operation_repository
+            .to_progress(mission_id, mission.chief_id)
+            .await?;
+
+        self.mission_events.started(mission_id).await;
+
+        Ok(())
+    }
+
+    pub async fn auto_expire(&self, mission_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.deleted_at.is_some() {
+            return Ok(());
+        }
+
+        let final_status = if mission.status == MissionStatuses::Open.to_string() {
+            MissionStatuses::Failed
+        } else if mission.status == MissionStatuses::InProgress.to_string() {
+            get_mission_automation_env()?.expire_in_progress_as
+        } else {
+            return Ok(());
+        };
+
+        match final_status {
+            MissionStatuses::Completed => {
+                self.mission_operation_repository
+                    .to_completed(mission_id, mission.chief_id)
+                    .await?;
+            }
+            _ => {
+                self.mission_operation_repository
+                    .to_failed(mission_id, mission.chief_id)
+                    .await?;
+            }
+        }
+
+        self.mission_events
+            .closed(mission_id, final_status, true)
+            .await;
+
+        Ok(())
+    }
+}
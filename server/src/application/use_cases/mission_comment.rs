@@ -1,12 +1,38 @@
+use crate::application::use_cases::{
+    achievements::AchievementUseCase, mission_transcript::MissionTranscriptUseCase,
+    notification_service::NotificationService, webhook_service::WebhookService,
+};
 use crate::domain::{
+    entities::{
+        mission_comment_revisions::MissionCommentRevisionEntity,
+        mission_read_markers::MissionReadMarkerEntity,
+    },
     repositories::{
         mission_comment::MissionCommentRepository, mission_viewing::MissionViewingRepository,
     },
-    value_objects::mission_comment_model::MissionCommentModel,
+    value_objects::{
+        achievements::AchievementEvents,
+        mission_comment_model::{
+            CommentPageQuery, CommentReactionSummaryModel, MissionAnnouncementsModel,
+            MissionCommentModel, MissionCommentWithReactionsModel,
+        },
+        mission_model::MissionModel,
+        mission_transcript_model::TranscriptArchiveReasons,
+        notification_events::NotificationEvent,
+        webhook_events::WebhookEvent,
+    },
 };
+use crate::infrastructure::websocket::{handler::WSMessage, manager::ConnectionManager};
 use anyhow::Result;
 use std::sync::Arc;
 
+/// Longest emoji sequence accepted as a reaction (ZWJ sequences and skin tones included)
+const MAX_REACTION_LENGTH: usize = 32;
+const DEFAULT_COMMENT_PAGE_SIZE: i64 = 50;
+const MAX_COMMENT_PAGE_SIZE: i64 = 100;
+/// How many announcements a chief can keep pinned at once
+const MAX_PINNED_COMMENTS: usize = 3;
+
 pub struct MissionCommentUseCase<T1, T2>
 where
     T1: MissionCommentRepository + Send + Sync,
@@ -14,6 +40,11 @@ where
 {
     pub repository: Arc<T1>,
     pub mission_viewing_repository: Arc<T2>,
+    pub transcript_use_case: Arc<MissionTranscriptUseCase>,
+    pub manager: Arc<ConnectionManager>,
+    pub notification_service: Arc<NotificationService>,
+    pub webhook_service: Arc<WebhookService>,
+    pub achievement_use_case: Arc<AchievementUseCase>,
 }
 
 impl<T1, T2> MissionCommentUseCase<T1, T2>
@@ -21,10 +52,23 @@ where
     T1: MissionCommentRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    pub fn new(repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        transcript_use_case: Arc<MissionTranscriptUseCase>,
+        manager: Arc<ConnectionManager>,
+        notification_service: Arc<NotificationService>,
+        webhook_service: Arc<WebhookService>,
+        achievement_use_case: Arc<AchievementUseCase>,
+    ) -> Self {
         Self {
             repository,
             mission_viewing_repository,
+            transcript_use_case,
+            manager,
+            notification_service,
+            webhook_service,
+            achievement_use_case,
         }
     }
 
@@ -33,6 +77,8 @@ where
         mission_id: i32,
         brawler_id: i32,
         content: &str,
+        parent_id: Option<i32>,
+        attachment_ids: Vec<i32>,
     ) -> Result<MissionCommentModel> {
         // 1. Check if user is chief or member
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
@@ -54,11 +100,237 @@ where
             ));
         }
 
-        self.repository.add(mission_id, brawler_id, content).await
+        if let Some(parent_id) = parent_id {
+            let parent = self.repository.find_by_id(parent_id).await?;
+            if parent.mission_id != mission_id || parent.deleted_at.is_some() {
+                return Err(anyhow::anyhow!(
+                    "You can only reply to a message in this chat."
+                ));
+            }
+        }
+
+        let comment = self
+            .repository
+            .add(mission_id, brawler_id, content, parent_id, attachment_ids)
+            .await?;
+
+        let mut participants: Vec<(i32, String)> = crew
+            .into_iter()
+            .map(|member| (member.id, member.display_name))
+            .collect();
+        participants.push((mission.chief_id, mission.chief_display_name));
+
+        let mentioned_ids = parse_mentions(content, &participants)
+            .into_iter()
+            .filter(|id| *id != brawler_id)
+            .collect();
+
+        // Whoever has the room open sees it live, everyone else through notifications
+        self.manager
+            .broadcast(
+                mission_id,
+                WSMessage {
+                    msg_type: "new_comment".to_string(),
+                    data: serde_json::to_value(&comment).unwrap_or_default(),
+                },
+            )
+            .await;
+        if let Err(e) = self
+            .achievement_use_case
+            .evaluate(brawler_id, AchievementEvents::CommentPosted)
+            .await
+        {
+            tracing::warn!("Failed to evaluate achievements: {:?}", e);
+        }
+        self.notification_service
+            .notify(NotificationEvent::ChatMessage {
+                comment: comment.clone(),
+                mentioned_ids,
+            })
+            .await;
+        self.webhook_service
+            .emit(WebhookEvent::CommentCreated {
+                comment: comment.clone(),
+            })
+            .await;
+
+        Ok(comment)
+    }
+
+    pub async fn get_comments(
+        &self,
+        mission_id: i32,
+        viewer_id: i32,
+        query: CommentPageQuery,
+    ) -> Result<Vec<MissionCommentWithReactionsModel>> {
+        self.ensure_in_chat(mission_id, viewer_id).await?;
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_COMMENT_PAGE_SIZE)
+            .clamp(1, MAX_COMMENT_PAGE_SIZE);
+        let comments = self
+            .repository
+            .get_by_mission_id(mission_id, query.before_id, limit)
+            .await?;
+        let comment_ids = comments.iter().map(|c| c.id).collect();
+        let reactions = self
+            .repository
+            .get_reactions(comment_ids, viewer_id)
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(|comment| MissionCommentWithReactionsModel {
+                reactions: reactions
+                    .iter()
+                    .filter(|r| r.comment_id == comment.id)
+                    .cloned()
+                    .collect(),
+                comment,
+            })
+            .collect())
+    }
+
+    pub async fn update_read_marker(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        last_read_comment_id: i32,
+    ) -> Result<MissionReadMarkerEntity> {
+        self.ensure_in_chat(mission_id, brawler_id).await?;
+
+        let comment = self.repository.find_by_id(last_read_comment_id).await?;
+        if comment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Message not found in this chat."));
+        }
+
+        self.repository
+            .upsert_read_marker(mission_id, brawler_id, last_read_comment_id)
+            .await
+    }
+
+    pub async fn get_read_markers(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<MissionReadMarkerEntity>> {
+        self.ensure_in_chat(mission_id, brawler_id).await?;
+        self.repository.get_read_markers(mission_id).await
+    }
+
+    /// Returns whether the reaction was added (`false` means it was removed) and the new counts
+    pub async fn toggle_reaction(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+        emoji: &str,
+    ) -> Result<(bool, Vec<CommentReactionSummaryModel>)> {
+        let emoji = emoji.trim();
+        let is_valid_emoji = !emoji.is_empty()
+            && emoji.len() <= MAX_REACTION_LENGTH
+            && !emoji
+                .chars()
+                .any(|c| c.is_ascii_alphanumeric() || c.is_whitespace());
+        if !is_valid_emoji {
+            return Err(anyhow::anyhow!("Reaction must be an emoji!"));
+        }
+
+        self.find_in_mission(mission_id, comment_id).await?;
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        if mission.chief_id != brawler_id && !crew.iter().any(|m| m.id == brawler_id) {
+            return Err(anyhow::anyhow!(
+                "You are not authorized to react in this mission's chat."
+            ));
+        }
+
+        let added = self
+            .repository
+            .toggle_reaction(comment_id, brawler_id, emoji)
+            .await?;
+        let reactions = self
+            .repository
+            .get_reactions(vec![comment_id], brawler_id)
+            .await?;
+
+        Ok((added, reactions))
     }
 
-    pub async fn get_comments(&self, mission_id: i32) -> Result<Vec<MissionCommentModel>> {
-        self.repository.get_by_mission_id(mission_id).await
+    pub async fn pin_comment(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+    ) -> Result<MissionCommentModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != brawler_id {
+            return Err(anyhow::anyhow!("Only the chief can pin messages!"));
+        }
+
+        let comment = self.find_in_mission(mission_id, comment_id).await?;
+        if comment.pinned_at.is_some() {
+            return Ok(comment);
+        }
+
+        let pinned = self.repository.get_pinned(mission_id).await?;
+        if pinned.len() >= MAX_PINNED_COMMENTS {
+            return Err(anyhow::anyhow!(
+                "You can pin at most {} messages. Unpin one first!",
+                MAX_PINNED_COMMENTS
+            ));
+        }
+
+        let comment = self
+            .repository
+            .set_pinned(comment_id, Some(brawler_id))
+            .await?;
+
+        self.manager
+            .broadcast(
+                mission_id,
+                WSMessage {
+                    msg_type: "comment_pinned".to_string(),
+                    data: serde_json::to_value(&comment).unwrap_or_default(),
+                },
+            )
+            .await;
+        // Every crew member hears about the announcement, in the room or not
+        self.notification_service
+            .notify(NotificationEvent::AnnouncementPinned {
+                comment: comment.clone(),
+                pinned_by: brawler_id,
+            })
+            .await;
+
+        Ok(comment)
+    }
+
+    pub async fn unpin_comment(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+    ) -> Result<MissionCommentModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != brawler_id {
+            return Err(anyhow::anyhow!("Only the chief can unpin messages!"));
+        }
+
+        self.find_in_mission(mission_id, comment_id).await?;
+        self.repository.set_pinned(comment_id, None).await
+    }
+
+    pub async fn get_announcements(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<MissionAnnouncementsModel> {
+        let mission = self.ensure_in_chat(mission_id, brawler_id).await?;
+        let pinned = self.repository.get_pinned(mission_id).await?;
+        Ok(MissionAnnouncementsModel { mission, pinned })
     }
 
     pub async fn clear_comments(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
@@ -66,6 +338,179 @@ where
         if mission.chief_id != brawler_id {
             return Err(anyhow::anyhow!("Only the chief can clear the chat!"));
         }
+        self.transcript_use_case
+            .archive(mission_id, TranscriptArchiveReasons::ChatCleared)
+            .await?;
         self.repository.clear_by_mission_id(mission_id).await
     }
+
+    pub async fn edit_comment(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+        content: &str,
+    ) -> Result<MissionCommentModel> {
+        if content.trim().is_empty() {
+            return Err(anyhow::anyhow!("Message cannot be empty!"));
+        }
+
+        let comment = self.find_in_mission(mission_id, comment_id).await?;
+        if comment.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("You can only edit your own messages!"));
+        }
+
+        self.repository.edit(comment_id, content).await
+    }
+
+    /// The author can delete their own message; the chief can delete anyone's
+    pub async fn delete_comment(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+    ) -> Result<()> {
+        let comment = self.find_in_mission(mission_id, comment_id).await?;
+
+        if comment.brawler_id != brawler_id {
+            let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+            if mission.chief_id != brawler_id {
+                return Err(anyhow::anyhow!(
+                    "Only the author or the chief can delete this message!"
+                ));
+            }
+        }
+
+        self.repository.soft_delete(comment_id, brawler_id).await
+    }
+
+    pub async fn get_revisions(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        comment_id: i32,
+    ) -> Result<Vec<MissionCommentRevisionEntity>> {
+        self.ensure_in_chat(mission_id, brawler_id).await?;
+
+        let comment = self.repository.find_by_id(comment_id).await?;
+        if comment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Message not found in this chat."));
+        }
+
+        // Don't leak the text of a deleted message through its history
+        if comment.deleted_at.is_some() {
+            return Ok(Vec::new());
+        }
+
+        self.repository.get_revisions(comment_id).await
+    }
+
+    /// Only the chief and crew can read or take part in a mission's chat
+    async fn ensure_in_chat(&self, mission_id: i32, brawler_id: i32) -> Result<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        if mission.chief_id != brawler_id && !crew.iter().any(|m| m.id == brawler_id) {
+            return Err(anyhow::anyhow!("You are not part of this mission's chat."));
+        }
+        Ok(mission)
+    }
+
+    async fn find_in_mission(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+    ) -> Result<MissionCommentModel> {
+        let comment = self.repository.find_by_id(comment_id).await?;
+        if comment.mission_id != mission_id || comment.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("Message not found in this chat."));
+        }
+        Ok(comment)
+    }
+}
+
+/// Finds `@id` and `@display_name` mentions of mission participants.
+/// Display names may contain spaces, so the longest participant name that follows the `@` wins.
+fn parse_mentions(content: &str, participants: &[(i32, String)]) -> Vec<i32> {
+    let mut mentioned: Vec<i32> = Vec::new();
+
+    for (at, _) in content.match_indices('@') {
+        let rest = &content[at + 1..];
+
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let by_id = digits
+            .parse::<i32>()
+            .ok()
+            .filter(|id| participants.iter().any(|(pid, _)| pid == id));
+
+        let by_name = participants
+            .iter()
+            .filter(|(_, name)| {
+                !name.is_empty()
+                    && rest
+                        .get(..name.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+                    && !rest[name.len()..]
+                        .chars()
+                        .next()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+            })
+            .max_by_key(|(_, name)| name.len())
+            .map(|(id, _)| *id);
+
+        if let Some(id) = by_name.or(by_id)
+            && !mentioned.contains(&id)
+        {
+            mentioned.push(id);
+        }
+    }
+
+    mentioned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participants() -> Vec<(i32, String)> {
+        vec![
+            (1, "Alice".to_string()),
+            (2, "Bob".to_string()),
+            (3, "Bob Smith".to_string()),
+        ]
+    }
+
+    #[test]
+    fn mentions_by_id_and_by_name() {
+        assert_eq!(parse_mentions("hey @1", &participants()), vec![1]);
+        assert_eq!(parse_mentions("hey @alice!", &participants()), vec![1]);
+    }
+
+    #[test]
+    fn longest_display_name_wins() {
+        assert_eq!(
+            parse_mentions("@Bob Smith ready?", &participants()),
+            vec![3]
+        );
+        assert_eq!(parse_mentions("@Bob ready?", &participants()), vec![2]);
+    }
+
+    #[test]
+    fn names_must_end_at_a_word_boundary() {
+        assert!(parse_mentions("@Bobby and @Alice_2", &participants()).is_empty());
+    }
+
+    #[test]
+    fn ignores_outsiders_and_repeats() {
+        assert!(parse_mentions("@99 @Carol", &participants()).is_empty());
+        assert_eq!(
+            parse_mentions("@2 @bob @Alice @1", &participants()),
+            vec![2, 1]
+        );
+    }
+
+    #[test]
+    fn handles_multibyte_text_after_the_at() {
+        assert!(parse_mentions("@ทีม @é", &participants()).is_empty());
+        assert_eq!(parse_mentions("สวัสดี @Bob", &participants()), vec![2]);
+    }
 }
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::{
+        achievements::AchievementUseCase, notification_service::NotificationService,
+        webhook_service::WebhookService,
+    },
+    domain::value_objects::{
+        mission_statuses::MissionStatuses, notification_events::NotificationEvent,
+        webhook_events::WebhookEvent,
+    },
+};
+
+/// What follows a mission changing state, whether the chief did it or the scheduler did.
+/// Like the services it calls, it is best effort and never fails the change itself.
+pub struct MissionEventService {
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+    achievement_use_case: Arc<AchievementUseCase>,
+}
+
+impl MissionEventService {
+    pub fn new(
+        notification_service: Arc<NotificationService>,
+        webhook_service: Arc<WebhookService>,
+        achievement_use_case: Arc<AchievementUseCase>,
+    ) -> Self {
+        Self {
+            notification_service,
+            webhook_service,
+            achievement_use_case,
+        }
+    }
+
+    pub async fn started(&self, mission_id: i32) {
+        self.notification_service
+            .notify(NotificationEvent::MissionStarted { mission_id })
+            .await;
+        self.webhook_service
+            .emit(WebhookEvent::MissionStarted { mission_id })
+            .await;
+    }
+
+    /// `status` is the final one, `Completed` or `Failed`
+    pub async fn closed(&self, mission_id: i32, status: MissionStatuses, automatic: bool) {
+        self.notification_service
+            .notify(NotificationEvent::MissionClosed {
+                mission_id,
+                status: status.clone(),
+                automatic,
+            })
+            .await;
+        self.webhook_service
+            .emit(match status {
+                MissionStatuses::Completed => WebhookEvent::MissionCompleted { mission_id },
+                _ => WebhookEvent::MissionFailed { mission_id },
+            })
+            .await;
+
+        if status == MissionStatuses::Completed
+            && let Err(e) = self
+                .achievement_use_case
+                .evaluate_mission_completed(mission_id)
+                .await
+        {
+            tracing::warn!("Failed to evaluate mission achievements: {:?}", e);
+        }
+    }
+
+    pub async fn kicked(&self, mission_id: i32, brawler_id: i32) {
+        self.notification_service
+            .notify(NotificationEvent::KickedFromMission {
+                mission_id,
+                brawler_id,
+            })
+            .await;
+    }
+}
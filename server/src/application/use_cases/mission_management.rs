@@ -1,12 +1,32 @@
 use std::sync::Arc;
 
-use crate::domain::{
-    repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::{
+    application::use_cases::{
+        mission_media::MissionMediaUseCase, mission_transcript::MissionTranscriptUseCase,
+    },
+    config::config_loader::get_mission_automation_env,
+    domain::{
+        entities::scheduled_jobs::AddScheduledJobEntity,
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository, scheduled_jobs::ScheduledJobRepository,
+        },
+        value_objects::{
+            mission_model::{AddMissionModel, EditMissionModel},
+            mission_transcript_model::TranscriptArchiveReasons,
+            scheduled_job_types::ScheduledJobTypes,
+        },
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
 };
 
+const MISSION_JOB_TYPES: [ScheduledJobTypes; 3] = [
+    ScheduledJobTypes::MissionReminder,
+    ScheduledJobTypes::MissionAutoStart,
+    ScheduledJobTypes::MissionAutoExpire,
+];
+
 pub struct MissionManagementUseCase<T1, T2>
 where
     T1: MissionManagementRepository + Send + Sync,
@@ -14,6 +34,9 @@ where
 {
     pub mission_management_repository: Arc<T1>,
     pub mission_viewing_repository: Arc<T2>,
+    pub scheduled_job_repository: Arc<dyn ScheduledJobRepository>,
+    pub transcript_use_case: Arc<MissionTranscriptUseCase>,
+    pub media_use_case: Arc<MissionMediaUseCase>,
 }
 
 use anyhow::Result;
@@ -26,10 +49,16 @@ where
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        scheduled_job_repository: Arc<dyn ScheduledJobRepository>,
+        transcript_use_case: Arc<MissionTranscriptUseCase>,
+        media_use_case: Arc<MissionMediaUseCase>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            scheduled_job_repository,
+            transcript_use_case,
+            media_use_case,
         }
     }
 
@@ -40,10 +69,18 @@ where
             ));
         }
 
-        if let Some(scheduled_at) = add_mission_model.scheduled_at {
-            if scheduled_at < chrono::Utc::now() {
-                return Err(anyhow::anyhow!("Scheduled time cannot be in the past!"));
-            }
+        if let Some(scheduled_at) = add_mission_model.scheduled_at
+            && scheduled_at < chrono::Utc::now()
+        {
+            return Err(anyhow::anyhow!("Scheduled time cannot be in the past!"));
+        }
+
+        if let Some(min_reputation) = add_mission_model.min_reputation
+            && !(1.0..=5.0).contains(&min_reputation)
+        {
+            return Err(anyhow::anyhow!(
+                "Minimum reputation must be between 1 and 5!"
+            ));
         }
 
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
@@ -53,6 +90,10 @@ where
             .add(insert_mission_entity)
             .await?;
 
+        if let Some(scheduled_at) = add_mission_model.scheduled_at {
+            self.schedule_jobs(result, scheduled_at.naive_utc()).await?;
+        }
+
         Ok(result)
     }
 
@@ -74,10 +115,18 @@ where
             }
         }
 
-        if let Some(scheduled_at) = edit_mission_model.scheduled_at {
-            if scheduled_at < chrono::Utc::now() {
-                return Err(anyhow::anyhow!("Scheduled time cannot be in the past!"));
-            }
+        if let Some(scheduled_at) = edit_mission_model.scheduled_at
+            && scheduled_at < chrono::Utc::now()
+        {
+            return Err(anyhow::anyhow!("Scheduled time cannot be in the past!"));
+        }
+
+        if let Some(min_reputation) = edit_mission_model.min_reputation
+            && !(1.0..=5.0).contains(&min_reputation)
+        {
+            return Err(anyhow::anyhow!(
+                "Minimum reputation must be between 1 and 5!"
+            ));
         }
 
         let crew_count = self
@@ -97,6 +146,14 @@ where
             .edit(mission_id, edit_mission_entity)
             .await?;
 
+        if let Some(scheduled_at) = edit_mission_model.scheduled_at {
+            self.scheduled_job_repository
+                .cancel_pending(mission_id, MISSION_JOB_TYPES.to_vec())
+                .await?;
+            self.schedule_jobs(mission_id, scheduled_at.naive_utc())
+                .await?;
+        }
+
         Ok(result)
     }
 
@@ -113,9 +170,61 @@ where
             ));
         }
 
+        // Without a crew the repository hard-deletes the mission together with its chat
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        let mut media_files = Vec::new();
+        if crew.is_empty() {
+            self.transcript_use_case
+                .archive(mission_id, TranscriptArchiveReasons::MissionRemoved)
+                .await?;
+            media_files = self.media_use_case.collect_files(mission_id).await?;
+        }
+
         self.mission_management_repository
             .remove(mission_id, chief_id)
             .await?;
+
+        // A soft-deleted mission keeps its images for the crew that can still see it
+        self.media_use_case.delete_files(media_files).await;
+
+        self.scheduled_job_repository
+            .cancel_pending(mission_id, MISSION_JOB_TYPES.to_vec())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn schedule_jobs(&self, mission_id: i32, scheduled_at: NaiveDateTime) -> Result<()> {
+        let automation_env = get_mission_automation_env()?;
+        let now = Utc::now().naive_utc();
+
+        let mut jobs = Vec::new();
+
+        let remind_at = scheduled_at - Duration::hours(automation_env.reminder_hours);
+        if automation_env.reminder_hours > 0 && remind_at > now {
+            jobs.push((ScheduledJobTypes::MissionReminder, remind_at));
+        }
+
+        if automation_env.auto_start {
+            jobs.push((ScheduledJobTypes::MissionAutoStart, scheduled_at));
+        }
+
+        jobs.push((
+            ScheduledJobTypes::MissionAutoExpire,
+            scheduled_at + Duration::hours(automation_env.expire_grace_hours),
+        ));
+
+        for (job_type, run_at) in jobs {
+            self.scheduled_job_repository
+                .add(AddScheduledJobEntity {
+                    job_type: job_type.to_string(),
+                    related_id: Some(mission_id),
+                    payload: serde_json::json!({}),
+                    run_at,
+                })
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,220 @@
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::mission_gallery_items::AddMissionGalleryItemEntity,
+        repositories::{
+            blob_storage::BlobStorage, mission_media::MissionMediaRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            base64_img::Base64Img, mission_gallery_model::MissionGalleryItemModel,
+            mission_model::MissionModel, uploaded_img::UploadedImg,
+        },
+    },
+    infrastructure::image_processing::{
+        self, ImageShape, MISSION_COVER_ASPECT, MISSION_COVER_WIDTHS, MISSION_GALLERY_WIDTHS,
+    },
+};
+
+/// A stored image: the folder holding its variants and the widths rendered into it
+pub type StoredImage = (String, &'static [u32]);
+
+pub struct MissionMediaUseCase {
+    media_repository: Arc<dyn MissionMediaRepository>,
+    mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+    storage: Arc<dyn BlobStorage>,
+}
+
+impl MissionMediaUseCase {
+    pub fn new(
+        media_repository: Arc<dyn MissionMediaRepository>,
+        mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+        storage: Arc<dyn BlobStorage>,
+    ) -> Self {
+        Self {
+            media_repository,
+            mission_viewing_repository,
+            storage,
+        }
+    }
+
+    /// Only the chief may set the cover; the previous one is deleted once the new one is saved
+    pub async fn upload_cover(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        base64string: String,
+    ) -> Result<UploadedImg> {
+        let mission = self.get_active_mission(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow!("You are not the chief of this mission!"));
+        }
+
+        let bytes = Base64Img::new(base64string)?.to_bytes()?;
+
+        // Each upload gets its own folder so the old cover can be removed without races
+        let folder = format!("missions/{}/cover/{}", mission_id, Uuid::new_v4());
+        image_processing::store_variants(
+            self.storage.as_ref(),
+            bytes,
+            ImageShape::Crop(MISSION_COVER_ASPECT.0, MISSION_COVER_ASPECT.1),
+            &MISSION_COVER_WIDTHS,
+            &folder,
+        )
+        .await?;
+
+        let largest = MISSION_COVER_WIDTHS[MISSION_COVER_WIDTHS.len() - 1];
+        let url = UploadedImg::media_url(&image_processing::variant_key(&folder, largest));
+
+        let previous = match self
+            .media_repository
+            .update_cover(mission_id, url.clone(), folder.clone())
+            .await
+        {
+            Ok(previous) => previous,
+            Err(e) => {
+                let _ = image_processing::delete_variants(
+                    self.storage.as_ref(),
+                    &folder,
+                    &MISSION_COVER_WIDTHS,
+                )
+                .await;
+                return Err(e);
+            }
+        };
+
+        if let Some(previous) = previous {
+            self.delete_files(vec![(previous, &MISSION_COVER_WIDTHS[..])])
+                .await;
+        }
+
+        Ok(UploadedImg::new(url, folder))
+    }
+
+    pub async fn get_gallery(&self, mission_id: i32) -> Result<Vec<MissionGalleryItemModel>> {
+        self.get_active_mission(mission_id).await?;
+
+        let items = self.media_repository.get_gallery(mission_id).await?;
+        Ok(items
+            .into_iter()
+            .map(MissionGalleryItemModel::from)
+            .collect())
+    }
+
+    /// The chief and the crew can share photos
+    pub async fn add_gallery_item(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        base64string: String,
+    ) -> Result<MissionGalleryItemModel> {
+        let mission = self.get_active_mission(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        if mission.chief_id != brawler_id && !crew.iter().any(|m| m.id == brawler_id) {
+            return Err(anyhow!(
+                "Only the chief and crew can add photos to this mission!"
+            ));
+        }
+
+        let bytes = Base64Img::new(base64string)?.to_bytes()?;
+
+        let folder = format!("missions/{}/gallery/{}", mission_id, Uuid::new_v4());
+        image_processing::store_variants(
+            self.storage.as_ref(),
+            bytes,
+            ImageShape::Original,
+            &MISSION_GALLERY_WIDTHS,
+            &folder,
+        )
+        .await?;
+
+        let largest = MISSION_GALLERY_WIDTHS[MISSION_GALLERY_WIDTHS.len() - 1];
+        let item = match self
+            .media_repository
+            .add_gallery_item(AddMissionGalleryItemEntity {
+                mission_id,
+                uploader_id: brawler_id,
+                image_url: UploadedImg::media_url(&image_processing::variant_key(&folder, largest)),
+                image_public_id: folder.clone(),
+            })
+            .await
+        {
+            Ok(item) => item,
+            Err(e) => {
+                let _ = image_processing::delete_variants(
+                    self.storage.as_ref(),
+                    &folder,
+                    &MISSION_GALLERY_WIDTHS,
+                )
+                .await;
+                return Err(e);
+            }
+        };
+
+        Ok(item.into())
+    }
+
+    /// Only the chief can take photos down
+    pub async fn remove_gallery_item(
+        &self,
+        mission_id: i32,
+        item_id: i32,
+        chief_id: i32,
+    ) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow!("You are not the chief of this mission!"));
+        }
+
+        let item = self
+            .media_repository
+            .find_gallery_item(mission_id, item_id)
+            .await?
+            .ok_or_else(|| anyhow!("Photo not found!"))?;
+
+        self.media_repository.remove_gallery_item(item.id).await?;
+        self.delete_files(vec![(item.image_public_id, &MISSION_GALLERY_WIDTHS[..])])
+            .await;
+
+        Ok(())
+    }
+
+    /// Everything stored for a mission; read it before the mission row is hard-deleted
+    pub async fn collect_files(&self, mission_id: i32) -> Result<Vec<StoredImage>> {
+        let (cover, gallery) = self.media_repository.get_public_ids(mission_id).await?;
+
+        let mut files: Vec<StoredImage> = cover
+            .into_iter()
+            .map(|folder| (folder, &MISSION_COVER_WIDTHS[..]))
+            .collect();
+        files.extend(
+            gallery
+                .into_iter()
+                .map(|folder| (folder, &MISSION_GALLERY_WIDTHS[..])),
+        );
+
+        Ok(files)
+    }
+
+    /// Storage failures only leave orphaned files behind, so they are logged and skipped
+    pub async fn delete_files(&self, files: Vec<StoredImage>) {
+        for (folder, widths) in files {
+            if let Err(e) =
+                image_processing::delete_variants(self.storage.as_ref(), &folder, widths).await
+            {
+                tracing::warn!("Failed to delete mission image {}: {:?}", folder, e);
+            }
+        }
+    }
+
+    async fn get_active_mission(&self, mission_id: i32) -> Result<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.deleted_at.is_some() {
+            return Err(anyhow!("This mission has been removed!"));
+        }
+        Ok(mission)
+    }
+}
@@ -2,11 +2,15 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
-use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::mission_events::MissionEventService,
+    domain::{
+        repositories::{
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_statuses::MissionStatuses,
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 pub struct MissionOperationUseCase<T1, T2>
 where
@@ -15,6 +19,7 @@ where
 {
     mission_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    mission_events: Arc<MissionEventService>,
 }
 
 impl<T1, T2> MissionOperationUseCase<T1, T2>
@@ -22,10 +27,15 @@ where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        mission_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        mission_events: Arc<MissionEventService>,
+    ) -> Self {
         Self {
             mission_operation_repository,
             mission_viewing_repository,
+            mission_events,
         }
     }
 
@@ -52,6 +62,8 @@ where
             .mission_operation_repository
             .to_progress(mission_id, chief_id)
             .await?;
+
+        self.mission_events.started(mission_id).await;
         Ok(result)
     }
     pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -67,6 +79,9 @@ where
             .to_completed(mission_id, chief_id)
             .await?;
 
+        self.mission_events
+            .closed(mission_id, MissionStatuses::Completed, false)
+            .await;
         Ok(result)
     }
     pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -82,6 +97,9 @@ where
             .to_failed(mission_id, chief_id)
             .await?;
 
+        self.mission_events
+            .closed(mission_id, MissionStatuses::Failed, false)
+            .await;
         Ok(result)
     }
 
@@ -102,6 +120,7 @@ where
             .kick(mission_id, brawler_id)
             .await?;
 
+        self.mission_events.kicked(mission_id, brawler_id).await;
         Ok(())
     }
 }
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::domain::{
+    entities::mission_ratings::MissionRatingEntity,
+    repositories::{
+        mission_ratings::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_rating_model::{AddMissionRatingModel, RATING_TAGS, ReputationModel},
+        mission_statuses::MissionStatuses,
+    },
+};
+
+/// How long after a mission ends its participants can still rate each other
+pub const RATING_WINDOW_DAYS: i64 = 7;
+
+pub struct MissionRatingUseCase<T>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    mission_rating_repository: Arc<dyn MissionRatingRepository>,
+    mission_viewing_repository: Arc<T>,
+}
+
+impl<T> MissionRatingUseCase<T>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_rating_repository: Arc<dyn MissionRatingRepository>,
+        mission_viewing_repository: Arc<T>,
+    ) -> Self {
+        Self {
+            mission_rating_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        model: AddMissionRatingModel,
+    ) -> Result<i32> {
+        if !(1..=5).contains(&model.score) {
+            return Err(anyhow::anyhow!("Score must be between 1 and 5!"));
+        }
+
+        if let Some(tags) = &model.tags
+            && let Some(tag) = tags.iter().find(|tag| !RATING_TAGS.contains(&tag.as_str()))
+        {
+            return Err(anyhow::anyhow!("Unknown rating tag: {}", tag));
+        }
+
+        if model.ratee_id == rater_id {
+            return Err(anyhow::anyhow!("You cannot rate yourself!"));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        let finished_at = match mission.finished_at {
+            Some(finished_at) if is_finished => finished_at,
+            _ => return Err(anyhow::anyhow!("Mission has not finished yet!")),
+        };
+
+        if Utc::now().naive_utc() > finished_at + Duration::days(RATING_WINDOW_DAYS) {
+            return Err(anyhow::anyhow!(
+                "Rating window for this mission has closed!"
+            ));
+        }
+
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        let is_participant = |brawler_id: i32| {
+            brawler_id == mission.chief_id || crew.iter().any(|m| m.id == brawler_id)
+        };
+
+        if !is_participant(rater_id) {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can rate this mission!"
+            ));
+        }
+        if !is_participant(model.ratee_id) {
+            return Err(anyhow::anyhow!("This brawler was not part of the mission!"));
+        }
+
+        let already_rated = self
+            .mission_rating_repository
+            .find_by_rater(mission_id, rater_id)
+            .await?
+            .iter()
+            .any(|rating| rating.ratee_id == model.ratee_id);
+        if already_rated {
+            return Err(anyhow::anyhow!("You have already rated this brawler!"));
+        }
+
+        self.mission_rating_repository
+            .add(model.to_entity(mission_id, rater_id))
+            .await
+    }
+
+    pub async fn get_my_ratings(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+    ) -> Result<Vec<MissionRatingEntity>> {
+        self.mission_rating_repository
+            .find_by_rater(mission_id, rater_id)
+            .await
+    }
+
+    pub async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        self.mission_rating_repository
+            .get_reputation(brawler_id)
+            .await
+    }
+}
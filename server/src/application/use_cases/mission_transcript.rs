@@ -0,0 +1,326 @@
+use anyhow::Result;
+use futures::{
+    StreamExt, TryStreamExt,
+    stream::{self, BoxStream},
+};
+use std::sync::Arc;
+
+use crate::{
+    config::config_loader::get_chat_archive_env,
+    domain::{
+        entities::mission_transcripts::{AddMissionTranscriptEntity, MissionTranscriptEntity},
+        repositories::{
+            mission_comment::MissionCommentRepository,
+            mission_transcripts::MissionTranscriptRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_transcript_model::{
+            TranscriptArchiveReasons, TranscriptEntryModel, TranscriptFormats,
+        },
+    },
+};
+
+/// Comments fetched per query while an export streams out
+pub const EXPORT_PAGE_SIZE: i64 = 200;
+
+const CSV_HEADER: &str = "id,parent_id,author_id,author,created_at,edited_at,content\n";
+
+pub struct MissionTranscriptUseCase {
+    comment_repository: Arc<dyn MissionCommentRepository>,
+    mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+    transcript_repository: Arc<dyn MissionTranscriptRepository>,
+}
+
+impl MissionTranscriptUseCase {
+    pub fn new(
+        comment_repository: Arc<dyn MissionCommentRepository>,
+        mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+        transcript_repository: Arc<dyn MissionTranscriptRepository>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            mission_viewing_repository,
+            transcript_repository,
+        }
+    }
+
+    /// Renders the whole chat history; only the chief and the crew may export it.
+    /// The body is streamed a page of comments at a time, so long chats never sit in memory.
+    pub async fn export(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        format: TranscriptFormats,
+    ) -> Result<BoxStream<'static, Result<String>>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        if mission.chief_id != brawler_id && !crew.iter().any(|m| m.id == brawler_id) {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can export this chat!"
+            ));
+        }
+
+        let header = match format {
+            TranscriptFormats::Json => "[".to_string(),
+            TranscriptFormats::Csv => CSV_HEADER.to_string(),
+            TranscriptFormats::Markdown => markdown_title(&mission.name),
+        };
+        let footer = match format {
+            TranscriptFormats::Json => "\n]\n".to_string(),
+            _ => String::new(),
+        };
+
+        let comment_repository = Arc::clone(&self.comment_repository);
+        let pages = stream::try_unfold(Some(0), move |after_id| {
+            let comment_repository = Arc::clone(&comment_repository);
+            async move {
+                let Some(after_id) = after_id else {
+                    return Ok::<_, anyhow::Error>(None);
+                };
+                let page = comment_repository
+                    .get_transcript_page(mission_id, after_id, EXPORT_PAGE_SIZE)
+                    .await?;
+                if page.is_empty() {
+                    return Ok(None);
+                }
+
+                // A short page is the last one
+                let next = (page.len() as i64 == EXPORT_PAGE_SIZE)
+                    .then(|| page.last().map(|comment| comment.id))
+                    .flatten();
+                Ok(Some((
+                    stream::iter(page.into_iter().map(Ok::<_, anyhow::Error>)),
+                    next,
+                )))
+            }
+        });
+
+        let entries = pages
+            .try_flatten()
+            .enumerate()
+            .map(move |(index, comment)| {
+                render_entry(format, &TranscriptEntryModel::from(comment?), index == 0)
+            });
+
+        Ok(stream::once(async move { Ok(header) })
+            .chain(entries)
+            .chain(stream::once(async move { Ok(footer) }))
+            .boxed())
+    }
+
+    /// Snapshots the chat before it is wiped. Does nothing when `CHAT_AUTO_ARCHIVE` is off
+    /// or there is nothing to keep.
+    pub async fn archive(
+        &self,
+        mission_id: i32,
+        reason: TranscriptArchiveReasons,
+    ) -> Result<Option<i32>> {
+        if !get_chat_archive_env()?.auto_archive {
+            return Ok(None);
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        let mut participant_ids: Vec<i32> = crew.iter().map(|m| m.id).collect();
+
+        // Read the chat a page at a time, keeping only the serialized entries
+        let mut comments = Vec::new();
+        let mut after_id = 0;
+        loop {
+            let page = self
+                .comment_repository
+                .get_transcript_page(mission_id, after_id, EXPORT_PAGE_SIZE)
+                .await?;
+            let last_page = (page.len() as i64) < EXPORT_PAGE_SIZE;
+
+            for comment in page {
+                after_id = comment.id;
+                let entry = TranscriptEntryModel::from(comment);
+                if entry.author_id != mission.chief_id
+                    && !participant_ids.contains(&entry.author_id)
+                {
+                    participant_ids.push(entry.author_id);
+                }
+                comments.push(serde_json::to_value(&entry)?);
+            }
+
+            if last_page {
+                break;
+            }
+        }
+        if comments.is_empty() {
+            return Ok(None);
+        }
+
+        let id = self
+            .transcript_repository
+            .add(AddMissionTranscriptEntity {
+                mission_id,
+                mission_name: mission.name,
+                chief_id: mission.chief_id,
+                participant_ids,
+                reason: reason.to_string(),
+                comment_count: comments.len() as i32,
+                comments: serde_json::Value::Array(comments),
+            })
+            .await?;
+
+        Ok(Some(id))
+    }
+
+    pub async fn get_archives(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<MissionTranscriptEntity>> {
+        self.transcript_repository
+            .find_by_mission(mission_id, brawler_id)
+            .await
+    }
+}
+
+/// One entry in `format`; JSON entries after the first are comma-separated from the last
+fn render_entry(
+    format: TranscriptFormats,
+    entry: &TranscriptEntryModel,
+    first: bool,
+) -> Result<String> {
+    Ok(match format {
+        TranscriptFormats::Json => format!(
+            "{}\n  {}",
+            if first { "" } else { "," },
+            serde_json::to_string(entry)?
+        ),
+        TranscriptFormats::Csv => csv_row(entry),
+        TranscriptFormats::Markdown => markdown_entry(entry),
+    })
+}
+
+fn csv_row(entry: &TranscriptEntryModel) -> String {
+    let row = [
+        entry.id.to_string(),
+        entry.parent_id.map(|id| id.to_string()).unwrap_or_default(),
+        entry.author_id.to_string(),
+        csv_field(&entry.author_display_name),
+        entry.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        entry
+            .edited_at
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default(),
+        csv_field(&entry.content),
+    ];
+
+    format!("{}\n", row.join(","))
+}
+
+/// Quotes a field when it holds a separator, quote or line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_title(mission_name: &str) -> String {
+    format!("# {} — chat transcript\n\n", markdown_text(mission_name))
+}
+
+/// Backslash-escapes Markdown syntax in names, so a display name can't break out of its bold.
+/// Message bodies keep their formatting, they sit in a blockquote of their own.
+fn markdown_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '`' | '#' | '[' | ']' | '<' | '>' | '~' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn markdown_entry(entry: &TranscriptEntryModel) -> String {
+    let mut markdown = format!(
+        "**{}** · {}",
+        markdown_text(&entry.author_display_name),
+        entry.created_at.format("%Y-%m-%d %H:%M")
+    );
+    if entry.edited_at.is_some() {
+        markdown.push_str(" _(edited)_");
+    }
+    if let Some(parent_id) = entry.parent_id {
+        markdown.push_str(&format!(" ↪ reply to #{}", parent_id));
+    }
+    markdown.push_str(&format!(" `#{}`\n\n", entry.id));
+
+    for line in entry.content.lines() {
+        markdown.push_str(&format!("> {}\n", line));
+    }
+    markdown.push('\n');
+
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(author: &str, content: &str) -> TranscriptEntryModel {
+        TranscriptEntryModel {
+            id: 7,
+            parent_id: Some(3),
+            author_id: 2,
+            author_display_name: author.to_string(),
+            content: content.to_string(),
+            created_at: chrono::NaiveDate::from_ymd_opt(2026, 3, 1)
+                .unwrap()
+                .and_hms_opt(18, 30, 0)
+                .unwrap(),
+            edited_at: None,
+        }
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"go\""), "\"say \"\"go\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field("cr\r"), "\"cr\r\"");
+    }
+
+    #[test]
+    fn csv_row_follows_the_header_columns() {
+        let row = csv_row(&entry("Bob, Jr.", "hi"));
+
+        assert_eq!(row, "7,3,2,\"Bob, Jr.\",2026-03-01 18:30:00,,hi\n");
+        assert_eq!(CSV_HEADER.split(',').count(), 7);
+    }
+
+    #[test]
+    fn markdown_escapes_names_and_quotes_every_line() {
+        let markdown = markdown_entry(&entry("**Bob**_", "first\nsecond"));
+
+        assert_eq!(
+            markdown,
+            "**\\*\\*Bob\\*\\*\\_** · 2026-03-01 18:30 ↪ reply to #3 `#7`\n\n> first\n> second\n\n"
+        );
+        assert_eq!(
+            markdown_title("[Raid] #1"),
+            "# \\[Raid\\] \\#1 — chat transcript\n\n"
+        );
+    }
+
+    #[test]
+    fn json_entries_are_comma_separated() {
+        let first = render_entry(TranscriptFormats::Json, &entry("Bob", "a"), true).unwrap();
+        let second = render_entry(TranscriptFormats::Json, &entry("Bob", "b"), false).unwrap();
+        let document = format!("[{}{}\n]\n", first, second);
+
+        let parsed: serde_json::Value = serde_json::from_str(&document).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 2);
+    }
+}
@@ -1,9 +1,24 @@
+pub mod achievements;
+pub mod attachments;
 pub mod authentication;
 pub mod brawlers;
+pub mod conversations;
 pub mod crew_operation;
+pub mod email_digests;
 pub mod friendships;
+pub mod mission_automation;
 pub mod mission_comment;
+pub mod mission_events;
 pub mod mission_management;
+pub mod mission_media;
 pub mod mission_operation;
+pub mod mission_rating;
+pub mod mission_transcript;
 pub mod mission_viewing;
+pub mod notification_preferences;
+pub mod notification_service;
 pub mod notifications;
+pub mod privacy;
+pub mod web_push;
+pub mod webhook_service;
+pub mod webhooks;
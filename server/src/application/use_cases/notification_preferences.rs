@@ -0,0 +1,77 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::notification_preferences::UpsertNotificationPreferenceEntity,
+    repositories::notification_preferences::NotificationPreferenceRepository,
+    value_objects::{
+        notification_channels::{NotificationChannels, NotificationPreferenceModel},
+        notification_types::NotificationTypes,
+    },
+};
+
+pub struct NotificationPreferenceUseCase {
+    repo: Arc<dyn NotificationPreferenceRepository>,
+}
+
+impl NotificationPreferenceUseCase {
+    pub fn new(repo: Arc<dyn NotificationPreferenceRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Every type and channel, with the defaults filled in
+    pub async fn get(&self, brawler_id: i32) -> Result<Vec<NotificationPreferenceModel>> {
+        let overrides = self.repo.get_by_brawler(brawler_id).await?;
+
+        let mut preferences = Vec::new();
+        for notification_type in NotificationTypes::ALL {
+            for channel in NotificationChannels::ALL {
+                let enabled = overrides
+                    .iter()
+                    .find(|pref| {
+                        pref.notification_type == notification_type.to_string()
+                            && pref.channel == channel.to_string()
+                    })
+                    .is_none_or(|pref| pref.enabled);
+
+                preferences.push(NotificationPreferenceModel {
+                    notification_type,
+                    channel,
+                    enabled,
+                });
+            }
+        }
+
+        Ok(preferences)
+    }
+
+    /// Only the given cells change; the rest keep their current value
+    pub async fn update(
+        &self,
+        brawler_id: i32,
+        changes: Vec<NotificationPreferenceModel>,
+    ) -> Result<Vec<NotificationPreferenceModel>> {
+        // One row per cell, the last value sent wins; Postgres rejects an upsert that
+        // touches the same row twice
+        let mut upserts: Vec<UpsertNotificationPreferenceEntity> = Vec::new();
+        for change in changes {
+            let notification_type = change.notification_type.to_string();
+            let channel = change.channel.to_string();
+            upserts.retain(|upsert| {
+                upsert.notification_type != notification_type || upsert.channel != channel
+            });
+            upserts.push(UpsertNotificationPreferenceEntity {
+                brawler_id,
+                notification_type,
+                channel,
+                enabled: change.enabled,
+            });
+        }
+
+        if !upserts.is_empty() {
+            self.repo.upsert(upserts).await?;
+        }
+
+        self.get(brawler_id).await
+    }
+}
@@ -0,0 +1,944 @@
+use anyhow::Result;
+use chrono::{NaiveDateTime, Utc};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::web_push::WebPushUseCase,
+    config::config_loader::get_notification_env,
+    domain::{
+        entities::notifications::{AddGroupedNotificationEntity, AddNotificationEntity},
+        repositories::{
+            mission_viewing::MissionViewingRepository,
+            notification_preferences::NotificationPreferenceRepository,
+            notifications::NotificationRepository, privacy::PrivacyRepository,
+        },
+        value_objects::{
+            attendance_statuses::AttendanceStatuses, mission_statuses::MissionStatuses,
+            notification_channels::NotificationChannels, notification_events::NotificationEvent,
+            notification_model::UnreadCountModel, notification_types::NotificationTypes,
+            web_push_model::PushPayloadModel,
+        },
+    },
+    infrastructure::websocket::{handler::WSMessage, manager::ConnectionManager},
+};
+
+const MISSION_STARTED: &str = "Mission '{mission}' has started!";
+const MISSION_COMPLETED: &str = "Mission '{mission}' has been COMPLETED!";
+const MISSION_FAILED: &str = "Mission '{mission}' has FAILED.";
+const MISSION_AUTO_COMPLETED: &str =
+    "Mission '{mission}' was closed as COMPLETED after passing its schedule.";
+const MISSION_AUTO_FAILED: &str = "Mission '{mission}' has FAILED after passing its schedule.";
+const MISSION_REMINDER: &str = "Reminder: mission '{mission}' starts at {scheduled_at} UTC.";
+const MISSION_REMINDER_UNSCHEDULED: &str = "Reminder: mission '{mission}' is coming up.";
+const MISSION_DELETED: &str = "Mission '{mission}' has been removed by the chief.";
+const CREW_JOINED: &str = "A new crew member joined your mission: {mission}";
+const CREW_LEFT: &str = "A crew member left your mission: {mission}";
+const KICKED_FROM_MISSION: &str = "You were removed from crew in mission: {mission}";
+const MARKED_NO_SHOW: &str = "You were marked as a no-show in mission: {mission}";
+const NEW_CHAT_MESSAGE: &str = "[{mission}] {sender}: \"{content}\"";
+const MENTION: &str = "{sender} mentioned you in [{mission}]: \"{content}\"";
+const MISSION_ANNOUNCEMENT: &str = "[{mission}] New announcement: \"{content}\"";
+/// No message body: the message can be unsent, but a copy here would outlive it
+const PRIVATE_MESSAGE: &str = "{sender} sent you a message";
+const FRIEND_REQUEST: &str = "User {requester} sent you a friend request";
+const FRIEND_ACCEPTED: &str = "User {friend} accepted your friend request";
+const ACHIEVEMENT_UNLOCKED: &str = "Achievement unlocked: {name} - {description}";
+
+// What a group reads once several notifications were folded into it
+const CREW_JOINED_GROUPED: &str = "{count} brawlers joined your mission: {mission}";
+const CREW_LEFT_GROUPED: &str = "{count} crew members left your mission: {mission}";
+const NEW_CHAT_MESSAGE_GROUPED: &str = "[{mission}] {count} new messages";
+const PRIVATE_MESSAGE_GROUPED: &str = "{count} new messages from {sender}";
+
+/// One notification fanned out to several brawlers
+struct Delivery {
+    recipient_ids: Vec<i32>,
+    notification_type: NotificationTypes,
+    content: String,
+    related_id: Option<i32>,
+    /// Set for chatty types, whose unread notifications fold into one row per `related_id`
+    grouped: Option<GroupedContent>,
+    /// Recipients who muted the actor are skipped
+    actor_id: Option<i32>,
+    /// Pushed over the global socket; `None` keeps it to the bell
+    toast: Option<WSMessage>,
+}
+
+/// What a group reads once it stands for more than one notification, split around the count
+struct GroupedContent {
+    prefix: String,
+    suffix: String,
+}
+
+enum Broadcast {
+    /// Every connected brawler, for live lists and dashboards
+    Everyone(WSMessage),
+    /// Whoever has the mission room open
+    Room(i32, WSMessage),
+}
+
+/// Turns `NotificationEvent`s into saved notifications, toasts and live updates.
+/// Delivery is best effort: failures are logged and never fail the action that caused them.
+pub struct NotificationService {
+    notification_repository: Arc<dyn NotificationRepository>,
+    preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+    privacy_repository: Arc<dyn PrivacyRepository>,
+    manager: Arc<ConnectionManager>,
+    web_push: Arc<WebPushUseCase>,
+}
+
+impl NotificationService {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        preference_repository: Arc<dyn NotificationPreferenceRepository>,
+        mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+        privacy_repository: Arc<dyn PrivacyRepository>,
+        manager: Arc<ConnectionManager>,
+        web_push: Arc<WebPushUseCase>,
+    ) -> Self {
+        Self {
+            notification_repository,
+            preference_repository,
+            mission_viewing_repository,
+            privacy_repository,
+            manager,
+            web_push,
+        }
+    }
+
+    pub async fn notify(&self, event: NotificationEvent) {
+        let (deliveries, broadcasts) = match self.resolve(event).await {
+            Ok(plan) => plan,
+            Err(e) => {
+                tracing::warn!("Failed to resolve notification: {:?}", e);
+                return;
+            }
+        };
+
+        for broadcast in broadcasts {
+            match broadcast {
+                Broadcast::Everyone(ws_msg) => self.manager.broadcast_all(ws_msg).await,
+                Broadcast::Room(mission_id, ws_msg) => {
+                    self.manager.broadcast(mission_id, ws_msg).await
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut groups = Vec::new();
+        let mut toasts = Vec::new();
+        let mut pushes = Vec::new();
+        for delivery in deliveries {
+            let recipient_ids = self
+                .without_muters(delivery.recipient_ids, delivery.actor_id)
+                .await;
+            let in_app_ids = self
+                .recipients_for(
+                    recipient_ids.clone(),
+                    delivery.notification_type,
+                    NotificationChannels::InApp,
+                )
+                .await;
+
+            if self.web_push.is_enabled() && delivery.notification_type.is_high_priority() {
+                let push_ids = self
+                    .offline_push_recipients(recipient_ids.clone(), delivery.notification_type)
+                    .await;
+                if !push_ids.is_empty() {
+                    pushes.push((
+                        push_ids,
+                        PushPayloadModel {
+                            title: "Brawlers".to_string(),
+                            body: delivery.content.clone(),
+                            notification_type: delivery.notification_type.to_string(),
+                            related_id: delivery.related_id,
+                            tag: match delivery.related_id {
+                                Some(related_id) => {
+                                    format!("{}:{}", delivery.notification_type, related_id)
+                                }
+                                None => delivery.notification_type.to_string(),
+                            },
+                        },
+                    ));
+                }
+            }
+
+            match (delivery.grouped, delivery.related_id) {
+                (Some(grouped), Some(related_id)) => groups.push(AddGroupedNotificationEntity {
+                    brawler_ids: in_app_ids,
+                    type_: delivery.notification_type.to_string(),
+                    content: delivery.content,
+                    related_id,
+                    count_prefix: grouped.prefix,
+                    count_suffix: grouped.suffix,
+                }),
+                _ => rows.extend(
+                    in_app_ids
+                        .into_iter()
+                        .map(|brawler_id| AddNotificationEntity {
+                            brawler_id,
+                            type_: delivery.notification_type.to_string(),
+                            content: delivery.content.clone(),
+                            related_id: delivery.related_id,
+                        }),
+                ),
+            }
+
+            if let Some(toast) = delivery.toast {
+                for brawler_id in self
+                    .recipients_for(
+                        recipient_ids,
+                        delivery.notification_type,
+                        NotificationChannels::RealTime,
+                    )
+                    .await
+                {
+                    toasts.push((brawler_id, toast.clone()));
+                }
+            }
+        }
+
+        let mut saved_for: Vec<i32> = rows
+            .iter()
+            .map(|row| row.brawler_id)
+            .chain(groups.iter().flat_map(|group| group.brawler_ids.clone()))
+            .collect();
+        saved_for.sort_unstable();
+        saved_for.dedup();
+
+        match self.save(rows, groups).await {
+            Ok(_) => self.push_unread_counts(saved_for).await,
+            Err(e) => tracing::warn!("Failed to save notifications: {:?}", e),
+        }
+        for (brawler_id, toast) in toasts {
+            self.manager.notify_user(brawler_id, toast).await;
+        }
+        // Push services can be slow, so the action that caused the notification doesn't wait
+        for (brawler_ids, payload) in pushes {
+            let web_push = Arc::clone(&self.web_push);
+            tokio::spawn(async move { web_push.send(brawler_ids, payload).await });
+        }
+    }
+
+    async fn save(
+        &self,
+        rows: Vec<AddNotificationEntity>,
+        groups: Vec<AddGroupedNotificationEntity>,
+    ) -> Result<()> {
+        self.notification_repository.add_many(rows).await?;
+
+        if groups.is_empty() {
+            return Ok(());
+        }
+        let since = group_window_start(
+            Utc::now().naive_utc(),
+            get_notification_env()?.group_window_minutes,
+        );
+        for group in groups {
+            self.notification_repository
+                .add_grouped(group, since)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends each brawler their current unread count, so badges stay right without polling
+    pub async fn push_unread_counts(&self, brawler_ids: Vec<i32>) {
+        let counts = match self
+            .notification_repository
+            .count_unread_many(brawler_ids.clone())
+            .await
+        {
+            Ok(counts) => counts,
+            Err(e) => {
+                tracing::warn!("Failed to count unread notifications: {:?}", e);
+                return;
+            }
+        };
+
+        for brawler_id in brawler_ids {
+            let unread_count = counts
+                .iter()
+                .find(|(id, _)| *id == brawler_id)
+                .map_or(0, |(_, count)| *count);
+
+            self.manager
+                .notify_user(
+                    brawler_id,
+                    WSMessage {
+                        msg_type: "unread_count".to_string(),
+                        data: json!(UnreadCountModel { unread_count }),
+                    },
+                )
+                .await;
+        }
+    }
+
+    /// The recipients who still want `notification_type` on `channel`
+    pub async fn find_recipients(
+        &self,
+        recipient_ids: Vec<i32>,
+        notification_type: NotificationTypes,
+        channel: NotificationChannels,
+    ) -> Result<Vec<i32>> {
+        let opted_out = self
+            .preference_repository
+            .find_opted_out(recipient_ids.clone(), notification_type, channel)
+            .await?;
+
+        Ok(recipient_ids
+            .into_iter()
+            .filter(|id| !opted_out.contains(id))
+            .collect())
+    }
+
+    /// Falls back to delivering when preferences can't be read; a missed opt-out is the
+    /// lesser evil next to a silently dropped notification
+    async fn recipients_for(
+        &self,
+        recipient_ids: Vec<i32>,
+        notification_type: NotificationTypes,
+        channel: NotificationChannels,
+    ) -> Vec<i32> {
+        match self
+            .find_recipients(recipient_ids.clone(), notification_type, channel)
+            .await
+        {
+            Ok(recipients) => recipients,
+            Err(e) => {
+                tracing::warn!("Failed to read notification preferences: {:?}", e);
+                recipient_ids
+            }
+        }
+    }
+
+    /// Web Push stands in for the toast, so only brawlers without an open socket get one
+    async fn offline_push_recipients(
+        &self,
+        recipient_ids: Vec<i32>,
+        notification_type: NotificationTypes,
+    ) -> Vec<i32> {
+        let mut offline_ids = Vec::new();
+        for brawler_id in recipient_ids {
+            if !self.manager.is_online(brawler_id).await {
+                offline_ids.push(brawler_id);
+            }
+        }
+
+        self.recipients_for(
+            offline_ids,
+            notification_type,
+            NotificationChannels::WebPush,
+        )
+        .await
+    }
+
+    async fn without_muters(&self, recipient_ids: Vec<i32>, actor_id: Option<i32>) -> Vec<i32> {
+        let Some(actor_id) = actor_id else {
+            return recipient_ids;
+        };
+        let muter_ids = self
+            .privacy_repository
+            .find_muters(actor_id, recipient_ids.clone())
+            .await
+            .unwrap_or_default();
+
+        recipient_ids
+            .into_iter()
+            .filter(|id| !muter_ids.contains(id))
+            .collect()
+    }
+
+    async fn crew_ids(&self, mission_id: i32) -> Result<Vec<i32>> {
+        Ok(self
+            .mission_viewing_repository
+            .get_crew(mission_id)
+            .await?
+            .into_iter()
+            .map(|member| member.id)
+            .collect())
+    }
+
+    async fn resolve(&self, event: NotificationEvent) -> Result<(Vec<Delivery>, Vec<Broadcast>)> {
+        let plan = match event {
+            NotificationEvent::MissionStarted { mission_id } => {
+                let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                let ws_msg = WSMessage {
+                    msg_type: NotificationTypes::MissionStarted.to_string(),
+                    data: json!({
+                        "mission_id": mission_id,
+                        "mission_name": mission.name,
+                        "new_status": MissionStatuses::InProgress.to_string()
+                    }),
+                };
+
+                (
+                    vec![Delivery {
+                        recipient_ids: self.crew_ids(mission_id).await?,
+                        notification_type: NotificationTypes::MissionStarted,
+                        content: render(MISSION_STARTED, &[("mission", &mission.name)]),
+                        related_id: Some(mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(ws_msg.clone()),
+                    }],
+                    vec![
+                        Broadcast::Everyone(ws_msg.clone()),
+                        Broadcast::Room(mission_id, ws_msg),
+                    ],
+                )
+            }
+            NotificationEvent::MissionClosed {
+                mission_id,
+                status,
+                automatic,
+            } => {
+                let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                let (notification_type, template) = match (&status, automatic) {
+                    (MissionStatuses::Completed, false) => {
+                        (NotificationTypes::MissionCompleted, MISSION_COMPLETED)
+                    }
+                    (MissionStatuses::Completed, true) => {
+                        (NotificationTypes::MissionCompleted, MISSION_AUTO_COMPLETED)
+                    }
+                    (_, false) => (NotificationTypes::MissionFailed, MISSION_FAILED),
+                    (_, true) => (NotificationTypes::MissionFailed, MISSION_AUTO_FAILED),
+                };
+                let content = render(template, &[("mission", &mission.name)]);
+                let ws_msg = WSMessage {
+                    msg_type: notification_type.to_string(),
+                    data: json!({
+                        "mission_id": mission_id,
+                        "mission_name": mission.name,
+                        "new_status": status.to_string()
+                    }),
+                };
+                let mut crew_ids = self.crew_ids(mission_id).await?;
+                crew_ids.retain(|id| *id != mission.chief_id);
+
+                // The chief finds it in the bell; the crew also gets a toast
+                (
+                    vec![
+                        Delivery {
+                            recipient_ids: vec![mission.chief_id],
+                            notification_type,
+                            content: content.clone(),
+                            related_id: Some(mission_id),
+                            grouped: None,
+                            actor_id: None,
+                            toast: None,
+                        },
+                        Delivery {
+                            recipient_ids: crew_ids,
+                            notification_type,
+                            content,
+                            related_id: Some(mission_id),
+                            grouped: None,
+                            actor_id: None,
+                            toast: Some(ws_msg.clone()),
+                        },
+                    ],
+                    vec![
+                        Broadcast::Everyone(ws_msg.clone()),
+                        Broadcast::Room(mission_id, ws_msg),
+                    ],
+                )
+            }
+            NotificationEvent::MissionReminder { mission_id } => {
+                let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                let content = match mission.scheduled_at {
+                    Some(scheduled_at) => render(
+                        MISSION_REMINDER,
+                        &[
+                            ("mission", &mission.name),
+                            (
+                                "scheduled_at",
+                                &scheduled_at.format("%Y-%m-%d %H:%M").to_string(),
+                            ),
+                        ],
+                    ),
+                    None => render(MISSION_REMINDER_UNSCHEDULED, &[("mission", &mission.name)]),
+                };
+                let mut recipient_ids = self.crew_ids(mission_id).await?;
+                if !recipient_ids.contains(&mission.chief_id) {
+                    recipient_ids.push(mission.chief_id);
+                }
+
+                (
+                    vec![Delivery {
+                        recipient_ids,
+                        notification_type: NotificationTypes::MissionReminder,
+                        content,
+                        related_id: Some(mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(WSMessage {
+                            msg_type: NotificationTypes::MissionReminder.to_string(),
+                            data: json!({
+                                "mission_id": mission_id,
+                                "mission_name": mission.name,
+                                "scheduled_at": mission.scheduled_at,
+                            }),
+                        }),
+                    }],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::MissionDeleted {
+                mission_id,
+                mission_name,
+                deleted_by,
+                crew_ids,
+            } => {
+                let ws_msg = WSMessage {
+                    msg_type: NotificationTypes::MissionDeleted.to_string(),
+                    data: json!({
+                        "mission_id": mission_id,
+                        "mission_name": mission_name,
+                    }),
+                };
+
+                (
+                    vec![Delivery {
+                        recipient_ids: crew_ids
+                            .into_iter()
+                            .filter(|id| *id != deleted_by)
+                            .collect(),
+                        notification_type: NotificationTypes::MissionDeleted,
+                        content: render(MISSION_DELETED, &[("mission", &mission_name)]),
+                        related_id: Some(mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(ws_msg.clone()),
+                    }],
+                    vec![Broadcast::Room(mission_id, ws_msg)],
+                )
+            }
+            NotificationEvent::CrewJoined {
+                mission_id,
+                brawler_id,
+            } => {
+                self.crew_change(
+                    mission_id,
+                    brawler_id,
+                    NotificationTypes::NewCrewJoined,
+                    CREW_JOINED,
+                    CREW_JOINED_GROUPED,
+                )
+                .await?
+            }
+            NotificationEvent::CrewLeft {
+                mission_id,
+                brawler_id,
+            } => {
+                self.crew_change(
+                    mission_id,
+                    brawler_id,
+                    NotificationTypes::CrewLeft,
+                    CREW_LEFT,
+                    CREW_LEFT_GROUPED,
+                )
+                .await?
+            }
+            NotificationEvent::KickedFromMission {
+                mission_id,
+                brawler_id,
+            } => {
+                let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                let ws_msg = WSMessage {
+                    msg_type: NotificationTypes::KickedFromMission.to_string(),
+                    data: json!({
+                        "mission_id": mission_id,
+                        "mission_name": mission.name,
+                        "brawler_id": brawler_id,
+                    }),
+                };
+
+                (
+                    vec![Delivery {
+                        recipient_ids: vec![brawler_id],
+                        notification_type: NotificationTypes::KickedFromMission,
+                        content: render(KICKED_FROM_MISSION, &[("mission", &mission.name)]),
+                        related_id: Some(mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(ws_msg.clone()),
+                    }],
+                    vec![
+                        Broadcast::Everyone(ws_msg.clone()),
+                        Broadcast::Room(mission_id, ws_msg),
+                    ],
+                )
+            }
+            NotificationEvent::MarkedNoShow {
+                mission_id,
+                brawler_ids,
+            } => {
+                let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                let content = render(MARKED_NO_SHOW, &[("mission", &mission.name)]);
+
+                // The toast is the same attendance update the room gets
+                let deliveries = brawler_ids
+                    .into_iter()
+                    .map(|brawler_id| Delivery {
+                        recipient_ids: vec![brawler_id],
+                        notification_type: NotificationTypes::MarkedNoShow,
+                        content: content.clone(),
+                        related_id: Some(mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(WSMessage {
+                            msg_type: "attendance_updated".to_string(),
+                            data: json!({
+                                "mission_id": mission_id,
+                                "brawler_id": brawler_id,
+                                "attendance_status": AttendanceStatuses::NoShow.to_string(),
+                            }),
+                        }),
+                    })
+                    .collect();
+
+                (deliveries, Vec::new())
+            }
+            NotificationEvent::ChatMessage {
+                comment,
+                mentioned_ids,
+            } => {
+                let mission = self
+                    .mission_viewing_repository
+                    .get_one(comment.mission_id)
+                    .await?;
+                let vars = [
+                    ("mission", mission.name.as_str()),
+                    ("sender", comment.brawler_display_name.as_str()),
+                    ("content", comment.content.as_str()),
+                ];
+
+                // Chief and crew except the sender; mentioned people get a mention instead
+                let mut recipient_ids = self.crew_ids(comment.mission_id).await?;
+                recipient_ids.push(mission.chief_id);
+                recipient_ids.retain(|id| *id != comment.brawler_id && !mentioned_ids.contains(id));
+
+                (
+                    vec![
+                        Delivery {
+                            recipient_ids,
+                            notification_type: NotificationTypes::NewChatMessage,
+                            content: render(NEW_CHAT_MESSAGE, &vars),
+                            related_id: Some(comment.mission_id),
+                            grouped: Some(render_grouped(NEW_CHAT_MESSAGE_GROUPED, &vars)),
+                            actor_id: Some(comment.brawler_id),
+                            toast: Some(WSMessage {
+                                msg_type: NotificationTypes::NewChatMessage.to_string(),
+                                data: json!({
+                                    "mission_id": comment.mission_id,
+                                    "mission_name": mission.name,
+                                    "sender_name": comment.brawler_display_name,
+                                    "content": comment.content,
+                                }),
+                            }),
+                        },
+                        // Mentioned people hear about it whether or not they are in the room
+                        Delivery {
+                            recipient_ids: mentioned_ids,
+                            notification_type: NotificationTypes::Mention,
+                            content: render(MENTION, &vars),
+                            related_id: Some(comment.mission_id),
+                            grouped: None,
+                            actor_id: Some(comment.brawler_id),
+                            toast: Some(WSMessage {
+                                msg_type: NotificationTypes::Mention.to_string(),
+                                data: json!({
+                                    "mission_id": comment.mission_id,
+                                    "mission_name": mission.name,
+                                    "comment_id": comment.id,
+                                    "sender_id": comment.brawler_id,
+                                    "sender_name": comment.brawler_display_name,
+                                    "content": comment.content,
+                                }),
+                            }),
+                        },
+                    ],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::AnnouncementPinned { comment, pinned_by } => {
+                let mission = self
+                    .mission_viewing_repository
+                    .get_one(comment.mission_id)
+                    .await?;
+                let mut recipient_ids = self.crew_ids(comment.mission_id).await?;
+                recipient_ids.retain(|id| *id != pinned_by);
+
+                // Announcements reach the whole crew, muted chief or not
+                (
+                    vec![Delivery {
+                        recipient_ids,
+                        notification_type: NotificationTypes::MissionAnnouncement,
+                        content: render(
+                            MISSION_ANNOUNCEMENT,
+                            &[("mission", &mission.name), ("content", &comment.content)],
+                        ),
+                        related_id: Some(comment.mission_id),
+                        grouped: None,
+                        actor_id: None,
+                        toast: Some(WSMessage {
+                            msg_type: NotificationTypes::MissionAnnouncement.to_string(),
+                            data: json!({
+                                "mission_id": comment.mission_id,
+                                "mission_name": mission.name,
+                                "comment_id": comment.id,
+                                "content": comment.content,
+                            }),
+                        }),
+                    }],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::PrivateMessage { message } => {
+                let sender_name = message
+                    .sender_display_name
+                    .clone()
+                    .unwrap_or_else(|| "Agent".to_string());
+
+                // Only the bell: the message itself already reaches an open socket
+                (
+                    vec![Delivery {
+                        recipient_ids: vec![message.receiver_id],
+                        notification_type: NotificationTypes::PrivateMessage,
+                        content: render(PRIVATE_MESSAGE, &[("sender", &sender_name)]),
+                        related_id: Some(message.sender_id),
+                        grouped: Some(render_grouped(
+                            PRIVATE_MESSAGE_GROUPED,
+                            &[("sender", &sender_name)],
+                        )),
+                        actor_id: Some(message.sender_id),
+                        toast: None,
+                    }],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::FriendRequested {
+                requester_id,
+                requester_name,
+                receiver_id,
+            } => {
+                let content = render(FRIEND_REQUEST, &[("requester", &requester_name)]);
+                let ws_msg = WSMessage {
+                    msg_type: "notification".to_string(),
+                    data: json!({
+                        "type": NotificationTypes::FriendRequest.to_string(),
+                        "requester_id": requester_id,
+                        "requester_name": requester_name,
+                        "content": content
+                    }),
+                };
+
+                (
+                    vec![Delivery {
+                        recipient_ids: vec![receiver_id],
+                        notification_type: NotificationTypes::FriendRequest,
+                        content,
+                        related_id: Some(requester_id),
+                        grouped: None,
+                        actor_id: Some(requester_id),
+                        toast: Some(ws_msg),
+                    }],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::FriendAccepted {
+                friend_id,
+                friend_name,
+                requester_id,
+            } => {
+                let content = render(FRIEND_ACCEPTED, &[("friend", &friend_name)]);
+                let ws_msg = WSMessage {
+                    msg_type: "notification".to_string(),
+                    data: json!({
+                        "type": NotificationTypes::FriendAccepted.to_string(),
+                        "friend_id": friend_id,
+                        "content": content
+                    }),
+                };
+
+                (
+                    vec![Delivery {
+                        recipient_ids: vec![requester_id],
+                        notification_type: NotificationTypes::FriendAccepted,
+                        content,
+                        related_id: Some(friend_id),
+                        grouped: None,
+                        actor_id: Some(friend_id),
+                        toast: Some(ws_msg),
+                    }],
+                    Vec::new(),
+                )
+            }
+            NotificationEvent::AchievementUnlocked {
+                brawler_id,
+                achievement,
+            } => (
+                vec![Delivery {
+                    recipient_ids: vec![brawler_id],
+                    notification_type: NotificationTypes::AchievementUnlocked,
+                    content: render(
+                        ACHIEVEMENT_UNLOCKED,
+                        &[
+                            ("name", &achievement.name),
+                            ("description", &achievement.description),
+                        ],
+                    ),
+                    related_id: None,
+                    grouped: None,
+                    actor_id: None,
+                    toast: Some(WSMessage {
+                        msg_type: NotificationTypes::AchievementUnlocked.to_string(),
+                        data: serde_json::to_value(&achievement).unwrap_or_default(),
+                    }),
+                }],
+                Vec::new(),
+            ),
+        };
+
+        Ok(plan)
+    }
+
+    /// Someone joined or left: the chief is told, lists and the room update live
+    async fn crew_change(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        notification_type: NotificationTypes,
+        template: &str,
+        grouped_template: &str,
+    ) -> Result<(Vec<Delivery>, Vec<Broadcast>)> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let ws_msg = WSMessage {
+            msg_type: notification_type.to_string(),
+            data: json!({
+                "mission_id": mission_id,
+                "mission_name": mission.name,
+                "brawler_id": brawler_id
+            }),
+        };
+
+        Ok((
+            vec![Delivery {
+                recipient_ids: vec![mission.chief_id],
+                notification_type,
+                content: render(template, &[("mission", &mission.name)]),
+                related_id: Some(mission_id),
+                grouped: Some(render_grouped(
+                    grouped_template,
+                    &[("mission", &mission.name)],
+                )),
+                actor_id: Some(brawler_id),
+                toast: Some(ws_msg.clone()),
+            }],
+            vec![
+                Broadcast::Everyone(ws_msg.clone()),
+                Broadcast::Room(mission_id, ws_msg),
+            ],
+        ))
+    }
+}
+
+/// Unread groups touched after this still take new notifications; older ones are left alone
+fn group_window_start(now: NaiveDateTime, window_minutes: i64) -> NaiveDateTime {
+    now - chrono::Duration::minutes(window_minutes)
+}
+
+/// Renders everything but `{count}`, which the database fills in as the group grows
+fn render_grouped(template: &str, vars: &[(&str, &str)]) -> GroupedContent {
+    let (prefix, suffix) = template.split_once("{count}").unwrap_or((template, ""));
+
+    GroupedContent {
+        prefix: render(prefix, vars),
+        suffix: render(suffix, vars),
+    }
+}
+
+/// Fills `{name}` placeholders in one pass, so values that contain braces are left alone
+fn render(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        match after.find('}').and_then(|end| {
+            vars.iter()
+                .find(|(key, _)| *key == &after[..end])
+                .map(|(_, value)| (end, value))
+        }) {
+            Some((end, value)) => {
+                rendered.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = after;
+            }
+        }
+    }
+    rendered.push_str(rest);
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_window_reaches_back_the_configured_minutes() {
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 3, 1)
+            .unwrap()
+            .and_hms_opt(0, 5, 0)
+            .unwrap();
+        let since = group_window_start(now, 10);
+
+        assert_eq!(
+            since,
+            chrono::NaiveDate::from_ymd_opt(2026, 2, 28)
+                .unwrap()
+                .and_hms_opt(23, 55, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn grouped_content_splits_around_the_count() {
+        let grouped = render_grouped(CREW_JOINED_GROUPED, &[("mission", "Night Raid")]);
+
+        assert_eq!(grouped.prefix, "");
+        assert_eq!(grouped.suffix, " brawlers joined your mission: Night Raid");
+
+        let grouped = render_grouped(NEW_CHAT_MESSAGE_GROUPED, &[("mission", "Night Raid")]);
+
+        assert_eq!(grouped.prefix, "[Night Raid] ");
+        assert_eq!(grouped.suffix, " new messages");
+    }
+
+    #[test]
+    fn values_with_braces_are_not_rendered_again() {
+        let rendered = render(
+            MENTION,
+            &[
+                ("sender", "{content}"),
+                ("mission", "Raid"),
+                ("content", "hi {sender}"),
+            ],
+        );
+
+        assert_eq!(
+            rendered,
+            "{content} mentioned you in [Raid]: \"hi {sender}\""
+        );
+    }
+}
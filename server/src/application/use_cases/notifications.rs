@@ -1,44 +1,112 @@
-use crate::domain::{
-    entities::notifications::{AddNotificationEntity, NotificationEntity},
-    repositories::notifications::NotificationRepository,
+use crate::{
+    application::use_cases::notification_service::NotificationService,
+    domain::{
+        entities::notifications::NotificationEntity,
+        repositories::notifications::NotificationRepository,
+        value_objects::{
+            notification_model::{
+                NotificationCursor, NotificationFilter, NotificationPageQuery, UnreadCountModel,
+            },
+            notification_types::NotificationTypes,
+        },
+    },
 };
-use anyhow::Result;
-use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use std::{str::FromStr, sync::Arc};
+
+const DEFAULT_NOTIFICATION_PAGE_SIZE: i64 = 20;
+const MAX_NOTIFICATION_PAGE_SIZE: i64 = 100;
 
 pub struct NotificationUseCase {
     repo: Arc<dyn NotificationRepository>,
+    notification_service: Arc<NotificationService>,
 }
 
 impl NotificationUseCase {
-    pub fn new(repo: Arc<dyn NotificationRepository>) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: Arc<dyn NotificationRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            repo,
+            notification_service,
+        }
     }
 
-    pub async fn get_my_notifications(&self, user_id: i32) -> Result<Vec<NotificationEntity>> {
-        self.repo.get_by_user(user_id).await
+    pub async fn get_my_notifications(
+        &self,
+        user_id: i32,
+        query: NotificationPageQuery,
+    ) -> Result<Vec<NotificationEntity>> {
+        let type_ = match query.type_.as_deref() {
+            Some(type_) => Some(NotificationTypes::from_str(type_)?.to_string()),
+            None => None,
+        };
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_NOTIFICATION_PAGE_SIZE)
+            .clamp(1, MAX_NOTIFICATION_PAGE_SIZE);
+        let cursor = match (query.cursor, query.cursor_updated_at) {
+            (Some(id), Some(updated_at)) => Some(NotificationCursor { updated_at, id }),
+            (None, None) => None,
+            _ => {
+                return Err(anyhow!(
+                    "cursor and cursor_updated_at must be given together"
+                ));
+            }
+        };
+
+        self.repo
+            .get_page(
+                user_id,
+                NotificationFilter {
+                    type_,
+                    unread_only: query.unread_only,
+                },
+                cursor,
+                limit,
+            )
+            .await
+    }
+
+    pub async fn unread_count(&self, user_id: i32) -> Result<UnreadCountModel> {
+        Ok(UnreadCountModel {
+            unread_count: self.repo.count_unread(user_id).await?,
+        })
     }
 
     pub async fn mark_as_read(&self, notification_id: i32, user_id: i32) -> Result<()> {
-        self.repo.mark_as_read(notification_id, user_id).await
+        self.repo.mark_as_read(notification_id, user_id).await?;
+        self.notification_service
+            .push_unread_counts(vec![user_id])
+            .await;
+        Ok(())
     }
 
     pub async fn mark_all_as_read(&self, user_id: i32) -> Result<()> {
-        self.repo.mark_all_as_read(user_id).await
+        self.repo.mark_all_as_read(user_id).await?;
+        self.notification_service
+            .push_unread_counts(vec![user_id])
+            .await;
+        Ok(())
     }
 
-    pub async fn save_notification(
-        &self,
-        user_id: i32,
-        msg_type: &str,
-        content: &str,
-        related_id: Option<i32>,
-    ) -> Result<NotificationEntity> {
-        let entity = AddNotificationEntity {
-            brawler_id: user_id,
-            type_: msg_type.to_string(),
-            content: content.to_string(),
-            related_id,
-        };
-        self.repo.add(entity).await
+    /// `false` when the brawler has no such notification
+    pub async fn delete(&self, notification_id: i32, user_id: i32) -> Result<bool> {
+        if !self.repo.delete(notification_id, user_id).await? {
+            return Ok(false);
+        }
+        self.notification_service
+            .push_unread_counts(vec![user_id])
+            .await;
+        Ok(true)
+    }
+
+    pub async fn clear(&self, user_id: i32) -> Result<()> {
+        self.repo.delete_for_user(user_id).await?;
+        self.notification_service
+            .push_unread_counts(vec![user_id])
+            .await;
+        Ok(())
     }
 }
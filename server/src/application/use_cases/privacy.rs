@@ -0,0 +1,116 @@
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::{friendship_repository::FriendshipRepository, privacy::PrivacyRepository},
+    value_objects::dm_privacy::DmPrivacy,
+};
+
+pub struct PrivacyUseCase {
+    repo: Arc<dyn PrivacyRepository>,
+    friendship_repo: Arc<dyn FriendshipRepository>,
+}
+
+impl PrivacyUseCase {
+    pub fn new(
+        repo: Arc<dyn PrivacyRepository>,
+        friendship_repo: Arc<dyn FriendshipRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            friendship_repo,
+        }
+    }
+
+    /// Blocking also ends any friendship or pending request between the two
+    pub async fn block(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        if blocker_id == blocked_id {
+            return Err(anyhow!("Cannot block yourself"));
+        }
+
+        self.repo.block(blocker_id, blocked_id).await?;
+
+        if let Some(friendship) = self
+            .friendship_repo
+            .find_by_users(blocker_id, blocked_id)
+            .await
+            .map_err(|e| anyhow!(e))?
+        {
+            self.friendship_repo
+                .delete(friendship.id)
+                .await
+                .map_err(|e| anyhow!(e))?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn unblock(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        self.repo.unblock(blocker_id, blocked_id).await
+    }
+
+    pub async fn list_blocked(&self, blocker_id: i32) -> Result<Vec<i32>> {
+        self.repo.list_blocked(blocker_id).await
+    }
+
+    pub async fn mute(&self, muter_id: i32, muted_id: i32) -> Result<()> {
+        if muter_id == muted_id {
+            return Err(anyhow!("Cannot mute yourself"));
+        }
+        self.repo.mute(muter_id, muted_id).await
+    }
+
+    pub async fn unmute(&self, muter_id: i32, muted_id: i32) -> Result<()> {
+        self.repo.unmute(muter_id, muted_id).await
+    }
+
+    pub async fn list_muted(&self, muter_id: i32) -> Result<Vec<i32>> {
+        self.repo.list_muted(muter_id).await
+    }
+
+    pub async fn get_dm_privacy(&self, brawler_id: i32) -> Result<DmPrivacy> {
+        self.repo.get_dm_privacy(brawler_id).await
+    }
+
+    pub async fn set_dm_privacy(&self, brawler_id: i32, dm_privacy: DmPrivacy) -> Result<()> {
+        self.repo.set_dm_privacy(brawler_id, dm_privacy).await
+    }
+
+    /// Fails when a block exists either way or the receiver's DM privacy shuts the sender out
+    pub async fn ensure_can_message(&self, sender_id: i32, receiver_id: i32) -> Result<()> {
+        if sender_id == receiver_id {
+            return Err(anyhow!("Cannot message yourself"));
+        }
+
+        if self.repo.is_blocked_between(sender_id, receiver_id).await? {
+            return Err(anyhow!("You can't message this user"));
+        }
+
+        match self.repo.get_dm_privacy(receiver_id).await? {
+            DmPrivacy::Everyone => Ok(()),
+            DmPrivacy::Nobody => Err(anyhow!("This user doesn't accept messages")),
+            DmPrivacy::Friends => {
+                let is_friend = self
+                    .friendship_repo
+                    .find_by_users(sender_id, receiver_id)
+                    .await
+                    .map_err(|e| anyhow!(e))?
+                    .is_some_and(|f| f.status == "accepted");
+                if is_friend {
+                    Ok(())
+                } else {
+                    Err(anyhow!("This user only accepts messages from friends"))
+                }
+            }
+        }
+    }
+
+    /// Whether `recipient_id` muted `actor_id`
+    pub async fn is_muted(&self, recipient_id: i32, actor_id: i32) -> Result<bool> {
+        Ok(!self
+            .repo
+            .find_muters(actor_id, vec![recipient_id])
+            .await?
+            .is_empty())
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{Result, anyhow};
+use chrono::DateTime;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{
+    config::{config_loader::get_stage, stage::Stage},
+    domain::{
+        entities::push_subscriptions::UpsertPushSubscriptionEntity,
+        repositories::{push_sender::PushSender, push_subscriptions::PushSubscriptionRepository},
+        value_objects::web_push_model::{
+            PushOutcomes, PushPayloadModel, PushSubscriptionModel, VapidPublicKeyModel,
+        },
+    },
+    infrastructure::{
+        web_push::{encryption::MAX_PAYLOAD_LEN, vapid::decode_base64url},
+        webhooks::address::resolve_public,
+    },
+};
+
+/// Leaves room for the rest of the payload JSON
+const MAX_BODY_CHARS: usize = 1000;
+
+pub struct WebPushUseCase {
+    repo: Arc<dyn PushSubscriptionRepository>,
+    /// `None` when no VAPID key is configured
+    sender: Option<Arc<dyn PushSender>>,
+}
+
+impl WebPushUseCase {
+    pub fn new(
+        repo: Arc<dyn PushSubscriptionRepository>,
+        sender: Option<Arc<dyn PushSender>>,
+    ) -> Self {
+        Self { repo, sender }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    pub fn vapid_public_key(&self) -> Result<VapidPublicKeyModel> {
+        let sender = self.enabled_sender()?;
+        Ok(VapidPublicKeyModel {
+            public_key: sender.public_key(),
+        })
+    }
+
+    pub async fn subscribe(&self, brawler_id: i32, model: PushSubscriptionModel) -> Result<()> {
+        self.enabled_sender()?;
+
+        let endpoint = reqwest::Url::parse(&model.endpoint)
+            .map_err(|_| anyhow!("Push endpoint must be a URL"))?;
+        // Plain http is only for the local mock push service
+        if endpoint.scheme() != "https" && get_stage() != Stage::Local {
+            return Err(anyhow!("Push endpoint must use https"));
+        }
+        // Sending checks again, this only turns away obviously internal targets up front
+        resolve_public(&endpoint).await?;
+        if decode_base64url(&model.keys.p256dh).map_or(true, |key| key.len() != 65) {
+            return Err(anyhow!("p256dh must be an uncompressed P-256 public key"));
+        }
+        if decode_base64url(&model.keys.auth).map_or(true, |auth| auth.len() != 16) {
+            return Err(anyhow!("auth must be a 16-byte secret"));
+        }
+
+        let expires_at = match model.expiration_time {
+            Some(millis) => Some(
+                DateTime::from_timestamp_millis(millis)
+                    .ok_or_else(|| anyhow!("Invalid expirationTime"))?
+                    .naive_utc(),
+            ),
+            None => None,
+        };
+
+        let written = self
+            .repo
+            .upsert(UpsertPushSubscriptionEntity {
+                brawler_id,
+                endpoint: model.endpoint,
+                p256dh: model.keys.p256dh,
+                auth: model.keys.auth,
+                expires_at,
+            })
+            .await?;
+        if !written {
+            return Err(anyhow!(
+                "This push endpoint is registered to another brawler"
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn unsubscribe(&self, brawler_id: i32, endpoint: &str) -> Result<()> {
+        if !self.repo.remove(brawler_id, endpoint).await? {
+            return Err(anyhow!("Push subscription not found"));
+        }
+        Ok(())
+    }
+
+    /// Pushes to every browser the brawlers subscribed; subscriptions the push service
+    /// reports as gone are deleted on the spot
+    pub async fn send(&self, brawler_ids: Vec<i32>, mut payload: PushPayloadModel) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+
+        let subscriptions = match self.repo.find_by_brawlers(brawler_ids).await {
+            Ok(subscriptions) => subscriptions,
+            Err(e) => {
+                warn!("Failed to load push subscriptions: {:?}", e);
+                return;
+            }
+        };
+        if subscriptions.is_empty() {
+            return;
+        }
+
+        if payload.body.chars().count() > MAX_BODY_CHARS {
+            payload.body = payload
+                .body
+                .chars()
+                .take(MAX_BODY_CHARS)
+                .collect::<String>()
+                + "…";
+        }
+        let body = match serde_json::to_vec(&payload) {
+            Ok(body) if body.len() <= MAX_PAYLOAD_LEN => body,
+            Ok(_) => {
+                warn!("Push payload for {} is too large", payload.tag);
+                return;
+            }
+            Err(e) => {
+                warn!("Failed to serialize push payload: {:?}", e);
+                return;
+            }
+        };
+
+        let mut gone_ids = Vec::new();
+        for subscription in subscriptions {
+            match sender.send(&subscription, &body).await {
+                Ok(PushOutcomes::Delivered) => {}
+                Ok(PushOutcomes::Gone) => gone_ids.push(subscription.id),
+                Err(e) => warn!(
+                    "Failed to push to subscription {}: {:?}",
+                    subscription.id, e
+                ),
+            }
+        }
+
+        match self.repo.remove_many(gone_ids).await {
+            Ok(pruned) if pruned > 0 => info!("Pruned {} expired push subscriptions", pruned),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to prune push subscriptions: {:?}", e),
+        }
+    }
+
+    fn enabled_sender(&self) -> Result<&Arc<dyn PushSender>> {
+        self.sender
+            .as_ref()
+            .ok_or_else(|| anyhow!("Web Push is not configured on this server"))
+    }
+}
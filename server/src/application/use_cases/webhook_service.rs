@@ -0,0 +1,112 @@
+use anyhow::Result;
+use chrono::Utc;
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::{scheduled_jobs::AddScheduledJobEntity, webhooks::AddWebhookDeliveryEntity},
+    repositories::{
+        mission_viewing::MissionViewingRepository, scheduled_jobs::ScheduledJobRepository,
+        webhooks::WebhookRepository,
+    },
+    value_objects::{
+        scheduled_job_types::ScheduledJobTypes, webhook_events::WebhookEvent,
+        webhook_model::WebhookMissionModel,
+    },
+};
+
+/// Turns `WebhookEvent`s into queued deliveries, one per subscribed webhook. Each delivery
+/// is sent by its own scheduled job, so it survives restarts and retries with backoff.
+/// Emitting is best effort: failures are logged and never fail the action that caused them.
+pub struct WebhookService {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+    scheduled_job_repository: Arc<dyn ScheduledJobRepository>,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        mission_viewing_repository: Arc<dyn MissionViewingRepository>,
+        scheduled_job_repository: Arc<dyn ScheduledJobRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            mission_viewing_repository,
+            scheduled_job_repository,
+        }
+    }
+
+    pub async fn emit(&self, event: WebhookEvent) {
+        let event_type = event.event_type();
+        if let Err(e) = self.enqueue(event).await {
+            tracing::warn!("Failed to queue {} webhooks: {:?}", event_type, e);
+        }
+    }
+
+    async fn enqueue(&self, event: WebhookEvent) -> Result<()> {
+        let event_type = event.event_type().to_string();
+        let mission = self
+            .mission_viewing_repository
+            .get_one(event.mission_id())
+            .await?;
+
+        let webhooks = self
+            .webhook_repository
+            .find_subscribed(&event_type, mission.id, mission.chief_id)
+            .await?;
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let mut payload = json!({
+            "event": event_type,
+            "occurred_at": Utc::now(),
+            "mission": WebhookMissionModel::from(mission),
+        });
+        match event {
+            WebhookEvent::CrewJoined { brawler_id, .. }
+            | WebhookEvent::CrewLeft { brawler_id, .. } => {
+                payload["brawler_id"] = json!(brawler_id);
+            }
+            WebhookEvent::CommentCreated { comment } => {
+                payload["comment"] = json!({
+                    "id": comment.id,
+                    "brawler_id": comment.brawler_id,
+                    "brawler_display_name": comment.brawler_display_name,
+                    "content": comment.content,
+                    "parent_id": comment.parent_id,
+                    "created_at": comment.created_at,
+                });
+            }
+            _ => {}
+        }
+
+        let delivery_ids = self
+            .webhook_repository
+            .add_deliveries(
+                webhooks
+                    .iter()
+                    .map(|webhook| AddWebhookDeliveryEntity {
+                        webhook_id: webhook.id,
+                        event: event_type.clone(),
+                        payload: payload.clone(),
+                    })
+                    .collect(),
+            )
+            .await?;
+
+        for delivery_id in delivery_ids {
+            self.scheduled_job_repository
+                .add(AddScheduledJobEntity {
+                    job_type: ScheduledJobTypes::DeliverWebhook.to_string(),
+                    related_id: Some(delivery_id),
+                    payload: json!({}),
+                    run_at: Utc::now().naive_utc(),
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand_core::{OsRng, RngCore};
+use std::{str::FromStr, sync::Arc};
+
+use crate::{
+    config::{config_loader::get_stage, stage::Stage},
+    domain::{
+        entities::webhooks::{AddWebhookEntity, EditWebhookEntity, WebhookEntity},
+        repositories::{
+            mission_viewing::MissionViewingRepository, webhook_sender::WebhookSender,
+            webhooks::WebhookRepository,
+        },
+        value_objects::{
+            webhook_delivery_statuses::WebhookDeliveryStatuses,
+            webhook_event_types::WebhookEventTypes,
+            webhook_model::{
+                AddWebhookModel, CreatedWebhookModel, EditWebhookModel, WebhookDeliveryModel,
+                WebhookDeliveryPageQuery, WebhookModel,
+            },
+        },
+    },
+    infrastructure::webhooks::address::resolve_public,
+};
+
+pub const DEFAULT_DELIVERY_PAGE_SIZE: i64 = 20;
+pub const MAX_DELIVERY_PAGE_SIZE: i64 = 100;
+
+pub struct WebhookUseCase {
+    repo: Arc<dyn WebhookRepository>,
+    mission_viewing_repo: Arc<dyn MissionViewingRepository>,
+    sender: Arc<dyn WebhookSender>,
+    /// Brawlers who may register webhooks for every mission
+    admin_ids: Vec<i32>,
+}
+
+impl WebhookUseCase {
+    pub fn new(
+        repo: Arc<dyn WebhookRepository>,
+        mission_viewing_repo: Arc<dyn MissionViewingRepository>,
+        sender: Arc<dyn WebhookSender>,
+        admin_ids: Vec<i32>,
+    ) -> Self {
+        Self {
+            repo,
+            mission_viewing_repo,
+            sender,
+            admin_ids,
+        }
+    }
+
+    pub async fn list(&self, owner_id: i32) -> Result<Vec<WebhookModel>> {
+        Ok(self
+            .repo
+            .list_by_owner(owner_id)
+            .await?
+            .into_iter()
+            .map(WebhookModel::from)
+            .collect())
+    }
+
+    pub async fn get(&self, webhook_id: i32, owner_id: i32) -> Result<WebhookModel> {
+        Ok(self.owned(webhook_id, owner_id).await?.into())
+    }
+
+    pub async fn add(&self, owner_id: i32, model: AddWebhookModel) -> Result<CreatedWebhookModel> {
+        if model.all_missions && !self.is_admin(owner_id) {
+            return Err(anyhow!("Only admins can subscribe to every mission"));
+        }
+        if let Some(mission_id) = model.mission_id {
+            if model.all_missions {
+                return Err(anyhow!(
+                    "A webhook is either scoped to one mission or to every mission"
+                ));
+            }
+            self.ensure_can_watch_mission(owner_id, mission_id).await?;
+        }
+
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        let secret = format!("whsec_{}", URL_SAFE_NO_PAD.encode(secret));
+
+        let webhook = self
+            .repo
+            .add(AddWebhookEntity {
+                owner_id,
+                mission_id: model.mission_id,
+                url: validate_url(&model.url).await?,
+                secret: secret.clone(),
+                events: validate_events(model.events)?,
+                all_missions: model.all_missions,
+            })
+            .await?;
+
+        Ok(CreatedWebhookModel {
+            webhook: webhook.into(),
+            secret,
+        })
+    }
+
+    pub async fn edit(
+        &self,
+        webhook_id: i32,
+        owner_id: i32,
+        model: EditWebhookModel,
+    ) -> Result<WebhookModel> {
+        self.owned(webhook_id, owner_id).await?;
+
+        let changes = EditWebhookEntity {
+            url: match model.url.as_deref() {
+                Some(url) => Some(validate_url(url).await?),
+                None => None,
+            },
+            events: model.events.map(validate_events).transpose()?,
+            active: model.active,
+        };
+        if changes.url.is_none() && changes.events.is_none() && changes.active.is_none() {
+            return Err(anyhow!("Nothing to update"));
+        }
+
+        Ok(self.repo.edit(webhook_id, changes).await?.into())
+    }
+
+    pub async fn remove(&self, webhook_id: i32, owner_id: i32) -> Result<()> {
+        self.owned(webhook_id, owner_id).await?;
+        self.repo.remove(webhook_id).await
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        owner_id: i32,
+        query: WebhookDeliveryPageQuery,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        self.owned(webhook_id, owner_id).await?;
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_DELIVERY_PAGE_SIZE)
+            .clamp(1, MAX_DELIVERY_PAGE_SIZE);
+
+        Ok(self
+            .repo
+            .get_deliveries(webhook_id, query.cursor, limit)
+            .await?
+            .into_iter()
+            .map(WebhookDeliveryModel::from)
+            .collect())
+    }
+
+    /// Makes one attempt at a queued delivery. Anything but a 2xx is recorded and returned
+    /// as an error so the job runner retries it; on the last attempt it is marked `Failed`.
+    pub async fn deliver(&self, delivery_id: i32, final_attempt: bool) -> Result<()> {
+        // The webhook, and its deliveries with it, may have been deleted since
+        let Some((delivery, webhook)) = self.repo.find_delivery(delivery_id).await? else {
+            return Ok(());
+        };
+        if delivery.status != WebhookDeliveryStatuses::Pending.to_string() {
+            return Ok(());
+        }
+
+        let (response_status, message) = match self.sender.send(&webhook, &delivery).await {
+            Ok(response) if (200..300).contains(&response.status) => {
+                return self
+                    .repo
+                    .record_attempt(
+                        delivery_id,
+                        WebhookDeliveryStatuses::Delivered,
+                        Some(response.status.into()),
+                        None,
+                    )
+                    .await;
+            }
+            Ok(response) => (
+                Some(i32::from(response.status)),
+                format!("Endpoint answered {}", response.status),
+            ),
+            Err(e) => (None, format!("Endpoint unreachable: {}", e)),
+        };
+
+        let status = if final_attempt {
+            WebhookDeliveryStatuses::Failed
+        } else {
+            WebhookDeliveryStatuses::Pending
+        };
+        self.repo
+            .record_attempt(delivery_id, status, response_status, Some(message.clone()))
+            .await?;
+
+        Err(anyhow!(message))
+    }
+
+    pub fn is_admin(&self, brawler_id: i32) -> bool {
+        self.admin_ids.contains(&brawler_id)
+    }
+
+    /// Only a mission's chief, or an admin, may scope a webhook to it
+    pub async fn ensure_can_watch_mission(&self, brawler_id: i32, mission_id: i32) -> Result<()> {
+        let mission = self
+            .mission_viewing_repo
+            .get_one(mission_id)
+            .await
+            .map_err(|_| anyhow!("Mission not found"))?;
+        if mission.deleted_at.is_some() {
+            return Err(anyhow!("Mission not found"));
+        }
+        if mission.chief_id != brawler_id && !self.is_admin(brawler_id) {
+            return Err(anyhow!(
+                "Only the mission's chief can add webhooks to this mission"
+            ));
+        }
+        Ok(())
+    }
+
+    async fn owned(&self, webhook_id: i32, owner_id: i32) -> Result<WebhookEntity> {
+        match self.repo.find(webhook_id).await? {
+            Some(webhook) if webhook.owner_id == owner_id => Ok(webhook),
+            _ => Err(anyhow!("Webhook not found")),
+        }
+    }
+}
+
+async fn validate_url(url: &str) -> Result<String> {
+    let parsed = reqwest::Url::parse(url).map_err(|_| anyhow!("Webhook url must be a URL"))?;
+    // Plain http is only for local receivers
+    if parsed.scheme() != "https" && !(parsed.scheme() == "http" && get_stage() == Stage::Local) {
+        return Err(anyhow!("Webhook url must use https"));
+    }
+    // Delivery checks again, this only turns away obviously internal targets up front
+    resolve_public(&parsed).await?;
+    Ok(parsed.to_string())
+}
+
+/// Normalizes the list and rejects unknown or missing events
+fn validate_events(events: Vec<String>) -> Result<Vec<String>> {
+    let mut valid = Vec::new();
+    for event in events {
+        let event = WebhookEventTypes::from_str(&event)?.to_string();
+        if !valid.contains(&event) {
+            valid.push(event);
+        }
+    }
+    if valid.is_empty() {
+        return Err(anyhow!(
+            "Subscribe to at least one of: {}",
+            WebhookEventTypes::ALL
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    Ok(valid)
+}
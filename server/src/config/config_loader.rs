@@ -2,9 +2,16 @@ use std::env;
 
 use anyhow::Result;
 
-use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
-    stage::Stage,
+use crate::{
+    config::{
+        config_model::{
+            ChatArchiveEnv, CloudinaryEnv, Database, DotEnvyConfig, JobRunnerEnv, JwtEnv,
+            MailerBackends, MailerEnv, MissionAutomationEnv, NotificationEnv, Server, SmtpTls,
+            StorageBackends, StorageEnv, WebPushEnv, WebhookEnv,
+        },
+        stage::Stage,
+    },
+    domain::value_objects::mission_statuses::MissionStatuses,
 };
 
 pub fn load() -> Result<DotEnvyConfig> {
@@ -64,3 +71,183 @@ pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
         api_secret: env::var("CLOUDINARY_API_SECRET")?,
     })
 }
+
+pub fn get_storage_env() -> Result<StorageEnv> {
+    dotenvy::dotenv().ok();
+
+    let backend = match env::var("STORAGE_BACKEND")
+        .unwrap_or("local".to_string())
+        .to_lowercase()
+        .as_str()
+    {
+        "local" => StorageBackends::Local,
+        "cloudinary" => StorageBackends::Cloudinary,
+        other => {
+            return Err(anyhow::anyhow!(
+                "STORAGE_BACKEND must be local or cloudinary, got {}",
+                other
+            ));
+        }
+    };
+
+    Ok(StorageEnv {
+        backend,
+        local_dir: env::var("STORAGE_LOCAL_DIR").unwrap_or("uploads".to_string()),
+        max_attachment_bytes: env::var("ATTACHMENT_MAX_BYTES")
+            .unwrap_or("10485760".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_mailer_env() -> Result<MailerEnv> {
+    dotenvy::dotenv().ok();
+
+    let backend = match env::var("MAILER_BACKEND")
+        .unwrap_or("file_drop".to_string())
+        .to_lowercase()
+        .as_str()
+    {
+        "smtp" => MailerBackends::Smtp,
+        "file_drop" => MailerBackends::FileDrop,
+        other => {
+            return Err(anyhow::anyhow!(
+                "MAILER_BACKEND must be smtp or file_drop, got {}",
+                other
+            ));
+        }
+    };
+
+    let smtp_tls = match env::var("SMTP_TLS")
+        .unwrap_or("starttls".to_string())
+        .to_lowercase()
+        .as_str()
+    {
+        "starttls" => SmtpTls::StartTls,
+        "tls" => SmtpTls::Tls,
+        "none" => SmtpTls::None,
+        other => {
+            return Err(anyhow::anyhow!(
+                "SMTP_TLS must be starttls, tls or none, got {}",
+                other
+            ));
+        }
+    };
+
+    Ok(MailerEnv {
+        backend,
+        from: env::var("MAIL_FROM").unwrap_or("Brawlers <no-reply@localhost>".to_string()),
+        smtp_host: env::var("SMTP_HOST").unwrap_or("localhost".to_string()),
+        smtp_port: env::var("SMTP_PORT").unwrap_or("587".to_string()).parse()?,
+        smtp_username: env::var("SMTP_USERNAME").ok(),
+        smtp_password: env::var("SMTP_PASSWORD").ok(),
+        smtp_tls,
+        file_drop_dir: env::var("MAILER_FILE_DROP_DIR").unwrap_or("mail".to_string()),
+        app_url: env::var("APP_URL").unwrap_or("http://localhost:4200".to_string()),
+    })
+}
+
+pub fn get_web_push_env() -> Result<WebPushEnv> {
+    dotenvy::dotenv().ok();
+    Ok(WebPushEnv {
+        vapid_private_key: env::var("VAPID_PRIVATE_KEY")
+            .ok()
+            .filter(|key| !key.is_empty()),
+        vapid_subject: env::var("VAPID_SUBJECT").unwrap_or("mailto:admin@localhost".to_string()),
+        ttl_secs: env::var("WEB_PUSH_TTL_SECS")
+            .unwrap_or("86400".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_webhook_env() -> Result<WebhookEnv> {
+    dotenvy::dotenv().ok();
+    Ok(WebhookEnv {
+        admin_ids: env::var("ADMIN_BRAWLER_IDS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?,
+        timeout_secs: env::var("WEBHOOK_TIMEOUT_SECS")
+            .unwrap_or("10".to_string())
+            .parse()?,
+        concurrency: env::var("WEBHOOK_CONCURRENCY")
+            .unwrap_or("8".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_chat_archive_env() -> Result<ChatArchiveEnv> {
+    dotenvy::dotenv().ok();
+    Ok(ChatArchiveEnv {
+        auto_archive: env::var("CHAT_AUTO_ARCHIVE")
+            .unwrap_or("true".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_mission_automation_env() -> Result<MissionAutomationEnv> {
+    dotenvy::dotenv().ok();
+
+    // Missions still InProgress after the grace period are closed as Completed only when asked to
+    let expire_in_progress_as = match env::var("MISSION_EXPIRE_IN_PROGRESS_AS")
+        .unwrap_or("Failed".to_string())
+        .as_str()
+    {
+        "Completed" => MissionStatuses::Completed,
+        "Failed" => MissionStatuses::Failed,
+        other => {
+            return Err(anyhow::anyhow!(
+                "MISSION_EXPIRE_IN_PROGRESS_AS must be Completed or Failed, got {}",
+                other
+            ));
+        }
+    };
+
+    Ok(MissionAutomationEnv {
+        reminder_hours: env::var("MISSION_REMINDER_HOURS")
+            .unwrap_or("24".to_string())
+            .parse()?,
+        auto_start: env::var("MISSION_AUTO_START")
+            .unwrap_or("false".to_string())
+            .parse()?,
+        expire_grace_hours: env::var("MISSION_EXPIRE_GRACE_HOURS")
+            .unwrap_or("12".to_string())
+            .parse()?,
+        expire_in_progress_as,
+    })
+}
+
+pub fn get_notification_env() -> Result<NotificationEnv> {
+    dotenvy::dotenv().ok();
+    Ok(NotificationEnv {
+        group_window_minutes: env::var("NOTIFICATION_GROUP_WINDOW_MINUTES")
+            .unwrap_or("60".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_job_runner_env() -> Result<JobRunnerEnv> {
+    dotenvy::dotenv().ok();
+    Ok(JobRunnerEnv {
+        poll_interval_secs: env::var("JOB_POLL_INTERVAL_SECS")
+            .unwrap_or("30".to_string())
+            .parse()?,
+        batch_size: env::var("JOB_BATCH_SIZE")
+            .unwrap_or("20".to_string())
+            .parse()?,
+        max_attempts: env::var("JOB_MAX_ATTEMPTS")
+            .unwrap_or("5".to_string())
+            .parse()?,
+        stats_refresh_interval_minutes: env::var("STATS_REFRESH_INTERVAL_MINUTES")
+            .unwrap_or("15".to_string())
+            .parse()?,
+        notification_retention_days: env::var("NOTIFICATION_RETENTION_DAYS")
+            .unwrap_or("30".to_string())
+            .parse()?,
+        job_retention_days: env::var("JOB_RETENTION_DAYS")
+            .unwrap_or("30".to_string())
+            .parse()?,
+    })
+}
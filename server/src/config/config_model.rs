@@ -1,3 +1,5 @@
+use crate::domain::value_objects::mission_statuses::MissionStatuses;
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
@@ -16,6 +18,11 @@ pub struct JwtEnv {
     pub ttl: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct ChatArchiveEnv {
+    pub auto_archive: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct CloudinaryEnv {
     pub cloud_name: String,
@@ -23,6 +30,69 @@ pub struct CloudinaryEnv {
     pub api_secret: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StorageBackends {
+    Local,
+    Cloudinary,
+}
+
+#[derive(Debug, Clone)]
+pub struct StorageEnv {
+    pub backend: StorageBackends,
+    /// Root folder of the local backend
+    pub local_dir: String,
+    pub max_attachment_bytes: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MailerBackends {
+    Smtp,
+    /// Writes each message as an `.eml` file instead of sending it
+    FileDrop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmtpTls {
+    StartTls,
+    Tls,
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct MailerEnv {
+    pub backend: MailerBackends,
+    pub from: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_tls: SmtpTls,
+    /// Folder the file-drop backend writes to
+    pub file_drop_dir: String,
+    /// Base URL used for links inside emails
+    pub app_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebPushEnv {
+    /// Raw P-256 private key, base64url; Web Push is off without it
+    pub vapid_private_key: Option<String>,
+    /// `mailto:` or `https:` contact push services can reach the operator at
+    pub vapid_subject: String,
+    /// How long push services keep a message for an unreachable browser
+    pub ttl_secs: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookEnv {
+    /// Brawlers allowed to register webhooks that hear about every mission
+    pub admin_ids: Vec<i32>,
+    /// How long an endpoint gets to answer before the attempt counts as failed
+    pub timeout_secs: u64,
+    /// Deliveries in flight at once, so slow endpoints can't hold up each other
+    pub concurrency: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct DotEnvyConfig {
     pub server: Server,
@@ -30,3 +100,29 @@ pub struct DotEnvyConfig {
     pub secret: String,
     // pub max_crew_per_mission: u32,
 }
+
+#[derive(Debug, Clone)]
+pub struct MissionAutomationEnv {
+    pub reminder_hours: i64,
+    pub auto_start: bool,
+    pub expire_grace_hours: i64,
+    pub expire_in_progress_as: MissionStatuses,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationEnv {
+    /// Unread notifications of the same kind this close together are shown as one
+    pub group_window_minutes: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct JobRunnerEnv {
+    pub poll_interval_secs: u64,
+    pub batch_size: i64,
+    pub max_attempts: i32,
+    pub stats_refresh_interval_minutes: i64,
+    /// Read notifications older than this are purged
+    pub notification_retention_days: i64,
+    /// Done and failed jobs older than this are purged
+    pub job_retention_days: i64,
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+use crate::infrastructure::database::schema::attachments;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(table_name = attachments)]
+pub struct AttachmentEntity {
+    pub id: i32,
+    pub uploader_id: i32,
+    pub storage_key: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub mission_comment_id: Option<i32>,
+    pub private_message_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = attachments)]
+pub struct AddAttachmentEntity {
+    pub uploader_id: i32,
+    pub storage_key: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+}
@@ -0,0 +1,19 @@
+use crate::infrastructure::database::schema::brawler_achievements;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct BrawlerAchievementEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub achievement_code: String,
+    pub awarded_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct AddBrawlerAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement_code: String,
+}
@@ -20,6 +20,7 @@ pub struct BrawlerEntity {
     pub contact_email: Option<String>,
     pub instagram: Option<String>,
     pub facebook: Option<String>,
+    pub dm_privacy: String,
 }
 
 #[derive(Debug, Clone, Insertable)]
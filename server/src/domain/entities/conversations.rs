@@ -0,0 +1,27 @@
+use crate::infrastructure::database::schema::{conversation_participants, conversations};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = conversations)]
+pub struct ConversationEntity {
+    pub id: i32,
+    pub name: Option<String>,
+    pub is_group: bool,
+    /// `"<lower id>:<higher id>"` for one-to-one conversations, `None` for groups
+    pub direct_key: Option<String>,
+    pub created_by: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Selectable, Queryable, Serialize)]
+#[diesel(table_name = conversation_participants)]
+pub struct ConversationParticipantEntity {
+    pub conversation_id: i32,
+    pub brawler_id: i32,
+    pub role: String,
+    pub last_read_message_id: Option<i32>,
+    pub joined_at: NaiveDateTime,
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::email_digest_subscriptions;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = email_digest_subscriptions)]
+pub struct EmailDigestSubscriptionEntity {
+    pub brawler_id: i32,
+    pub frequency: String,
+    pub last_sent_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = email_digest_subscriptions)]
+pub struct UpsertEmailDigestSubscriptionEntity {
+    pub brawler_id: i32,
+    pub frequency: String,
+    pub last_sent_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,14 @@
+use crate::infrastructure::database::schema::mission_comment_revisions;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(table_name = mission_comment_revisions)]
+pub struct MissionCommentRevisionEntity {
+    pub id: i32,
+    pub comment_id: i32,
+    /// The content as it was before the edit
+    pub content: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_gallery_items;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = mission_gallery_items)]
+pub struct MissionGalleryItemEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploader_id: i32,
+    pub image_url: String,
+    pub image_public_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_gallery_items)]
+pub struct AddMissionGalleryItemEntity {
+    pub mission_id: i32,
+    pub uploader_id: i32,
+    pub image_url: String,
+    pub image_public_id: String,
+}
@@ -0,0 +1,26 @@
+use crate::infrastructure::database::schema::mission_ratings;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(table_name = mission_ratings)]
+pub struct MissionRatingEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_ratings)]
+pub struct AddMissionRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Vec<String>,
+}
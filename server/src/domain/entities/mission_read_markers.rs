@@ -0,0 +1,13 @@
+use crate::infrastructure::database::schema::mission_read_markers;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Selectable, Queryable, Serialize)]
+#[diesel(table_name = mission_read_markers)]
+pub struct MissionReadMarkerEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub last_read_comment_id: i32,
+    pub updated_at: NaiveDateTime,
+}
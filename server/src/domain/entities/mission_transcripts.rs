@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+use crate::infrastructure::database::schema::mission_transcripts;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(table_name = mission_transcripts)]
+pub struct MissionTranscriptEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub mission_name: String,
+    pub chief_id: i32,
+    pub participant_ids: Vec<i32>,
+    pub reason: String,
+    pub comments: serde_json::Value,
+    pub comment_count: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_transcripts)]
+pub struct AddMissionTranscriptEntity {
+    pub mission_id: i32,
+    pub mission_name: String,
+    pub chief_id: i32,
+    pub participant_ids: Vec<i32>,
+    pub reason: String,
+    pub comments: serde_json::Value,
+    pub comment_count: i32,
+}
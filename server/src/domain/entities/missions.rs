@@ -21,6 +21,10 @@ pub struct MissionEntity {
     pub scheduled_at: Option<NaiveDateTime>,
     pub location: Option<String>,
     pub category: String,
+    pub finished_at: Option<NaiveDateTime>,
+    pub min_reputation: Option<f64>,
+    pub cover_url: Option<String>,
+    pub cover_public_id: Option<String>,
 }
 
 impl MissionEntity {
@@ -41,6 +45,9 @@ impl MissionEntity {
             location: self.location.clone(),
             deleted_at: self.deleted_at,
             category: self.category.clone(),
+            finished_at: self.finished_at,
+            min_reputation: self.min_reputation,
+            cover_url: self.cover_url.clone(),
         }
     }
 }
@@ -56,6 +63,7 @@ pub struct AddMissionEntity {
     pub scheduled_at: Option<NaiveDateTime>,
     pub location: Option<String>,
     pub category: String,
+    pub min_reputation: Option<f64>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -68,4 +76,5 @@ pub struct EditMissionEntity {
     pub scheduled_at: Option<NaiveDateTime>,
     pub location: Option<String>,
     pub category: Option<String>,
+    pub min_reputation: Option<f64>,
 }
@@ -1,6 +1,19 @@
+pub mod attachments;
+pub mod brawler_achievements;
 pub mod brawlers;
+pub mod conversations;
 pub mod crew_memberships;
+pub mod email_digest_subscriptions;
 pub mod friendships;
+pub mod mission_comment_revisions;
+pub mod mission_gallery_items;
+pub mod mission_ratings;
+pub mod mission_read_markers;
+pub mod mission_transcripts;
 pub mod missions;
+pub mod notification_preferences;
 pub mod notifications;
 pub mod private_messages;
+pub mod push_subscriptions;
+pub mod scheduled_jobs;
+pub mod webhooks;
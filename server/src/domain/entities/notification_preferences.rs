@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::notification_preferences;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = notification_preferences)]
+pub struct NotificationPreferenceEntity {
+    pub brawler_id: i32,
+    pub notification_type: String,
+    pub channel: String,
+    pub enabled: bool,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = notification_preferences)]
+pub struct UpsertNotificationPreferenceEntity {
+    pub brawler_id: i32,
+    pub notification_type: String,
+    pub channel: String,
+    pub enabled: bool,
+}
@@ -16,6 +16,10 @@ pub struct NotificationEntity {
     pub related_id: Option<i32>,
     pub is_read: bool,
     pub created_at: NaiveDateTime,
+    /// How many notifications this row stands for; above 1 once others were folded into it
+    pub group_count: i32,
+    /// When the last notification was folded in
+    pub updated_at: NaiveDateTime,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -26,3 +30,15 @@ pub struct AddNotificationEntity {
     pub content: String,
     pub related_id: Option<i32>,
 }
+
+/// One fan-out that folds into each recipient's open group for `(type_, related_id)` when
+/// there is one. A group's content becomes `count_prefix`, the new count, then `count_suffix`.
+#[derive(Debug, Clone)]
+pub struct AddGroupedNotificationEntity {
+    pub brawler_ids: Vec<i32>,
+    pub type_: String,
+    pub content: String,
+    pub related_id: i32,
+    pub count_prefix: String,
+    pub count_suffix: String,
+}
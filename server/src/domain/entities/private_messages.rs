@@ -13,10 +13,36 @@ pub struct PrivateMessage {
     pub content: String,
     pub is_read: bool,
     pub created_at: NaiveDateTime,
+    pub edited_at: Option<NaiveDateTime>,
+    /// Set when the sender unsent the message; the content is wiped
+    pub deleted_at: Option<NaiveDateTime>,
+    /// `AttachmentModel`s sent with the message
+    pub attachments: serde_json::Value,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreatePrivateMessage {
     pub receiver_id: i32,
     pub content: String,
+    /// Uploads from `POST /api/attachments` to send with the message
+    #[serde(default)]
+    pub attachment_ids: Vec<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditPrivateMessage {
+    pub content: String,
+}
+
+/// Keyset page: `before_id` is the id of the oldest item already loaded
+#[derive(Debug, Deserialize, Default)]
+pub struct MessagePageQuery {
+    pub before_id: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessageSearchQuery {
+    pub q: String,
+    pub limit: Option<i64>,
 }
@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::push_subscriptions;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = push_subscriptions)]
+pub struct PushSubscriptionEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub endpoint: String,
+    /// The browser's P-256 public key, base64url
+    pub p256dh: String,
+    /// The browser's auth secret, base64url
+    pub auth: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = push_subscriptions)]
+pub struct UpsertPushSubscriptionEntity {
+    pub brawler_id: i32,
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+    pub expires_at: Option<NaiveDateTime>,
+}
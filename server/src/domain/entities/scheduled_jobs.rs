@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::scheduled_jobs;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, QueryableByName)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = scheduled_jobs)]
+pub struct ScheduledJobEntity {
+    pub id: i32,
+    pub job_type: String,
+    pub related_id: Option<i32>,
+    pub payload: serde_json::Value,
+    pub run_at: NaiveDateTime,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = scheduled_jobs)]
+pub struct AddScheduledJobEntity {
+    pub job_type: String,
+    pub related_id: Option<i32>,
+    pub payload: serde_json::Value,
+    pub run_at: NaiveDateTime,
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{webhook_deliveries, webhooks};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct WebhookEntity {
+    pub id: i32,
+    pub owner_id: i32,
+    pub mission_id: Option<i32>,
+    pub url: String,
+    /// HMAC key deliveries are signed with
+    pub secret: String,
+    pub events: Vec<String>,
+    pub all_missions: bool,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = webhooks)]
+pub struct AddWebhookEntity {
+    pub owner_id: i32,
+    pub mission_id: Option<i32>,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub all_missions: bool,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = webhooks)]
+pub struct EditWebhookEntity {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct WebhookDeliveryEntity {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub last_attempt_at: Option<NaiveDateTime>,
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct AddWebhookDeliveryEntity {
+    pub webhook_id: i32,
+    pub event: String,
+    pub payload: serde_json::Value,
+}
@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::brawler_achievements::{AddBrawlerAchievementEntity, BrawlerAchievementEntity},
+    value_objects::achievements::AchievementProgressModel,
+};
+
+#[async_trait]
+pub trait AchievementRepository: Send + Sync {
+    async fn get_progress(&self, brawler_id: i32) -> Result<AchievementProgressModel>;
+    /// Inserts the awards and returns only the ones the brawler didn't already have
+    async fn award(
+        &self,
+        awards: Vec<AddBrawlerAchievementEntity>,
+    ) -> Result<Vec<BrawlerAchievementEntity>>;
+    async fn find_by_brawler(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementEntity>>;
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::attachments::{AddAttachmentEntity, AttachmentEntity};
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn add(&self, attachment: AddAttachmentEntity) -> Result<AttachmentEntity>;
+    async fn find_by_id(&self, attachment_id: i32) -> Result<Option<AttachmentEntity>>;
+    /// The uploader always may; otherwise the brawler must be able to read the comment or
+    /// message the file was sent with
+    async fn can_access(&self, attachment_id: i32, brawler_id: i32) -> Result<bool>;
+    async fn delete(&self, attachment_id: i32) -> Result<()>;
+}
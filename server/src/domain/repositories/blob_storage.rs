@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// Where uploaded files live. Keys are backend-neutral paths such as
+/// `attachments/2026/02/<uuid>.png`.
+#[async_trait]
+pub trait BlobStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<()>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>>;
+    /// Deleting a missing key is not an error
+    async fn delete(&self, key: &str) -> Result<()>;
+}
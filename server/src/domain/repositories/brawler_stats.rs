@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::brawler_stats_model::{
+    BrawlerStatsSummaryModel, CategoryCountModel, CrewmateModel, WeeklyActivityModel,
+};
+
+#[async_trait]
+pub trait BrawlerStatsRepository: Send + Sync {
+    async fn get_summary(&self, brawler_id: i32) -> Result<BrawlerStatsSummaryModel>;
+    async fn get_top_categories(
+        &self,
+        brawler_id: i32,
+        limit: i64,
+    ) -> Result<Vec<CategoryCountModel>>;
+    /// Every week the brawler had at least one mission, oldest first
+    async fn get_weekly_activity(&self, brawler_id: i32) -> Result<Vec<WeeklyActivityModel>>;
+    async fn get_top_crewmates(&self, brawler_id: i32, limit: i64) -> Result<Vec<CrewmateModel>>;
+    async fn refresh(&self) -> Result<()>;
+}
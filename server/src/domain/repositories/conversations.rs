@@ -0,0 +1,76 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::conversations::{ConversationEntity, ConversationParticipantEntity},
+    value_objects::{
+        conversation_model::{
+            ConversationMessageModel, ConversationParticipantModel, ConversationSummaryModel,
+        },
+        conversation_roles::ConversationRoles,
+    },
+};
+
+#[async_trait]
+pub trait ConversationRepository: Send + Sync {
+    /// Creates the group with `creator_id` as its owner and everyone else as members
+    async fn create_group(
+        &self,
+        creator_id: i32,
+        name: String,
+        participant_ids: Vec<i32>,
+    ) -> Result<ConversationEntity>;
+    async fn find_by_id(&self, conversation_id: i32) -> Result<Option<ConversationEntity>>;
+    async fn get_participants(
+        &self,
+        conversation_id: i32,
+    ) -> Result<Vec<ConversationParticipantModel>>;
+    async fn get_participant_ids(&self, conversation_id: i32) -> Result<Vec<i32>>;
+    async fn find_participant(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<Option<ConversationParticipantEntity>>;
+    async fn rename(&self, conversation_id: i32, name: String) -> Result<ConversationEntity>;
+    /// Returns the brawlers that were not already in the conversation
+    async fn add_participants(
+        &self,
+        conversation_id: i32,
+        brawler_ids: Vec<i32>,
+    ) -> Result<Vec<i32>>;
+    async fn remove_participant(&self, conversation_id: i32, brawler_id: i32) -> Result<()>;
+    async fn set_role(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        role: ConversationRoles,
+    ) -> Result<()>;
+    /// Conversations the brawler takes part in, most recently active first
+    async fn list_for_brawler(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>>;
+    /// `receiver_id` is only set for one-to-one conversations. The sender's unsent
+    /// `attachment_ids` are linked in the same transaction, and any that can't be fail the send.
+    async fn add_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        receiver_id: Option<i32>,
+        content: String,
+        attachment_ids: Vec<i32>,
+    ) -> Result<ConversationMessageModel>;
+    async fn find_message(&self, message_id: i32) -> Result<ConversationMessageModel>;
+    /// Keyset page of messages older than `before_id`, oldest first
+    async fn get_messages(
+        &self,
+        conversation_id: i32,
+        viewer_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<ConversationMessageModel>>;
+    /// Moves the brawler's read marker forward and returns it
+    async fn mark_read(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        last_read_message_id: Option<i32>,
+    ) -> Result<Option<i32>>;
+}
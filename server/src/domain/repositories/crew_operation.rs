@@ -2,13 +2,23 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::domain::{
-    entities::crew_memberships::CrewMemberShips, value_objects::mission_model::MissionModel,
+    entities::crew_memberships::CrewMemberShips,
+    value_objects::{attendance_statuses::AttendanceStatuses, mission_model::JoinedMissionModel},
 };
 
 #[async_trait]
 pub trait CrewOperationRepository {
     async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
     async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    /// Deletes a soft-deleted mission together with its chat, once nobody is left to see it
+    async fn purge_removed_mission(&self, mission_id: i32) -> Result<()>;
     /// ดึงภารกิจที่ brawler เข้าร่วมอยู่ (เป็น crew member)
-    async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<JoinedMissionModel>>;
+    async fn set_attendance(
+        &self,
+        crew_member_ships: CrewMemberShips,
+        status: AttendanceStatuses,
+    ) -> Result<()>;
+    /// Marks every crew member still `Pending` as `NoShow`, returning who was marked
+    async fn mark_pending_as_no_show(&self, mission_id: i32) -> Result<Vec<i32>>;
 }
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::email_digest_subscriptions::{
+        EmailDigestSubscriptionEntity, UpsertEmailDigestSubscriptionEntity,
+    },
+    value_objects::email_digest_model::{DigestDmModel, DigestMissionModel, DigestRecipientModel},
+};
+
+#[async_trait]
+pub trait EmailDigestRepository: Send + Sync {
+    async fn get(&self, brawler_id: i32) -> Result<Option<EmailDigestSubscriptionEntity>>;
+    /// Changing the frequency keeps `last_sent_at`, so switching doesn't send an extra digest
+    async fn upsert(
+        &self,
+        subscription: UpsertEmailDigestSubscriptionEntity,
+    ) -> Result<EmailDigestSubscriptionEntity>;
+    async fn remove(&self, brawler_id: i32) -> Result<()>;
+    /// Subscribers with a contact email who haven't had a digest yet this day or week
+    async fn find_due(&self, at: NaiveDateTime, limit: i64) -> Result<Vec<DigestRecipientModel>>;
+    /// Open missions the brawler is crew on, scheduled between `from` and `until`
+    async fn get_upcoming_missions(
+        &self,
+        brawler_id: i32,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Result<Vec<DigestMissionModel>>;
+    /// Unread direct messages, counted per sender
+    async fn get_unread_dms(&self, brawler_id: i32) -> Result<Vec<DigestDmModel>>;
+    async fn mark_sent(&self, brawler_id: i32, sent_at: NaiveDateTime) -> Result<()>;
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::outgoing_email::OutgoingEmail;
+
+/// Delivers outgoing email; SMTP in production, files on disk for local testing
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: OutgoingEmail) -> Result<()>;
+}
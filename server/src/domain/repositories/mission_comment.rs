@@ -1,15 +1,67 @@
-use crate::domain::value_objects::mission_comment_model::MissionCommentModel;
+use crate::domain::{
+    entities::{
+        mission_comment_revisions::MissionCommentRevisionEntity,
+        mission_read_markers::MissionReadMarkerEntity,
+    },
+    value_objects::mission_comment_model::{CommentReactionSummaryModel, MissionCommentModel},
+};
 use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait MissionCommentRepository {
+pub trait MissionCommentRepository: Send + Sync {
+    /// Inserts the comment and links the author's unsent `attachment_ids` to it in one
+    /// transaction; fails without posting if any of them can't be linked
     async fn add(
         &self,
         mission_id: i32,
         brawler_id: i32,
         content: &str,
+        parent_id: Option<i32>,
+        attachment_ids: Vec<i32>,
     ) -> Result<MissionCommentModel>;
-    async fn get_by_mission_id(&self, mission_id: i32) -> Result<Vec<MissionCommentModel>>;
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentModel>;
+    /// Up to `limit` comments that have not been deleted with an id above `after_id`,
+    /// oldest first, for exports and archives
+    async fn get_transcript_page(
+        &self,
+        mission_id: i32,
+        after_id: i32,
+        limit: i64,
+    ) -> Result<Vec<MissionCommentModel>>;
+    /// Up to `limit` comments older than `before_id` (newest page when `None`), oldest first
+    async fn get_by_mission_id(
+        &self,
+        mission_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<MissionCommentModel>>;
+    /// Saves the current content as a revision, then replaces it
+    async fn edit(&self, comment_id: i32, content: &str) -> Result<MissionCommentModel>;
+    async fn soft_delete(&self, comment_id: i32, deleted_by: i32) -> Result<()>;
+    async fn get_revisions(&self, comment_id: i32) -> Result<Vec<MissionCommentRevisionEntity>>;
+    /// Pins the comment when `pinned_by` is set, unpins it otherwise
+    async fn set_pinned(
+        &self,
+        comment_id: i32,
+        pinned_by: Option<i32>,
+    ) -> Result<MissionCommentModel>;
+    async fn get_pinned(&self, mission_id: i32) -> Result<Vec<MissionCommentModel>>;
     async fn clear_by_mission_id(&self, mission_id: i32) -> Result<()>;
+    /// Adds the reaction, or removes it if the brawler already reacted with that emoji.
+    /// Returns whether the reaction is now present.
+    async fn toggle_reaction(&self, comment_id: i32, brawler_id: i32, emoji: &str) -> Result<bool>;
+    /// Moves the marker forward only; returns the stored marker
+    async fn upsert_read_marker(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        last_read_comment_id: i32,
+    ) -> Result<MissionReadMarkerEntity>;
+    async fn get_read_markers(&self, mission_id: i32) -> Result<Vec<MissionReadMarkerEntity>>;
+    async fn get_reactions(
+        &self,
+        comment_ids: Vec<i32>,
+        viewer_id: i32,
+    ) -> Result<Vec<CommentReactionSummaryModel>>;
 }
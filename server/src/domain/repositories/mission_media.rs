@@ -0,0 +1,30 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::mission_gallery_items::{
+    AddMissionGalleryItemEntity, MissionGalleryItemEntity,
+};
+
+#[async_trait]
+pub trait MissionMediaRepository: Send + Sync {
+    /// Returns the public id of the cover it replaced, if any
+    async fn update_cover(
+        &self,
+        mission_id: i32,
+        cover_url: String,
+        cover_public_id: String,
+    ) -> Result<Option<String>>;
+    async fn add_gallery_item(
+        &self,
+        item: AddMissionGalleryItemEntity,
+    ) -> Result<MissionGalleryItemEntity>;
+    async fn get_gallery(&self, mission_id: i32) -> Result<Vec<MissionGalleryItemEntity>>;
+    async fn find_gallery_item(
+        &self,
+        mission_id: i32,
+        item_id: i32,
+    ) -> Result<Option<MissionGalleryItemEntity>>;
+    async fn remove_gallery_item(&self, item_id: i32) -> Result<()>;
+    /// Public ids of the cover and every gallery item, for cleaning up storage
+    async fn get_public_ids(&self, mission_id: i32) -> Result<(Option<String>, Vec<String>)>;
+}
@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity},
+    value_objects::mission_rating_model::ReputationModel,
+};
+
+#[async_trait]
+pub trait MissionRatingRepository: Send + Sync {
+    async fn add(&self, rating: AddMissionRatingEntity) -> Result<i32>;
+    async fn find_by_rater(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+    ) -> Result<Vec<MissionRatingEntity>>;
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel>;
+}
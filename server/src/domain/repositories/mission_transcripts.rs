@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::mission_transcripts::{
+    AddMissionTranscriptEntity, MissionTranscriptEntity,
+};
+
+#[async_trait]
+pub trait MissionTranscriptRepository: Send + Sync {
+    async fn add(&self, transcript: AddMissionTranscriptEntity) -> Result<i32>;
+    /// Archives of the mission that the brawler took part in, newest first
+    async fn find_by_mission(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<MissionTranscriptEntity>>;
+}
@@ -6,7 +6,7 @@ use crate::domain::value_objects::{
 };
 
 #[async_trait]
-pub trait MissionViewingRepository {
+pub trait MissionViewingRepository: Send + Sync {
     async fn get_one(&self, mission_id: i32) -> Result<MissionModel>;
     async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
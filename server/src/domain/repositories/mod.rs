@@ -1,10 +1,27 @@
+pub mod achievements;
+pub mod attachments;
+pub mod blob_storage;
+pub mod brawler_stats;
 pub mod brawlers;
+pub mod conversations;
 pub mod crew_operation;
+pub mod email_digests;
 pub mod friendship_repository;
+pub mod mailer;
 pub mod mission_comment;
 pub mod mission_management;
+pub mod mission_media;
 pub mod mission_operation;
+pub mod mission_ratings;
+pub mod mission_transcripts;
 pub mod mission_viewing;
+pub mod notification_preferences;
 pub mod notifications;
+pub mod privacy;
 pub mod private_messages;
+pub mod push_sender;
+pub mod push_subscriptions;
 // pub mod transaction_provider;
+pub mod scheduled_jobs;
+pub mod webhook_sender;
+pub mod webhooks;
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::notification_preferences::{
+        NotificationPreferenceEntity, UpsertNotificationPreferenceEntity,
+    },
+    value_objects::{
+        notification_channels::NotificationChannels, notification_types::NotificationTypes,
+    },
+};
+
+#[async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    /// Only the stored overrides; anything missing is enabled
+    async fn get_by_brawler(&self, brawler_id: i32) -> Result<Vec<NotificationPreferenceEntity>>;
+    async fn upsert(&self, preferences: Vec<UpsertNotificationPreferenceEntity>) -> Result<()>;
+    /// The subset of `brawler_ids` who turned `channel` off for `notification_type`
+    async fn find_opted_out(
+        &self,
+        brawler_ids: Vec<i32>,
+        notification_type: NotificationTypes,
+        channel: NotificationChannels,
+    ) -> Result<Vec<i32>>;
+}
@@ -1,12 +1,40 @@
-use crate::domain::entities::notifications::{AddNotificationEntity, NotificationEntity};
+use crate::domain::{
+    entities::notifications::{
+        AddGroupedNotificationEntity, AddNotificationEntity, NotificationEntity,
+    },
+    value_objects::notification_model::{NotificationCursor, NotificationFilter},
+};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait NotificationRepository: Send + Sync {
     async fn add(&self, notification: AddNotificationEntity) -> Result<NotificationEntity>;
-    async fn get_by_user(&self, user_id: i32) -> Result<Vec<NotificationEntity>>;
+    /// Saves a whole fan-out in one insert
+    async fn add_many(&self, notifications: Vec<AddNotificationEntity>) -> Result<()>;
+    /// Folds into groups updated after `since`, inserting for recipients without one
+    async fn add_grouped(
+        &self,
+        notification: AddGroupedNotificationEntity,
+        since: NaiveDateTime,
+    ) -> Result<()>;
+    /// Most recently updated first, starting after `cursor` when given
+    async fn get_page(
+        &self,
+        user_id: i32,
+        filter: NotificationFilter,
+        cursor: Option<NotificationCursor>,
+        limit: i64,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn count_unread(&self, user_id: i32) -> Result<i64>;
+    /// Unread counts for several brawlers; those with nothing unread are left out
+    async fn count_unread_many(&self, user_ids: Vec<i32>) -> Result<Vec<(i32, i64)>>;
     async fn mark_as_read(&self, notification_id: i32, user_id: i32) -> Result<()>;
     async fn mark_all_as_read(&self, user_id: i32) -> Result<()>;
+    /// Whether the notification existed and belonged to `user_id`
+    async fn delete(&self, notification_id: i32, user_id: i32) -> Result<bool>;
     async fn delete_for_user(&self, user_id: i32) -> Result<()>;
+    /// Removes read notifications created before `cutoff`, returning how many went
+    async fn purge_read_before(&self, cutoff: NaiveDateTime) -> Result<usize>;
 }
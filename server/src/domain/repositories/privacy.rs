@@ -0,0 +1,23 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::dm_privacy::DmPrivacy;
+
+#[async_trait]
+pub trait PrivacyRepository: Send + Sync {
+    async fn block(&self, blocker_id: i32, blocked_id: i32) -> Result<()>;
+    async fn unblock(&self, blocker_id: i32, blocked_id: i32) -> Result<()>;
+    async fn list_blocked(&self, blocker_id: i32) -> Result<Vec<i32>>;
+    /// Brawlers who blocked `blocked_id`
+    async fn list_blockers(&self, blocked_id: i32) -> Result<Vec<i32>>;
+    /// Whether either brawler blocked the other
+    async fn is_blocked_between(&self, brawler1_id: i32, brawler2_id: i32) -> Result<bool>;
+    async fn is_blocked(&self, blocker_id: i32, blocked_id: i32) -> Result<bool>;
+    async fn mute(&self, muter_id: i32, muted_id: i32) -> Result<()>;
+    async fn unmute(&self, muter_id: i32, muted_id: i32) -> Result<()>;
+    async fn list_muted(&self, muter_id: i32) -> Result<Vec<i32>>;
+    /// The subset of `recipient_ids` who muted `actor_id`
+    async fn find_muters(&self, actor_id: i32, recipient_ids: Vec<i32>) -> Result<Vec<i32>>;
+    async fn get_dm_privacy(&self, brawler_id: i32) -> Result<DmPrivacy>;
+    async fn set_dm_privacy(&self, brawler_id: i32, dm_privacy: DmPrivacy) -> Result<()>;
+}
@@ -3,18 +3,45 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait PrivateMessageRepository: Send + Sync {
+    /// Sends the message with the sender's unsent `attachment_ids` in one transaction;
+    /// fails without sending if any of them can't be linked
     async fn save(
         &self,
         sender_id: i32,
         receiver_id: i32,
         content: String,
+        attachment_ids: Vec<i32>,
     ) -> Result<PrivateMessage, String>;
+    /// Up to `limit` messages older than `before_id` (latest page when `None`), oldest first
     async fn get_conversation(
         &self,
         user1_id: i32,
         user2_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
     ) -> Result<Vec<PrivateMessage>, String>;
-    async fn mark_as_read(&self, receiver_id: i32, sender_id: i32) -> Result<(), String>;
+    async fn find_by_id(&self, message_id: i32) -> Result<PrivateMessage, String>;
+    async fn edit(&self, message_id: i32, content: String) -> Result<PrivateMessage, String>;
+    /// Deletes the message for both sides, leaving a tombstone with no content
+    async fn unsend(&self, message_id: i32) -> Result<PrivateMessage, String>;
+    /// Deletes the message for `user_id` only
+    async fn hide_for(&self, message_id: i32, user_id: i32) -> Result<(), String>;
+    /// Returns the newest message id that just became read, if any
+    async fn mark_as_read(&self, receiver_id: i32, sender_id: i32) -> Result<Option<i32>, String>;
     async fn get_unread_count(&self, user_id: i32) -> Result<i64, String>;
-    async fn get_recent_chats(&self, user_id: i32) -> Result<Vec<PrivateMessage>, String>;
+    /// Latest message of each conversation, newest conversation first.
+    /// `before_id` is the latest message id of the last conversation on the previous page.
+    async fn get_recent_chats(
+        &self,
+        user_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<PrivateMessage>, String>;
+    /// Full-text search over messages the user sent or received, newest first
+    async fn search(
+        &self,
+        user_id: i32,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<PrivateMessage>, String>;
 }
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::push_subscriptions::PushSubscriptionEntity,
+    value_objects::web_push_model::PushOutcomes,
+};
+
+/// Encrypts a payload for one subscription and hands it to its push service
+#[async_trait]
+pub trait PushSender: Send + Sync {
+    /// The VAPID public key browsers subscribe with, base64url
+    fn public_key(&self) -> String;
+    async fn send(
+        &self,
+        subscription: &PushSubscriptionEntity,
+        payload: &[u8],
+    ) -> Result<PushOutcomes>;
+}
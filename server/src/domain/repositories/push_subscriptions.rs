@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::push_subscriptions::{
+    PushSubscriptionEntity, UpsertPushSubscriptionEntity,
+};
+
+#[async_trait]
+pub trait PushSubscriptionRepository: Send + Sync {
+    /// Keyed by endpoint; an existing one of the same brawler gets the new keys. `false` when
+    /// the endpoint belongs to another brawler and was left alone.
+    async fn upsert(&self, subscription: UpsertPushSubscriptionEntity) -> Result<bool>;
+    async fn remove(&self, brawler_id: i32, endpoint: &str) -> Result<bool>;
+    async fn find_by_brawlers(&self, brawler_ids: Vec<i32>) -> Result<Vec<PushSubscriptionEntity>>;
+    async fn remove_many(&self, ids: Vec<i32>) -> Result<usize>;
+    async fn purge_expired(&self, at: NaiveDateTime) -> Result<usize>;
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::scheduled_jobs::{AddScheduledJobEntity, ScheduledJobEntity},
+    value_objects::scheduled_job_types::{JobLane, ScheduledJobTypes},
+};
+
+#[async_trait]
+pub trait ScheduledJobRepository: Send + Sync {
+    async fn add(&self, job: AddScheduledJobEntity) -> Result<i32>;
+    async fn has_pending(&self, job_type: ScheduledJobTypes) -> Result<bool>;
+    /// Drops jobs of the given types for `related_id` that have not started yet
+    async fn cancel_pending(
+        &self,
+        related_id: i32,
+        job_types: Vec<ScheduledJobTypes>,
+    ) -> Result<()>;
+    /// Atomically moves up to `limit` due jobs in `lane` to `Running` so no other runner
+    /// picks them up
+    async fn claim_due(&self, limit: i64, lane: JobLane) -> Result<Vec<ScheduledJobEntity>>;
+    async fn mark_done(&self, job_id: i32) -> Result<()>;
+    /// Records the error and re-queues the job at `retry_at`, or fails it for good when `None`
+    async fn mark_failed(
+        &self,
+        job_id: i32,
+        error: String,
+        retry_at: Option<NaiveDateTime>,
+    ) -> Result<()>;
+    /// Puts jobs left `Running` by a previous process back in the queue
+    async fn requeue_running(&self) -> Result<usize>;
+    /// Deletes `Done` and `Failed` jobs last touched before `cutoff`
+    async fn purge_finished_before(&self, cutoff: NaiveDateTime) -> Result<usize>;
+}
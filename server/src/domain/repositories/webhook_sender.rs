@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::webhooks::{WebhookDeliveryEntity, WebhookEntity},
+    value_objects::webhook_model::WebhookResponse,
+};
+
+/// Signs a delivery with its webhook's secret and POSTs it to the webhook's URL
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// `Err` only when the endpoint could not be reached at all
+    async fn send(
+        &self,
+        webhook: &WebhookEntity,
+        delivery: &WebhookDeliveryEntity,
+    ) -> Result<WebhookResponse>;
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::webhooks::{
+        AddWebhookDeliveryEntity, AddWebhookEntity, EditWebhookEntity, WebhookDeliveryEntity,
+        WebhookEntity,
+    },
+    value_objects::webhook_delivery_statuses::WebhookDeliveryStatuses,
+};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn add(&self, webhook: AddWebhookEntity) -> Result<WebhookEntity>;
+    async fn find(&self, webhook_id: i32) -> Result<Option<WebhookEntity>>;
+    async fn list_by_owner(&self, owner_id: i32) -> Result<Vec<WebhookEntity>>;
+    async fn edit(&self, webhook_id: i32, changes: EditWebhookEntity) -> Result<WebhookEntity>;
+    async fn remove(&self, webhook_id: i32) -> Result<()>;
+    /// Active webhooks listening for `event` on `mission_id`, which is led by `chief_id`
+    async fn find_subscribed(
+        &self,
+        event: &str,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<Vec<WebhookEntity>>;
+    async fn add_deliveries(&self, deliveries: Vec<AddWebhookDeliveryEntity>) -> Result<Vec<i32>>;
+    /// The delivery together with the webhook it goes to
+    async fn find_delivery(
+        &self,
+        delivery_id: i32,
+    ) -> Result<Option<(WebhookDeliveryEntity, WebhookEntity)>>;
+    async fn record_attempt(
+        &self,
+        delivery_id: i32,
+        status: WebhookDeliveryStatuses,
+        response_status: Option<i32>,
+        error: Option<String>,
+    ) -> Result<()>;
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryEntity>>;
+}
@@ -0,0 +1,166 @@
+use chrono::NaiveDateTime;
+use diesel::{QueryableByName, sql_types::BigInt};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::brawler_achievements::BrawlerAchievementEntity;
+
+/// Things that happen in the app which can move a brawler closer to a badge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementEvents {
+    MissionCompleted,
+    CrewJoined,
+    FriendAccepted,
+    CommentPosted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementCriteria {
+    /// Completed missions, as chief or as a crew member who showed up
+    MissionsCompleted(i64),
+    /// Completed missions led as chief
+    MissionsLed(i64),
+    MissionsJoined(i64),
+    Friends(i64),
+    Comments(i64),
+}
+
+impl AchievementCriteria {
+    pub fn is_triggered_by(&self, event: AchievementEvents) -> bool {
+        matches!(
+            (self, event),
+            (
+                AchievementCriteria::MissionsCompleted(_) | AchievementCriteria::MissionsLed(_),
+                AchievementEvents::MissionCompleted
+            ) | (
+                AchievementCriteria::MissionsJoined(_),
+                AchievementEvents::CrewJoined
+            ) | (
+                AchievementCriteria::Friends(_),
+                AchievementEvents::FriendAccepted
+            ) | (
+                AchievementCriteria::Comments(_),
+                AchievementEvents::CommentPosted
+            )
+        )
+    }
+
+    pub fn is_met(&self, progress: &AchievementProgressModel) -> bool {
+        match *self {
+            AchievementCriteria::MissionsCompleted(n) => progress.missions_completed >= n,
+            AchievementCriteria::MissionsLed(n) => progress.missions_led >= n,
+            AchievementCriteria::MissionsJoined(n) => progress.missions_joined >= n,
+            AchievementCriteria::Friends(n) => progress.friends >= n,
+            AchievementCriteria::Comments(n) => progress.comments >= n,
+        }
+    }
+}
+
+pub struct Achievement {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub criteria: AchievementCriteria,
+}
+
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        code: "first_mission",
+        name: "First Blood",
+        description: "Complete your first mission",
+        criteria: AchievementCriteria::MissionsCompleted(1),
+    },
+    Achievement {
+        code: "veteran",
+        name: "Veteran",
+        description: "Complete 10 missions",
+        criteria: AchievementCriteria::MissionsCompleted(10),
+    },
+    Achievement {
+        code: "legend",
+        name: "Legend",
+        description: "Complete 50 missions",
+        criteria: AchievementCriteria::MissionsCompleted(50),
+    },
+    Achievement {
+        code: "first_command",
+        name: "First Command",
+        description: "Lead a mission to completion as chief",
+        criteria: AchievementCriteria::MissionsLed(1),
+    },
+    Achievement {
+        code: "commander",
+        name: "Commander",
+        description: "Lead 10 missions to completion as chief",
+        criteria: AchievementCriteria::MissionsLed(10),
+    },
+    Achievement {
+        code: "recruit",
+        name: "Recruit",
+        description: "Join your first crew",
+        criteria: AchievementCriteria::MissionsJoined(1),
+    },
+    Achievement {
+        code: "first_friend",
+        name: "Brothers in Arms",
+        description: "Make your first friend",
+        criteria: AchievementCriteria::Friends(1),
+    },
+    Achievement {
+        code: "social_butterfly",
+        name: "Social Butterfly",
+        description: "Make 10 friends",
+        criteria: AchievementCriteria::Friends(10),
+    },
+    Achievement {
+        code: "icebreaker",
+        name: "Icebreaker",
+        description: "Post your first mission comment",
+        criteria: AchievementCriteria::Comments(1),
+    },
+    Achievement {
+        code: "chatterbox",
+        name: "Chatterbox",
+        description: "Post 100 mission comments",
+        criteria: AchievementCriteria::Comments(100),
+    },
+];
+
+pub fn find_achievement(code: &str) -> Option<&'static Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .find(|achievement| achievement.code == code)
+}
+
+#[derive(Debug, Clone, Default, QueryableByName)]
+pub struct AchievementProgressModel {
+    #[diesel(sql_type = BigInt)]
+    pub missions_completed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub missions_led: i64,
+    #[diesel(sql_type = BigInt)]
+    pub missions_joined: i64,
+    #[diesel(sql_type = BigInt)]
+    pub friends: i64,
+    #[diesel(sql_type = BigInt)]
+    pub comments: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementModel {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub awarded_at: NaiveDateTime,
+}
+
+impl AchievementModel {
+    /// Awards whose code was dropped from the catalogue come back as `None`
+    pub fn from_entity(entity: BrawlerAchievementEntity) -> Option<Self> {
+        find_achievement(&entity.achievement_code).map(|achievement| Self {
+            code: achievement.code.to_string(),
+            name: achievement.name.to_string(),
+            description: achievement.description.to_string(),
+            awarded_at: entity.awarded_at,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::attachments::AttachmentEntity;
+
+/// File types accepted for upload, as sniffed from the content rather than the client's claim
+pub const ALLOWED_ATTACHMENT_TYPES: [&str; 8] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "application/zip",
+    "audio/mpeg",
+    "video/mp4",
+];
+
+/// An attachment as embedded in comments and messages. The file itself is only served
+/// through `url`, which checks that the caller may see it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentModel {
+    pub id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub url: String,
+}
+
+impl AttachmentModel {
+    pub fn download_url(attachment_id: i32) -> String {
+        format!("/api/attachments/{}", attachment_id)
+    }
+}
+
+impl From<AttachmentEntity> for AttachmentModel {
+    fn from(attachment: AttachmentEntity) -> Self {
+        Self {
+            id: attachment.id,
+            url: Self::download_url(attachment.id),
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+        }
+    }
+}
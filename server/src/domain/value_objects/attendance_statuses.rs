@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AttendanceStatuses {
+    #[default]
+    Pending,
+    Present,
+    NoShow,
+}
+
+impl Display for AttendanceStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttendanceStatuses::Pending => write!(f, "Pending"),
+            AttendanceStatuses::Present => write!(f, "Present"),
+            AttendanceStatuses::NoShow => write!(f, "NoShow"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAttendanceModel {
+    pub status: AttendanceStatuses,
+}
@@ -17,7 +17,11 @@ impl Base64Img {
             Err(_) => return Err(anyhow::anyhow!("invalid img data !!")),
         };
         let file_type = match infer::get(&bytes) {
-            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
+            Some(t)
+                if t.mime_type() == "image/png"
+                    || t.mime_type() == "image/jpeg"
+                    || t.mime_type() == "image/webp" =>
+            {
                 t.mime_type()
             }
             _ => return Err(anyhow::anyhow!("un-support file type")),
@@ -26,4 +30,13 @@ impl Base64Img {
         let base64text = format!("data:{};base64,{}", file_type, data);
         Ok(Self(base64text))
     }
+
+    /// The decoded image behind the data URI
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let data = self
+            .0
+            .split_once("base64,")
+            .map_or(self.0.as_str(), |(_, data)| data);
+        Ok(general_purpose::STANDARD.decode(data)?)
+    }
 }
@@ -1,7 +1,11 @@
+use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+    value_objects::{achievements::AchievementModel, mission_rating_model::ReputationModel},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -32,6 +36,15 @@ pub struct BrawlerModel {
     pub mission_success_count: i64,
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub mission_join_count: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub mission_no_show_count: i64,
+    /// Share of attendance-tracked missions the brawler actually showed up to (`None` until one is recorded)
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Double>)]
+    pub reliability_rate: Option<f64>,
+    #[diesel(sql_type = diesel::sql_types::VarChar)]
+    pub attendance_status: String,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Timestamp>)]
+    pub checked_in_at: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,3 +56,11 @@ pub struct UpdateBrawlerModel {
     pub instagram: Option<String>,
     pub facebook: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BrawlerProfileModel {
+    #[serde(flatten)]
+    pub brawler: BrawlerEntity,
+    pub reputation: ReputationModel,
+    pub badges: Vec<AchievementModel>,
+}
@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Date, Int4, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerStatsSummaryModel {
+    #[diesel(sql_type = BigInt)]
+    pub missions_led: i64,
+    #[diesel(sql_type = BigInt)]
+    pub missions_joined: i64,
+    #[diesel(sql_type = BigInt)]
+    pub open_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub in_progress_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub completed_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct CategoryCountModel {
+    #[diesel(sql_type = Varchar)]
+    pub category: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct WeeklyActivityModel {
+    /// Monday of the week
+    #[diesel(sql_type = Date)]
+    pub week_start: NaiveDate,
+    #[diesel(sql_type = BigInt)]
+    pub mission_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct CrewmateModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = BigInt)]
+    pub missions_together: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrawlerStatsModel {
+    pub brawler_id: i32,
+    #[serde(flatten)]
+    pub summary: BrawlerStatsSummaryModel,
+    /// Completed out of finished (completed + failed) missions, `None` until one finishes
+    pub completion_rate: Option<f64>,
+    pub favourite_categories: Vec<CategoryCountModel>,
+    pub weekly_activity: Vec<WeeklyActivityModel>,
+    pub current_streak_weeks: i64,
+    pub longest_streak_weeks: i64,
+    pub top_crewmates: Vec<CrewmateModel>,
+}
@@ -0,0 +1,114 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{Array, BigInt, Bool, Int4, Json, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::conversations::ConversationEntity;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateConversationModel {
+    pub name: String,
+    /// Everyone to add besides the creator
+    pub participant_ids: Vec<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RenameConversationModel {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddParticipantsModel {
+    pub brawler_ids: Vec<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SendConversationMessageModel {
+    pub content: String,
+    /// Uploads from `POST /api/attachments` to send with the message
+    #[serde(default)]
+    pub attachment_ids: Vec<i32>,
+}
+
+/// Without an id everything up to the newest message is marked as read
+#[derive(Debug, Default, Deserialize)]
+pub struct MarkConversationReadModel {
+    pub last_read_message_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct ConversationMessageModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub conversation_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub sender_id: i32,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub sender_display_name: Option<String>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub sender_avatar_url: Option<String>,
+    #[diesel(sql_type = Text)]
+    pub content: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub edited_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deleted_at: Option<NaiveDateTime>,
+    /// `AttachmentModel`s sent with the message
+    #[diesel(sql_type = Json)]
+    pub attachments: serde_json::Value,
+}
+
+/// One row of the conversation list, as seen by one participant
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct ConversationSummaryModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub name: Option<String>,
+    #[diesel(sql_type = Bool)]
+    pub is_group: bool,
+    #[diesel(sql_type = Varchar)]
+    pub role: String,
+    #[diesel(sql_type = Array<Int4>)]
+    pub participant_ids: Vec<i32>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub last_message_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub last_message_sender_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub last_message_content: Option<String>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub last_message_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = BigInt)]
+    pub unread_count: i64,
+    #[diesel(sql_type = Timestamp)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct ConversationParticipantModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = Varchar)]
+    pub role: String,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub last_read_message_id: Option<i32>,
+    #[diesel(sql_type = Timestamp)]
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversationDetailModel {
+    #[serde(flatten)]
+    pub conversation: ConversationEntity,
+    pub participants: Vec<ConversationParticipantModel>,
+}
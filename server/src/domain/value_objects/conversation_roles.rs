@@ -0,0 +1,44 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Owners and admins manage a group; members can only chat and leave
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ConversationRoles {
+    Owner,
+    Admin,
+    #[default]
+    Member,
+}
+
+impl ConversationRoles {
+    pub fn can_manage(&self) -> bool {
+        matches!(self, ConversationRoles::Owner | ConversationRoles::Admin)
+    }
+}
+
+impl Display for ConversationRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversationRoles::Owner => write!(f, "Owner"),
+            ConversationRoles::Admin => write!(f, "Admin"),
+            ConversationRoles::Member => write!(f, "Member"),
+        }
+    }
+}
+
+impl FromStr for ConversationRoles {
+    type Err = anyhow::Error;
+
+    fn from_str(role: &str) -> Result<Self, Self::Err> {
+        match role {
+            "Owner" => Ok(Self::Owner),
+            "Admin" => Ok(Self::Admin),
+            "Member" => Ok(Self::Member),
+            _ => Err(anyhow::anyhow!(
+                "Conversation role must be Owner, Admin or Member, got {}",
+                role
+            )),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// How often an opted-in brawler gets the email digest
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum DigestFrequencies {
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequencies {
+    /// How far ahead the digest looks for upcoming missions
+    pub fn period(&self) -> chrono::Duration {
+        match self {
+            DigestFrequencies::Daily => chrono::Duration::days(1),
+            DigestFrequencies::Weekly => chrono::Duration::weeks(1),
+        }
+    }
+}
+
+impl Display for DigestFrequencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DigestFrequencies::Daily => write!(f, "daily"),
+            DigestFrequencies::Weekly => write!(f, "weekly"),
+        }
+    }
+}
+
+impl FromStr for DigestFrequencies {
+    type Err = anyhow::Error;
+
+    fn from_str(frequency: &str) -> Result<Self, Self::Err> {
+        match frequency {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(anyhow::anyhow!(
+                "Digest frequency must be daily or weekly, got {}",
+                frequency
+            )),
+        }
+    }
+}
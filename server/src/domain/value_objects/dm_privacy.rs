@@ -0,0 +1,43 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Who may start or continue a DM with a brawler
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum DmPrivacy {
+    #[default]
+    Everyone,
+    Friends,
+    Nobody,
+}
+
+impl Display for DmPrivacy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DmPrivacy::Everyone => write!(f, "Everyone"),
+            DmPrivacy::Friends => write!(f, "Friends"),
+            DmPrivacy::Nobody => write!(f, "Nobody"),
+        }
+    }
+}
+
+impl FromStr for DmPrivacy {
+    type Err = anyhow::Error;
+
+    fn from_str(privacy: &str) -> Result<Self, Self::Err> {
+        match privacy {
+            "Everyone" => Ok(Self::Everyone),
+            "Friends" => Ok(Self::Friends),
+            "Nobody" => Ok(Self::Nobody),
+            _ => Err(anyhow::anyhow!(
+                "DM privacy must be Everyone, Friends or Nobody, got {}",
+                privacy
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DmPrivacyModel {
+    pub dm_privacy: DmPrivacy,
+}
@@ -0,0 +1,79 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+/// `frequency` is `None` while the brawler hasn't opted in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailDigestSettingsModel {
+    pub frequency: Option<String>,
+    pub last_sent_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateEmailDigestModel {
+    /// `daily`, `weekly`, or `None` to opt out
+    pub frequency: Option<String>,
+}
+
+/// A subscriber whose next digest is due
+#[derive(Debug, Clone, QueryableByName)]
+pub struct DigestRecipientModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub contact_email: String,
+    #[diesel(sql_type = Varchar)]
+    pub frequency: String,
+}
+
+#[derive(Debug, Clone, Serialize, QueryableByName)]
+pub struct DigestMissionModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub name: String,
+    #[diesel(sql_type = Timestamp)]
+    pub scheduled_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, QueryableByName)]
+pub struct DigestDmModel {
+    #[diesel(sql_type = Int4)]
+    pub sender_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub sender_display_name: String,
+    #[diesel(sql_type = BigInt)]
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestNotificationModel {
+    pub content: String,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Everything the digest templates render
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestModel {
+    pub display_name: String,
+    pub frequency: String,
+    pub app_url: String,
+    pub notifications: Vec<DigestNotificationModel>,
+    pub upcoming_missions: Vec<DigestMissionModel>,
+    pub unread_dms: Vec<DigestDmModel>,
+}
+
+impl DigestModel {
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+            && self.upcoming_missions.is_empty()
+            && self.unread_dms.is_empty()
+    }
+}
@@ -1,10 +1,12 @@
 use chrono::NaiveDateTime;
 use diesel::{
     QueryableByName,
-    sql_types::{Int4, Text, Timestamp, Varchar},
+    sql_types::{BigInt, Bool, Int4, Json, Nullable, Text, Timestamp, Varchar},
 };
 use serde::{Deserialize, Serialize};
 
+use super::mission_model::MissionModel;
+
 #[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
 pub struct MissionCommentModel {
     #[diesel(sql_type = Int4)]
@@ -21,9 +23,79 @@ pub struct MissionCommentModel {
     pub content: String,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
+    /// The comment this one replies to
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub parent_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub edited_at: Option<NaiveDateTime>,
+    /// Deleted comments keep their place in the thread but come back with empty content
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deleted_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub deleted_by: Option<i32>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub pinned_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub pinned_by: Option<i32>,
+    /// `AttachmentModel`s sent with the comment
+    #[diesel(sql_type = Json)]
+    pub attachments: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AddMissionCommentModel {
     pub content: String,
+    pub parent_id: Option<i32>,
+    /// Uploads from `POST /api/attachments` to send with the comment
+    #[serde(default)]
+    pub attachment_ids: Vec<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EditMissionCommentModel {
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct CommentReactionSummaryModel {
+    #[diesel(sql_type = Int4)]
+    pub comment_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub emoji: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+    #[diesel(sql_type = Bool)]
+    pub reacted_by_me: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissionCommentWithReactionsModel {
+    #[serde(flatten)]
+    pub comment: MissionCommentModel,
+    pub reactions: Vec<CommentReactionSummaryModel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissionAnnouncementsModel {
+    #[serde(flatten)]
+    pub mission: MissionModel,
+    /// Newest pin first
+    pub pinned: Vec<MissionCommentModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CommentPageQuery {
+    /// Only return comments older than this one
+    pub before_id: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateReadMarkerModel {
+    pub last_read_comment_id: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ToggleReactionModel {
+    pub emoji: String,
 }
@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        entities::mission_gallery_items::MissionGalleryItemEntity,
+        value_objects::uploaded_img::UploadedImg,
+    },
+    infrastructure::image_processing::{MISSION_GALLERY_WIDTHS, variant_key},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionGalleryItemModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploader_id: i32,
+    /// Full view
+    pub image_url: String,
+    pub thumbnail_url: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<MissionGalleryItemEntity> for MissionGalleryItemModel {
+    fn from(item: MissionGalleryItemEntity) -> Self {
+        Self {
+            id: item.id,
+            mission_id: item.mission_id,
+            uploader_id: item.uploader_id,
+            thumbnail_url: UploadedImg::media_url(&variant_key(
+                &item.image_public_id,
+                MISSION_GALLERY_WIDTHS[0],
+            )),
+            image_url: item.image_url,
+            created_at: item.created_at,
+        }
+    }
+}
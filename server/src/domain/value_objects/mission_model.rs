@@ -1,7 +1,7 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use diesel::{
     QueryableByName,
-    sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar},
+    sql_types::{BigInt, Double, Int4, Nullable, Text, Timestamp, Varchar},
 };
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +42,24 @@ pub struct MissionModel {
     pub deleted_at: Option<NaiveDateTime>,
     #[diesel(sql_type = Varchar)]
     pub category: String,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub finished_at: Option<NaiveDateTime>,
+    /// Lowest reputation score a brawler needs to join (`None` means anyone can)
+    #[diesel(sql_type = Nullable<Double>)]
+    pub min_reputation: Option<f64>,
+    /// Largest cover variant; `None` until the chief uploads one
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub cover_url: Option<String>,
+}
+
+/// A mission the brawler crews, with how many chat messages they haven't read yet
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct JoinedMissionModel {
+    #[diesel(embed)]
+    #[serde(flatten)]
+    pub mission: MissionModel,
+    #[diesel(sql_type = BigInt)]
+    pub unread_count: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -52,6 +70,7 @@ pub struct AddMissionModel {
     pub scheduled_at: Option<DateTime<Utc>>,
     pub location: Option<String>,
     pub category: Option<String>,
+    pub min_reputation: Option<f64>,
 }
 
 impl AddMissionModel {
@@ -65,6 +84,7 @@ impl AddMissionModel {
             scheduled_at: self.scheduled_at.map(|dt| dt.naive_utc()),
             location: self.location.clone(),
             category: self.category.clone().unwrap_or("Other".to_string()),
+            min_reputation: self.min_reputation,
         }
     }
 }
@@ -77,6 +97,7 @@ pub struct EditMissionModel {
     pub scheduled_at: Option<DateTime<Utc>>,
     pub location: Option<String>,
     pub category: Option<String>,
+    pub min_reputation: Option<f64>,
 }
 
 impl EditMissionModel {
@@ -89,6 +110,7 @@ impl EditMissionModel {
             scheduled_at: self.scheduled_at.map(|dt| dt.naive_utc()),
             location: self.location.clone(),
             category: self.category.clone(),
+            min_reputation: self.min_reputation,
         }
     }
 }
@@ -0,0 +1,80 @@
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Text},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_ratings::AddMissionRatingEntity;
+
+/// Tags a rater can attach to a score
+pub const RATING_TAGS: [&str; 6] = [
+    "on_time",
+    "leader",
+    "team_player",
+    "communicative",
+    "skilled",
+    "friendly",
+];
+
+/// Score every brawler starts from, so a single rating can't make or break a reputation
+const PRIOR_SCORE: f64 = 3.0;
+/// How many ratings the prior is worth
+const PRIOR_WEIGHT: f64 = 5.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionRatingModel {
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Option<Vec<String>>,
+}
+
+impl AddMissionRatingModel {
+    pub fn to_entity(&self, mission_id: i32, rater_id: i32) -> AddMissionRatingEntity {
+        let mut tags = self.tags.clone().unwrap_or_default();
+        tags.sort();
+        tags.dedup();
+
+        AddMissionRatingEntity {
+            mission_id,
+            rater_id,
+            ratee_id: self.ratee_id,
+            score: self.score,
+            tags,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct RatingTagCountModel {
+    #[diesel(sql_type = Text)]
+    pub tag: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReputationModel {
+    pub reputation_score: f64,
+    pub average_score: Option<f64>,
+    pub rating_count: i64,
+    pub tags: Vec<RatingTagCountModel>,
+}
+
+impl ReputationModel {
+    pub fn new(
+        rating_count: i64,
+        average_score: Option<f64>,
+        tags: Vec<RatingTagCountModel>,
+    ) -> Self {
+        let count = rating_count as f64;
+        let total = average_score.unwrap_or(0.0) * count;
+        let reputation_score = (PRIOR_SCORE * PRIOR_WEIGHT + total) / (PRIOR_WEIGHT + count);
+
+        Self {
+            reputation_score: (reputation_score * 100.0).round() / 100.0,
+            average_score,
+            rating_count,
+            tags,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::mission_comment_model::MissionCommentModel;
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TranscriptFormats {
+    #[default]
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl TranscriptFormats {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TranscriptFormats::Json => "application/json",
+            TranscriptFormats::Csv => "text/csv; charset=utf-8",
+            TranscriptFormats::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TranscriptFormats::Json => "json",
+            TranscriptFormats::Csv => "csv",
+            TranscriptFormats::Markdown => "md",
+        }
+    }
+}
+
+impl Display for TranscriptFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranscriptFormats::Json => write!(f, "json"),
+            TranscriptFormats::Csv => write!(f, "csv"),
+            TranscriptFormats::Markdown => write!(f, "markdown"),
+        }
+    }
+}
+
+impl FromStr for TranscriptFormats {
+    type Err = anyhow::Error;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(anyhow::anyhow!(
+                "Unknown export format: {}. Use json, csv or markdown!",
+                format
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TranscriptArchiveReasons {
+    ChatCleared,
+    MissionRemoved,
+}
+
+impl Display for TranscriptArchiveReasons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranscriptArchiveReasons::ChatCleared => write!(f, "chat_cleared"),
+            TranscriptArchiveReasons::MissionRemoved => write!(f, "mission_removed"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TranscriptExportQuery {
+    pub format: Option<String>,
+}
+
+/// One message as it appears in an export or archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptEntryModel {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub author_id: i32,
+    pub author_display_name: String,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+    pub edited_at: Option<NaiveDateTime>,
+}
+
+impl From<MissionCommentModel> for TranscriptEntryModel {
+    fn from(comment: MissionCommentModel) -> Self {
+        Self {
+            id: comment.id,
+            parent_id: comment.parent_id,
+            author_id: comment.brawler_id,
+            author_display_name: comment.brawler_display_name,
+            content: comment.content,
+            created_at: comment.created_at,
+            edited_at: comment.edited_at,
+        }
+    }
+}
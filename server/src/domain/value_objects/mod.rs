@@ -1,7 +1,31 @@
+pub mod achievements;
+pub mod attachment_model;
+pub mod attendance_statuses;
 pub mod base64_img;
 pub mod brawler_model;
+pub mod brawler_stats_model;
+pub mod conversation_model;
+pub mod conversation_roles;
+pub mod digest_frequencies;
+pub mod dm_privacy;
+pub mod email_digest_model;
 pub mod mission_comment_model;
 pub mod mission_filter;
+pub mod mission_gallery_model;
 pub mod mission_model;
+pub mod mission_rating_model;
 pub mod mission_statuses;
+pub mod mission_transcript_model;
+pub mod notification_channels;
+pub mod notification_events;
+pub mod notification_model;
+pub mod notification_types;
+pub mod outgoing_email;
+pub mod scheduled_job_statuses;
+pub mod scheduled_job_types;
 pub mod uploaded_img;
+pub mod web_push_model;
+pub mod webhook_delivery_statuses;
+pub mod webhook_event_types;
+pub mod webhook_events;
+pub mod webhook_model;
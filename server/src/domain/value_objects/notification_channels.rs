@@ -0,0 +1,59 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::notification_types::NotificationTypes;
+
+/// How a notification reaches a brawler
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannels {
+    /// The bell: a row in `notifications`
+    InApp,
+    /// A toast over the global WebSocket
+    RealTime,
+    Email,
+    WebPush,
+}
+
+impl NotificationChannels {
+    pub const ALL: [NotificationChannels; 4] = [
+        NotificationChannels::InApp,
+        NotificationChannels::RealTime,
+        NotificationChannels::Email,
+        NotificationChannels::WebPush,
+    ];
+}
+
+impl Display for NotificationChannels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationChannels::InApp => write!(f, "in_app"),
+            NotificationChannels::RealTime => write!(f, "real_time"),
+            NotificationChannels::Email => write!(f, "email"),
+            NotificationChannels::WebPush => write!(f, "web_push"),
+        }
+    }
+}
+
+impl FromStr for NotificationChannels {
+    type Err = anyhow::Error;
+
+    fn from_str(channel: &str) -> Result<Self, Self::Err> {
+        match channel {
+            "in_app" => Ok(Self::InApp),
+            "real_time" => Ok(Self::RealTime),
+            "email" => Ok(Self::Email),
+            "web_push" => Ok(Self::WebPush),
+            _ => Err(anyhow::anyhow!("Unknown notification channel: {}", channel)),
+        }
+    }
+}
+
+/// One cell of the preference matrix, as read and written by the settings API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreferenceModel {
+    pub notification_type: NotificationTypes,
+    pub channel: NotificationChannels,
+    pub enabled: bool,
+}
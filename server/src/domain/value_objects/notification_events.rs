@@ -0,0 +1,73 @@
+use crate::domain::{
+    entities::private_messages::PrivateMessage,
+    value_objects::{
+        achievements::AchievementModel, mission_comment_model::MissionCommentModel,
+        mission_statuses::MissionStatuses,
+    },
+};
+
+/// Something brawlers should hear about. Callers only say what happened; who gets told,
+/// what it says and how it is delivered is up to the notification service.
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+    MissionStarted {
+        mission_id: i32,
+    },
+    /// `status` is either `Completed` or `Failed`; `automatic` when the schedule closed it
+    MissionClosed {
+        mission_id: i32,
+        status: MissionStatuses,
+        automatic: bool,
+    },
+    MissionReminder {
+        mission_id: i32,
+    },
+    /// Carries the mission and crew because a hard-deleted mission can't be read back
+    MissionDeleted {
+        mission_id: i32,
+        mission_name: String,
+        deleted_by: i32,
+        crew_ids: Vec<i32>,
+    },
+    CrewJoined {
+        mission_id: i32,
+        brawler_id: i32,
+    },
+    CrewLeft {
+        mission_id: i32,
+        brawler_id: i32,
+    },
+    KickedFromMission {
+        mission_id: i32,
+        brawler_id: i32,
+    },
+    MarkedNoShow {
+        mission_id: i32,
+        brawler_ids: Vec<i32>,
+    },
+    ChatMessage {
+        comment: MissionCommentModel,
+        mentioned_ids: Vec<i32>,
+    },
+    AnnouncementPinned {
+        comment: MissionCommentModel,
+        pinned_by: i32,
+    },
+    PrivateMessage {
+        message: PrivateMessage,
+    },
+    FriendRequested {
+        requester_id: i32,
+        requester_name: String,
+        receiver_id: i32,
+    },
+    FriendAccepted {
+        friend_id: i32,
+        friend_name: String,
+        requester_id: i32,
+    },
+    AchievementUnlocked {
+        brawler_id: i32,
+        achievement: AchievementModel,
+    },
+}
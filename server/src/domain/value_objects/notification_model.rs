@@ -0,0 +1,34 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NotificationPageQuery {
+    /// Id of the last notification seen; goes with `cursor_updated_at`
+    pub cursor: Option<i32>,
+    /// `updated_at` of the last notification seen
+    pub cursor_updated_at: Option<NaiveDateTime>,
+    pub limit: Option<i64>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    #[serde(default)]
+    pub unread_only: bool,
+}
+
+/// Where the next page starts. Groups move up when they take a new notification,
+/// so position is `(updated_at, id)` rather than the id alone.
+#[derive(Debug, Clone, Copy)]
+pub struct NotificationCursor {
+    pub updated_at: NaiveDateTime,
+    pub id: i32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NotificationFilter {
+    pub type_: Option<String>,
+    pub unread_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnreadCountModel {
+    pub unread_count: i64,
+}
@@ -0,0 +1,95 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// What a notification is about; stored as `notifications.type`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationTypes {
+    AchievementUnlocked,
+    CrewLeft,
+    FriendAccepted,
+    FriendRequest,
+    KickedFromMission,
+    MarkedNoShow,
+    Mention,
+    MissionAnnouncement,
+    MissionCompleted,
+    MissionDeleted,
+    MissionFailed,
+    MissionReminder,
+    MissionStarted,
+    NewChatMessage,
+    NewCrewJoined,
+    PrivateMessage,
+}
+
+impl NotificationTypes {
+    pub const ALL: [NotificationTypes; 16] = [
+        NotificationTypes::AchievementUnlocked,
+        NotificationTypes::CrewLeft,
+        NotificationTypes::FriendAccepted,
+        NotificationTypes::FriendRequest,
+        NotificationTypes::KickedFromMission,
+        NotificationTypes::MarkedNoShow,
+        NotificationTypes::Mention,
+        NotificationTypes::MissionAnnouncement,
+        NotificationTypes::MissionCompleted,
+        NotificationTypes::MissionDeleted,
+        NotificationTypes::MissionFailed,
+        NotificationTypes::MissionReminder,
+        NotificationTypes::MissionStarted,
+        NotificationTypes::NewChatMessage,
+        NotificationTypes::NewCrewJoined,
+        NotificationTypes::PrivateMessage,
+    ];
+
+    /// Worth a Web Push when the brawler is offline; the rest wait for the bell or the digest
+    pub fn is_high_priority(&self) -> bool {
+        matches!(
+            self,
+            NotificationTypes::FriendRequest
+                | NotificationTypes::KickedFromMission
+                | NotificationTypes::Mention
+                | NotificationTypes::MissionAnnouncement
+                | NotificationTypes::MissionDeleted
+                | NotificationTypes::MissionReminder
+                | NotificationTypes::MissionStarted
+                | NotificationTypes::PrivateMessage
+        )
+    }
+}
+
+impl Display for NotificationTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationTypes::AchievementUnlocked => write!(f, "achievement_unlocked"),
+            NotificationTypes::CrewLeft => write!(f, "crew_left"),
+            NotificationTypes::FriendAccepted => write!(f, "friend_accepted"),
+            NotificationTypes::FriendRequest => write!(f, "friend_request"),
+            NotificationTypes::KickedFromMission => write!(f, "kicked_from_mission"),
+            NotificationTypes::MarkedNoShow => write!(f, "marked_no_show"),
+            NotificationTypes::Mention => write!(f, "mention"),
+            NotificationTypes::MissionAnnouncement => write!(f, "mission_announcement"),
+            NotificationTypes::MissionCompleted => write!(f, "mission_completed"),
+            NotificationTypes::MissionDeleted => write!(f, "mission_deleted"),
+            NotificationTypes::MissionFailed => write!(f, "mission_failed"),
+            NotificationTypes::MissionReminder => write!(f, "mission_reminder"),
+            NotificationTypes::MissionStarted => write!(f, "mission_started"),
+            NotificationTypes::NewChatMessage => write!(f, "new_chat_message"),
+            NotificationTypes::NewCrewJoined => write!(f, "new_crew_joined"),
+            NotificationTypes::PrivateMessage => write!(f, "private_message"),
+        }
+    }
+}
+
+impl FromStr for NotificationTypes {
+    type Err = anyhow::Error;
+
+    fn from_str(notification_type: &str) -> Result<Self, Self::Err> {
+        NotificationTypes::ALL
+            .into_iter()
+            .find(|known| known.to_string() == notification_type)
+            .ok_or_else(|| anyhow::anyhow!("Unknown notification type: {}", notification_type))
+    }
+}
@@ -0,0 +1,8 @@
+/// A message for the mailer; both bodies are sent as alternatives
+#[derive(Debug, Clone)]
+pub struct OutgoingEmail {
+    pub to: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
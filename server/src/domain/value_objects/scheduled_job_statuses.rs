@@ -0,0 +1,23 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ScheduledJobStatuses {
+    #[default]
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+impl Display for ScheduledJobStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduledJobStatuses::Pending => write!(f, "Pending"),
+            ScheduledJobStatuses::Running => write!(f, "Running"),
+            ScheduledJobStatuses::Done => write!(f, "Done"),
+            ScheduledJobStatuses::Failed => write!(f, "Failed"),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ScheduledJobTypes {
+    MissionReminder,
+    MissionAutoStart,
+    MissionAutoExpire,
+    RefreshBrawlerStats,
+    PurgeNotifications,
+    SendEmailDigests,
+    PurgePushSubscriptions,
+    DeliverWebhook,
+    PurgeScheduledJobs,
+}
+
+impl Display for ScheduledJobTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduledJobTypes::MissionReminder => write!(f, "mission_reminder"),
+            ScheduledJobTypes::MissionAutoStart => write!(f, "mission_auto_start"),
+            ScheduledJobTypes::MissionAutoExpire => write!(f, "mission_auto_expire"),
+            ScheduledJobTypes::RefreshBrawlerStats => write!(f, "refresh_brawler_stats"),
+            ScheduledJobTypes::PurgeNotifications => write!(f, "purge_notifications"),
+            ScheduledJobTypes::SendEmailDigests => write!(f, "send_email_digests"),
+            ScheduledJobTypes::PurgePushSubscriptions => write!(f, "purge_push_subscriptions"),
+            ScheduledJobTypes::DeliverWebhook => write!(f, "deliver_webhook"),
+            ScheduledJobTypes::PurgeScheduledJobs => write!(f, "purge_scheduled_jobs"),
+        }
+    }
+}
+
+impl FromStr for ScheduledJobTypes {
+    type Err = anyhow::Error;
+
+    fn from_str(job_type: &str) -> Result<Self, Self::Err> {
+        match job_type {
+            "mission_reminder" => Ok(Self::MissionReminder),
+            "mission_auto_start" => Ok(Self::MissionAutoStart),
+            "mission_auto_expire" => Ok(Self::MissionAutoExpire),
+            "refresh_brawler_stats" => Ok(Self::RefreshBrawlerStats),
+            "purge_notifications" => Ok(Self::PurgeNotifications),
+            "send_email_digests" => Ok(Self::SendEmailDigests),
+            "purge_push_subscriptions" => Ok(Self::PurgePushSubscriptions),
+            "deliver_webhook" => Ok(Self::DeliverWebhook),
+            "purge_scheduled_jobs" => Ok(Self::PurgeScheduledJobs),
+            _ => Err(anyhow::anyhow!("Unknown job type: {}", job_type)),
+        }
+    }
+}
+
+/// Which jobs a runner loop claims, so slow webhook deliveries get a lane of their own
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobLane {
+    Only(ScheduledJobTypes),
+    Except(ScheduledJobTypes),
+}
@@ -9,6 +9,11 @@ impl UploadedImg {
     pub fn new(url: String, public_id: String) -> Self {
         Self { url, public_id }
     }
+
+    /// Public URL of a processed image in blob storage, served by the media router
+    pub fn media_url(key: &str) -> String {
+        format!("/api/media/{}", key)
+    }
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UploadBase64Img {
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// A `PushSubscription` as the browser serializes it with `toJSON()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushSubscriptionModel {
+    pub endpoint: String,
+    /// Milliseconds since the epoch
+    #[serde(rename = "expirationTime")]
+    pub expiration_time: Option<i64>,
+    pub keys: PushSubscriptionKeys,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushSubscriptionKeys {
+    pub p256dh: String,
+    pub auth: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemovePushSubscriptionModel {
+    pub endpoint: String,
+}
+
+/// What the service worker needs for `pushManager.subscribe({ applicationServerKey })`
+#[derive(Debug, Clone, Serialize)]
+pub struct VapidPublicKeyModel {
+    pub public_key: String,
+}
+
+/// The JSON the service worker receives in its `push` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushPayloadModel {
+    pub title: String,
+    pub body: String,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    pub related_id: Option<i32>,
+    /// Same tag replaces the previous notification instead of stacking
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PushOutcomes {
+    Delivered,
+    /// The push service no longer knows the subscription; it should be dropped
+    Gone,
+}
@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum WebhookDeliveryStatuses {
+    #[default]
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl Display for WebhookDeliveryStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookDeliveryStatuses::Pending => write!(f, "Pending"),
+            WebhookDeliveryStatuses::Delivered => write!(f, "Delivered"),
+            WebhookDeliveryStatuses::Failed => write!(f, "Failed"),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// The events a webhook can subscribe to, named the way they go out on the wire
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum WebhookEventTypes {
+    MissionCreated,
+    MissionStarted,
+    MissionCompleted,
+    MissionFailed,
+    CrewJoined,
+    CrewLeft,
+    CommentCreated,
+}
+
+impl WebhookEventTypes {
+    pub const ALL: [WebhookEventTypes; 7] = [
+        WebhookEventTypes::MissionCreated,
+        WebhookEventTypes::MissionStarted,
+        WebhookEventTypes::MissionCompleted,
+        WebhookEventTypes::MissionFailed,
+        WebhookEventTypes::CrewJoined,
+        WebhookEventTypes::CrewLeft,
+        WebhookEventTypes::CommentCreated,
+    ];
+}
+
+impl Display for WebhookEventTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEventTypes::MissionCreated => write!(f, "mission.created"),
+            WebhookEventTypes::MissionStarted => write!(f, "mission.started"),
+            WebhookEventTypes::MissionCompleted => write!(f, "mission.completed"),
+            WebhookEventTypes::MissionFailed => write!(f, "mission.failed"),
+            WebhookEventTypes::CrewJoined => write!(f, "crew.joined"),
+            WebhookEventTypes::CrewLeft => write!(f, "crew.left"),
+            WebhookEventTypes::CommentCreated => write!(f, "comment.created"),
+        }
+    }
+}
+
+impl FromStr for WebhookEventTypes {
+    type Err = anyhow::Error;
+
+    fn from_str(event: &str) -> Result<Self, Self::Err> {
+        match event {
+            "mission.created" => Ok(Self::MissionCreated),
+            "mission.started" => Ok(Self::MissionStarted),
+            "mission.completed" => Ok(Self::MissionCompleted),
+            "mission.failed" => Ok(Self::MissionFailed),
+            "crew.joined" => Ok(Self::CrewJoined),
+            "crew.left" => Ok(Self::CrewLeft),
+            "comment.created" => Ok(Self::CommentCreated),
+            _ => Err(anyhow::anyhow!("Unknown webhook event: {}", event)),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::value_objects::{
+    mission_comment_model::MissionCommentModel, webhook_event_types::WebhookEventTypes,
+};
+
+/// Something that happened to a mission which webhooks may want to hear about.
+/// Callers only say what happened; the webhook service works out who is subscribed.
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    MissionCreated { mission_id: i32 },
+    MissionStarted { mission_id: i32 },
+    MissionCompleted { mission_id: i32 },
+    MissionFailed { mission_id: i32 },
+    CrewJoined { mission_id: i32, brawler_id: i32 },
+    CrewLeft { mission_id: i32, brawler_id: i32 },
+    CommentCreated { comment: MissionCommentModel },
+}
+
+impl WebhookEvent {
+    pub fn event_type(&self) -> WebhookEventTypes {
+        match self {
+            WebhookEvent::MissionCreated { .. } => WebhookEventTypes::MissionCreated,
+            WebhookEvent::MissionStarted { .. } => WebhookEventTypes::MissionStarted,
+            WebhookEvent::MissionCompleted { .. } => WebhookEventTypes::MissionCompleted,
+            WebhookEvent::MissionFailed { .. } => WebhookEventTypes::MissionFailed,
+            WebhookEvent::CrewJoined { .. } => WebhookEventTypes::CrewJoined,
+            WebhookEvent::CrewLeft { .. } => WebhookEventTypes::CrewLeft,
+            WebhookEvent::CommentCreated { .. } => WebhookEventTypes::CommentCreated,
+        }
+    }
+
+    pub fn mission_id(&self) -> i32 {
+        match self {
+            WebhookEvent::MissionCreated { mission_id }
+            | WebhookEvent::MissionStarted { mission_id }
+            | WebhookEvent::MissionCompleted { mission_id }
+            | WebhookEvent::MissionFailed { mission_id }
+            | WebhookEvent::CrewJoined { mission_id, .. }
+            | WebhookEvent::CrewLeft { mission_id, .. } => *mission_id,
+            WebhookEvent::CommentCreated { comment } => comment.mission_id,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::webhooks::{WebhookDeliveryEntity, WebhookEntity},
+    value_objects::mission_model::MissionModel,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddWebhookModel {
+    pub url: String,
+    pub events: Vec<String>,
+    /// Only hear about this mission; its chief or an admin may scope a webhook to it
+    pub mission_id: Option<i32>,
+    /// Admins only: hear about every mission instead of just the ones they lead
+    #[serde(default)]
+    pub all_missions: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EditWebhookModel {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub active: Option<bool>,
+}
+
+/// A webhook as its owner sees it; the secret is only shown once, on creation
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookModel {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<String>,
+    pub mission_id: Option<i32>,
+    pub all_missions: bool,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<WebhookEntity> for WebhookModel {
+    fn from(webhook: WebhookEntity) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            mission_id: webhook.mission_id,
+            all_missions: webhook.all_missions,
+            active: webhook.active,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedWebhookModel {
+    #[serde(flatten)]
+    pub webhook: WebhookModel,
+    /// Key for checking `X-Brawlers-Signature`
+    pub secret: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct WebhookDeliveryPageQuery {
+    /// Only return deliveries older than this one
+    pub cursor: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDeliveryModel {
+    pub id: i32,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    /// HTTP status of the last attempt, if the endpoint answered at all
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub last_attempt_at: Option<NaiveDateTime>,
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+impl From<WebhookDeliveryEntity> for WebhookDeliveryModel {
+    fn from(delivery: WebhookDeliveryEntity) -> Self {
+        Self {
+            id: delivery.id,
+            event: delivery.event,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            last_attempt_at: delivery.last_attempt_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+/// The mission as it appears in webhook payloads
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookMissionModel {
+    pub id: i32,
+    pub name: String,
+    pub status: String,
+    pub category: String,
+    pub chief_id: i32,
+    pub crew_count: i64,
+    pub max_crew: i32,
+    pub scheduled_at: Option<NaiveDateTime>,
+    pub location: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+}
+
+impl From<MissionModel> for WebhookMissionModel {
+    fn from(mission: MissionModel) -> Self {
+        Self {
+            id: mission.id,
+            name: mission.name,
+            status: mission.status,
+            category: mission.category,
+            chief_id: mission.chief_id,
+            crew_count: mission.crew_count,
+            max_crew: mission.max_crew,
+            scheduled_at: mission.scheduled_at,
+            location: mission.location,
+            created_at: mission.created_at,
+            finished_at: mission.finished_at,
+        }
+    }
+}
+
+/// The outcome of one attempt to POST a delivery
+#[derive(Debug, Clone)]
+pub struct WebhookResponse {
+    pub status: u16,
+}
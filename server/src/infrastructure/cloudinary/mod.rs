@@ -14,13 +14,17 @@ pub struct UploadImageOptions {
     pub transformation: Option<String>,
 }
 
-fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result<Form> {
+fn form_builder(
+    option: UploadImageOptions,
+    resource_type: &str,
+    cloud_env: &CloudinaryEnv,
+) -> Result<Form> {
     let mut form = Form::new();
     let timestamp = Utc::now().timestamp_millis().to_string();
     let mut hasher = Sha1::new();
 
     let mut params_to_sign: HashMap<String, String> = HashMap::new();
-    params_to_sign.insert("resource_type".to_string(), "image".to_string());
+    params_to_sign.insert("resource_type".to_string(), resource_type.to_string());
     params_to_sign.insert("timestamp".to_string(), timestamp.clone());
     if let Some(folder_name) = option.folder {
         params_to_sign.insert("folder".to_string(), folder_name);
@@ -62,7 +66,7 @@ pub async fn upload(base64_image: Base64Img, option: UploadImageOptions) -> Resu
     let cloud_env = get_cloudinary_env()?;
 
     let file = Part::text(base64_image.into_inner());
-    let form = form_builder(option, &cloud_env)?;
+    let form = form_builder(option, "image", &cloud_env)?;
     let multipart = form.part("file", file);
     let client = reqwest::Client::new();
     let url = format!(
@@ -82,3 +86,69 @@ pub async fn upload(base64_image: Base64Img, option: UploadImageOptions) -> Resu
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
 }
+
+/// Uploads any file as a `raw` resource under the exact `public_id`
+pub async fn upload_raw(bytes: Vec<u8>, public_id: &str) -> Result<()> {
+    let cloud_env = get_cloudinary_env()?;
+
+    let option = UploadImageOptions {
+        folder: None,
+        public_id: Some(public_id.to_string()),
+        transformation: None,
+    };
+    let form = form_builder(option, "raw", &cloud_env)?.part("file", Part::bytes(bytes));
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/raw/upload",
+        cloud_env.cloud_name
+    );
+
+    reqwest::Client::new()
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("upload to {}", url))?
+        .error_for_status()?;
+    Ok(())
+}
+
+pub async fn download_raw(public_id: &str) -> Result<Vec<u8>> {
+    let cloud_env = get_cloudinary_env()?;
+    let url = format!(
+        "https://res.cloudinary.com/{}/raw/upload/{}",
+        cloud_env.cloud_name, public_id
+    );
+
+    let bytes = reqwest::get(&url)
+        .await
+        .context(format!("download from {}", url))?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(bytes.to_vec())
+}
+
+/// Removes an asset; `resource_type` is `image` for uploads made with [`upload`]
+pub async fn destroy(public_id: &str, resource_type: &str) -> Result<()> {
+    let cloud_env = get_cloudinary_env()?;
+
+    let option = UploadImageOptions {
+        folder: None,
+        public_id: Some(public_id.to_string()),
+        transformation: None,
+    };
+    let form = form_builder(option, resource_type, &cloud_env)?;
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/{}/destroy",
+        cloud_env.cloud_name, resource_type
+    );
+
+    reqwest::Client::new()
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("destroy via {}", url))?
+        .error_for_status()?;
+    Ok(())
+}
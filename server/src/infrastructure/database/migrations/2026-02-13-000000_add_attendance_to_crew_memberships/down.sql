@@ -0,0 +1,2 @@
+ALTER TABLE crew_memberships DROP COLUMN checked_in_at;
+ALTER TABLE crew_memberships DROP COLUMN attendance_status;
@@ -0,0 +1,2 @@
+ALTER TABLE crew_memberships ADD COLUMN attendance_status VARCHAR(20) NOT NULL DEFAULT 'Pending';
+ALTER TABLE crew_memberships ADD COLUMN checked_in_at TIMESTAMP;
@@ -0,0 +1,17 @@
+CREATE TABLE scheduled_jobs (
+    id SERIAL PRIMARY KEY,
+    job_type VARCHAR(100) NOT NULL,
+    related_id INT,
+    payload JSONB NOT NULL DEFAULT '{}',
+    run_at TIMESTAMP NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'Pending',
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_scheduled_jobs_due ON scheduled_jobs(status, run_at);
+CREATE INDEX idx_scheduled_jobs_related ON scheduled_jobs(job_type, related_id);
+
+SELECT diesel_manage_updated_at('scheduled_jobs');
@@ -0,0 +1,3 @@
+DROP TABLE mission_ratings;
+ALTER TABLE missions DROP COLUMN min_reputation;
+ALTER TABLE missions DROP COLUMN finished_at;
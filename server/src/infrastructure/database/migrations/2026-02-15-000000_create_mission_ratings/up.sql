@@ -0,0 +1,19 @@
+ALTER TABLE missions ADD COLUMN finished_at TIMESTAMP;
+ALTER TABLE missions ADD COLUMN min_reputation DOUBLE PRECISION;
+
+-- Missions closed before this migration count as finished at their last update
+UPDATE missions SET finished_at = updated_at WHERE status IN ('Completed', 'Failed');
+
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INT NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    rater_id INT NOT NULL REFERENCES brawlers(id),
+    ratee_id INT NOT NULL REFERENCES brawlers(id),
+    score INT NOT NULL CHECK (score BETWEEN 1 AND 5),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_mission_rating UNIQUE (mission_id, rater_id, ratee_id),
+    CONSTRAINT no_self_rating CHECK (rater_id <> ratee_id)
+);
+
+CREATE INDEX idx_mission_ratings_ratee ON mission_ratings(ratee_id);
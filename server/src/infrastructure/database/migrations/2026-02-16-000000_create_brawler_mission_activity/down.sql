@@ -0,0 +1 @@
+DROP MATERIALIZED VIEW brawler_mission_activity;
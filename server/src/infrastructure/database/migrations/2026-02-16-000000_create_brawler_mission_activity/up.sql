@@ -0,0 +1,25 @@
+-- One row per brawler per mission they led or crewed, used for profile statistics.
+-- Refreshed periodically by the job runner rather than on every write.
+CREATE MATERIALIZED VIEW brawler_mission_activity AS
+SELECT m.chief_id AS brawler_id,
+       m.id AS mission_id,
+       'Chief' AS role,
+       m.status,
+       m.category,
+       COALESCE(m.scheduled_at, m.created_at) AS activity_at
+FROM missions m
+WHERE m.deleted_at IS NULL
+UNION ALL
+SELECT cm.brawler_id,
+       m.id AS mission_id,
+       'Crew' AS role,
+       m.status,
+       m.category,
+       COALESCE(m.scheduled_at, m.created_at) AS activity_at
+FROM crew_memberships cm
+INNER JOIN missions m ON m.id = cm.mission_id
+WHERE m.deleted_at IS NULL;
+
+-- Required for REFRESH MATERIALIZED VIEW CONCURRENTLY
+CREATE UNIQUE INDEX idx_brawler_mission_activity_unique ON brawler_mission_activity(brawler_id, mission_id);
+CREATE INDEX idx_brawler_mission_activity_mission ON brawler_mission_activity(mission_id);
@@ -0,0 +1,7 @@
+CREATE TABLE brawler_achievements (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    achievement_code VARCHAR(100) NOT NULL,
+    awarded_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_brawler_achievement UNIQUE (brawler_id, achievement_code)
+);
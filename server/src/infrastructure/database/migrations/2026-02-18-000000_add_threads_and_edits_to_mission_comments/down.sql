@@ -0,0 +1,6 @@
+DROP TABLE mission_comment_revisions;
+DROP INDEX idx_mission_comments_parent;
+ALTER TABLE mission_comments DROP COLUMN deleted_by;
+ALTER TABLE mission_comments DROP COLUMN deleted_at;
+ALTER TABLE mission_comments DROP COLUMN edited_at;
+ALTER TABLE mission_comments DROP COLUMN parent_id;
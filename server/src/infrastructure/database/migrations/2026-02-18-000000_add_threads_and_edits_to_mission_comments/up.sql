@@ -0,0 +1,16 @@
+ALTER TABLE mission_comments ADD COLUMN parent_id INT REFERENCES mission_comments(id) ON DELETE CASCADE;
+ALTER TABLE mission_comments ADD COLUMN edited_at TIMESTAMP;
+ALTER TABLE mission_comments ADD COLUMN deleted_at TIMESTAMP;
+ALTER TABLE mission_comments ADD COLUMN deleted_by INT REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_comments_parent ON mission_comments(parent_id);
+
+-- Previous versions of a comment, one row per edit
+CREATE TABLE mission_comment_revisions (
+    id SERIAL PRIMARY KEY,
+    comment_id INT NOT NULL REFERENCES mission_comments(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_comment_revisions_comment ON mission_comment_revisions(comment_id);
@@ -0,0 +1,8 @@
+CREATE TABLE mission_comment_reactions (
+    id SERIAL PRIMARY KEY,
+    comment_id INT NOT NULL REFERENCES mission_comments(id) ON DELETE CASCADE,
+    brawler_id INT NOT NULL REFERENCES brawlers(id),
+    emoji VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_comment_reaction UNIQUE (comment_id, brawler_id, emoji)
+);
@@ -0,0 +1,2 @@
+DROP INDEX idx_mission_comments_mission_id_id;
+DROP TABLE mission_read_markers;
@@ -0,0 +1,10 @@
+CREATE TABLE mission_read_markers (
+    mission_id INT NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    last_read_comment_id INT NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (mission_id, brawler_id)
+);
+
+-- Keyset pagination walks comments of one mission by id
+CREATE INDEX idx_mission_comments_mission_id_id ON mission_comments(mission_id, id);
@@ -0,0 +1,3 @@
+DROP INDEX idx_mission_comments_pinned;
+ALTER TABLE mission_comments DROP COLUMN pinned_by;
+ALTER TABLE mission_comments DROP COLUMN pinned_at;
@@ -0,0 +1,4 @@
+ALTER TABLE mission_comments ADD COLUMN pinned_at TIMESTAMP;
+ALTER TABLE mission_comments ADD COLUMN pinned_by INT REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_comments_pinned ON mission_comments(mission_id) WHERE pinned_at IS NOT NULL;
@@ -0,0 +1,15 @@
+-- Snapshots of a mission chat taken right before it is wiped.
+-- No FK to missions: the mission itself may be hard-deleted right after.
+CREATE TABLE mission_transcripts (
+    id SERIAL PRIMARY KEY,
+    mission_id INT NOT NULL,
+    mission_name VARCHAR(255) NOT NULL,
+    chief_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    participant_ids INT[] NOT NULL DEFAULT '{}',
+    reason VARCHAR(32) NOT NULL,
+    comments JSONB NOT NULL,
+    comment_count INT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_transcripts_mission ON mission_transcripts(mission_id);
@@ -0,0 +1,3 @@
+DROP INDEX idx_pm_content_search;
+DROP INDEX idx_pm_receiver;
+DROP INDEX idx_pm_pair_id;
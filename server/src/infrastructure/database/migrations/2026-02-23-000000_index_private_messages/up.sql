@@ -0,0 +1,6 @@
+-- Conversations are looked up by the unordered pair of participants, newest first
+CREATE INDEX idx_pm_pair_id ON private_messages (LEAST(sender_id, receiver_id), GREATEST(sender_id, receiver_id), id DESC);
+CREATE INDEX idx_pm_receiver ON private_messages (receiver_id, sender_id);
+
+-- Full-text search over a user's own DMs
+CREATE INDEX idx_pm_content_search ON private_messages USING GIN (to_tsvector('simple', content));
@@ -0,0 +1,4 @@
+ALTER TABLE private_messages DROP COLUMN hidden_for_receiver;
+ALTER TABLE private_messages DROP COLUMN hidden_for_sender;
+ALTER TABLE private_messages DROP COLUMN deleted_at;
+ALTER TABLE private_messages DROP COLUMN edited_at;
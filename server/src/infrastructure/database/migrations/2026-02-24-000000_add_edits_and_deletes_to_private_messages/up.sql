@@ -0,0 +1,6 @@
+ALTER TABLE private_messages ADD COLUMN edited_at TIMESTAMP;
+-- Set when the sender unsends: the row stays as a tombstone with its content wiped
+ALTER TABLE private_messages ADD COLUMN deleted_at TIMESTAMP;
+-- Delete-for-me, tracked per side
+ALTER TABLE private_messages ADD COLUMN hidden_for_sender BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE private_messages ADD COLUMN hidden_for_receiver BOOLEAN NOT NULL DEFAULT FALSE;
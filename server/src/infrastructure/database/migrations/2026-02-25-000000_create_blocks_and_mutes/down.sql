@@ -0,0 +1,3 @@
+ALTER TABLE brawlers DROP COLUMN dm_privacy;
+DROP TABLE brawler_mutes;
+DROP TABLE brawler_blocks;
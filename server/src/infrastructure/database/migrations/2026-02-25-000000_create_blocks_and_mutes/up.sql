@@ -0,0 +1,21 @@
+CREATE TABLE brawler_blocks (
+    blocker_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    blocked_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (blocker_id, blocked_id),
+    CHECK (blocker_id <> blocked_id)
+);
+
+CREATE INDEX idx_brawler_blocks_blocked ON brawler_blocks(blocked_id);
+
+-- Muting only silences notifications caused by the muted brawler
+CREATE TABLE brawler_mutes (
+    muter_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    muted_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (muter_id, muted_id),
+    CHECK (muter_id <> muted_id)
+);
+
+-- Who may open a DM: Everyone, Friends or Nobody
+ALTER TABLE brawlers ADD COLUMN dm_privacy VARCHAR(16) NOT NULL DEFAULT 'Everyone';
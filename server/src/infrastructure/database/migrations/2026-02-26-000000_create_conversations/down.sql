@@ -0,0 +1,6 @@
+DROP INDEX idx_pm_conversation_id;
+DELETE FROM private_messages WHERE receiver_id IS NULL;
+ALTER TABLE private_messages ALTER COLUMN receiver_id SET NOT NULL;
+ALTER TABLE private_messages DROP COLUMN conversation_id;
+DROP TABLE conversation_participants;
+DROP TABLE conversations;
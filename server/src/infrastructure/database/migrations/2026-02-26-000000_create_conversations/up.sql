@@ -0,0 +1,49 @@
+CREATE TABLE conversations (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100),
+    is_group BOOLEAN NOT NULL DEFAULT FALSE,
+    -- "<lower id>:<higher id>" for one-to-one conversations, so each pair has exactly one
+    direct_key VARCHAR(32) UNIQUE,
+    created_by INT REFERENCES brawlers(id) ON DELETE SET NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE conversation_participants (
+    conversation_id INT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    brawler_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    role VARCHAR(16) NOT NULL DEFAULT 'Member',
+    last_read_message_id INT,
+    joined_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (conversation_id, brawler_id)
+);
+
+CREATE INDEX idx_conversation_participants_brawler ON conversation_participants(brawler_id);
+
+-- Every message now belongs to a conversation; group messages have no single receiver
+ALTER TABLE private_messages ADD COLUMN conversation_id INT REFERENCES conversations(id) ON DELETE CASCADE;
+ALTER TABLE private_messages ALTER COLUMN receiver_id DROP NOT NULL;
+
+-- Existing one-to-one DMs become two-participant conversations. A legacy self-DM (sent before
+-- messaging yourself was refused) keeps its own "N:N" conversation with N as the only participant.
+INSERT INTO conversations (is_group, direct_key, created_at, updated_at)
+SELECT FALSE,
+       LEAST(sender_id, receiver_id) || ':' || GREATEST(sender_id, receiver_id),
+       MIN(created_at),
+       MAX(created_at)
+FROM private_messages
+GROUP BY LEAST(sender_id, receiver_id), GREATEST(sender_id, receiver_id);
+
+INSERT INTO conversation_participants (conversation_id, brawler_id, joined_at)
+SELECT id, split_part(direct_key, ':', 1)::INT, created_at FROM conversations WHERE direct_key IS NOT NULL
+UNION
+SELECT id, split_part(direct_key, ':', 2)::INT, created_at FROM conversations WHERE direct_key IS NOT NULL;
+
+UPDATE private_messages m
+SET conversation_id = c.id
+FROM conversations c
+WHERE c.direct_key = LEAST(m.sender_id, m.receiver_id) || ':' || GREATEST(m.sender_id, m.receiver_id);
+
+ALTER TABLE private_messages ALTER COLUMN conversation_id SET NOT NULL;
+
+CREATE INDEX idx_pm_conversation_id ON private_messages(conversation_id, id DESC);
@@ -0,0 +1,19 @@
+CREATE TABLE attachments (
+    id SERIAL PRIMARY KEY,
+    uploader_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    -- Where the blob lives in the configured storage backend
+    storage_key VARCHAR(255) NOT NULL UNIQUE,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    -- At most one of these is set; both are NULL until the upload is sent with a message
+    mission_comment_id INT REFERENCES mission_comments(id) ON DELETE CASCADE,
+    private_message_id INT REFERENCES private_messages(id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CHECK (mission_comment_id IS NULL OR private_message_id IS NULL)
+);
+
+CREATE INDEX idx_attachments_mission_comment_id ON attachments(mission_comment_id)
+    WHERE mission_comment_id IS NOT NULL;
+CREATE INDEX idx_attachments_private_message_id ON attachments(private_message_id)
+    WHERE private_message_id IS NOT NULL;
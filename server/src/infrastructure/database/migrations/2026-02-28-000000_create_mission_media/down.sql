@@ -0,0 +1,4 @@
+DROP TABLE mission_gallery_items;
+
+ALTER TABLE missions DROP COLUMN cover_public_id;
+ALTER TABLE missions DROP COLUMN cover_url;
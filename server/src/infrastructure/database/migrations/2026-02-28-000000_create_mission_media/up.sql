@@ -0,0 +1,14 @@
+-- Mirrors brawlers.avatar_url/avatar_public_id; the public id is the storage folder of the variants
+ALTER TABLE missions ADD COLUMN cover_url VARCHAR(512);
+ALTER TABLE missions ADD COLUMN cover_public_id VARCHAR(255);
+
+CREATE TABLE mission_gallery_items (
+    id SERIAL PRIMARY KEY,
+    mission_id INT NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    uploader_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    image_url VARCHAR(512) NOT NULL,
+    image_public_id VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_gallery_items_mission_id ON mission_gallery_items(mission_id, id);
@@ -0,0 +1,9 @@
+-- Only overrides are stored; a missing row means the channel is on for that type
+CREATE TABLE notification_preferences (
+    brawler_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    notification_type VARCHAR(64) NOT NULL,
+    channel VARCHAR(32) NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (brawler_id, notification_type, channel)
+);
@@ -0,0 +1,4 @@
+CREATE INDEX idx_notifications_brawler_id ON notifications (brawler_id);
+DROP INDEX idx_notifications_read_created_at;
+DROP INDEX idx_notifications_unread;
+DROP INDEX idx_notifications_brawler_id_id;
@@ -0,0 +1,5 @@
+-- Newest-first paging per brawler, the unread counter and the retention purge
+CREATE INDEX idx_notifications_brawler_id_id ON notifications (brawler_id, id DESC);
+CREATE INDEX idx_notifications_unread ON notifications (brawler_id) WHERE is_read = FALSE;
+CREATE INDEX idx_notifications_read_created_at ON notifications (created_at) WHERE is_read = TRUE;
+DROP INDEX idx_notifications_brawler_id;
@@ -0,0 +1,5 @@
+DROP INDEX idx_notifications_group;
+
+ALTER TABLE notifications
+    DROP COLUMN updated_at,
+    DROP COLUMN group_count;
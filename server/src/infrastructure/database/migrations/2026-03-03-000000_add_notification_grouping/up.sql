@@ -0,0 +1,9 @@
+-- A group is an unread row that later notifications with the same (type, related_id) fold into
+ALTER TABLE notifications
+    ADD COLUMN group_count INT NOT NULL DEFAULT 1,
+    ADD COLUMN updated_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+UPDATE notifications SET updated_at = created_at;
+
+CREATE INDEX idx_notifications_group ON notifications (brawler_id, type, related_id)
+    WHERE is_read = FALSE;
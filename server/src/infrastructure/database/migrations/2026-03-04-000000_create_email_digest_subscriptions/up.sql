@@ -0,0 +1,8 @@
+-- Opting in creates the row; opting out deletes it
+CREATE TABLE email_digest_subscriptions (
+    brawler_id INT PRIMARY KEY REFERENCES brawlers(id) ON DELETE CASCADE,
+    frequency VARCHAR(16) NOT NULL,
+    last_sent_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,15 @@
+-- One row per browser; the endpoint is unique, so re-subscribing from another account moves it
+CREATE TABLE push_subscriptions (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    endpoint TEXT NOT NULL UNIQUE,
+    p256dh VARCHAR(128) NOT NULL,
+    auth VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_push_subscriptions_brawler ON push_subscriptions (brawler_id);
+CREATE INDEX idx_push_subscriptions_expires ON push_subscriptions (expires_at)
+    WHERE expires_at IS NOT NULL;
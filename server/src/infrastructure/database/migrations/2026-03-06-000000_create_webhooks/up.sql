@@ -0,0 +1,34 @@
+-- Without all_missions a webhook only hears about missions its owner leads, or the one
+-- mission it is scoped to
+CREATE TABLE webhooks (
+    id SERIAL PRIMARY KEY,
+    owner_id INT NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    mission_id INT REFERENCES missions(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    secret VARCHAR(64) NOT NULL,
+    events TEXT[] NOT NULL,
+    all_missions BOOLEAN NOT NULL DEFAULT FALSE,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhooks_owner ON webhooks (owner_id);
+CREATE INDEX idx_webhooks_mission ON webhooks (mission_id);
+
+-- One row per event and webhook; the scheduled job that sends it retries with backoff
+CREATE TABLE webhook_deliveries (
+    id SERIAL PRIMARY KEY,
+    webhook_id INT NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event VARCHAR(32) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'Pending',
+    attempts INT NOT NULL DEFAULT 0,
+    response_status INT,
+    last_error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    last_attempt_at TIMESTAMP,
+    delivered_at TIMESTAMP
+);
+
+CREATE INDEX idx_webhook_deliveries_webhook ON webhook_deliveries (webhook_id, id DESC);
@@ -0,0 +1,2 @@
+-- The removed message bodies can't be brought back
+SELECT 1;
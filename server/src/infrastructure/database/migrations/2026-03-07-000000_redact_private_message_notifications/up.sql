@@ -0,0 +1,5 @@
+-- Private message notifications no longer carry the message body, which outlived unsent messages
+UPDATE notifications
+SET content = regexp_replace(content, '^(.*?): ".*"$', '\1 sent you a message', 's')
+WHERE type = 'private_message'
+  AND group_count = 1;
@@ -0,0 +1,2 @@
+CREATE INDEX idx_notifications_brawler_id_id ON notifications (brawler_id, id DESC);
+DROP INDEX idx_notifications_brawler_updated_at_id;
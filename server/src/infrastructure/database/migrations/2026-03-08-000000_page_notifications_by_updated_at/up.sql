@@ -0,0 +1,3 @@
+-- Pages follow (updated_at, id) now that groups move up when they take a new notification
+CREATE INDEX idx_notifications_brawler_updated_at_id ON notifications (brawler_id, updated_at DESC, id DESC);
+DROP INDEX idx_notifications_brawler_id_id;
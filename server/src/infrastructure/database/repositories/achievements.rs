@@ -0,0 +1,85 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into, sql_types::Int4,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::brawler_achievements::{AddBrawlerAchievementEntity, BrawlerAchievementEntity},
+        repositories::achievements::AchievementRepository,
+        value_objects::achievements::AchievementProgressModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::brawler_achievements},
+};
+
+pub struct AchievementPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AchievementPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for AchievementPostgres {
+    async fn get_progress(&self, brawler_id: i32) -> Result<AchievementProgressModel> {
+        let sql = r#"
+SELECT
+    (SELECT COUNT(*) FROM missions m
+      WHERE m.chief_id = $1 AND m.status = 'Completed' AND m.deleted_at IS NULL)
+  + (SELECT COUNT(*) FROM crew_memberships cm
+      INNER JOIN missions m ON m.id = cm.mission_id
+      WHERE cm.brawler_id = $1 AND m.status = 'Completed' AND m.deleted_at IS NULL
+        AND cm.attendance_status <> 'NoShow') AS missions_completed,
+    (SELECT COUNT(*) FROM missions m
+      WHERE m.chief_id = $1 AND m.status = 'Completed' AND m.deleted_at IS NULL) AS missions_led,
+    (SELECT COUNT(*) FROM crew_memberships cm WHERE cm.brawler_id = $1) AS missions_joined,
+    (SELECT COUNT(*) FROM friendships f
+      WHERE (f.requester_id = $1 OR f.receiver_id = $1) AND f.status = 'accepted') AS friends,
+    (SELECT COUNT(*) FROM mission_comments mc
+      WHERE mc.brawler_id = $1 AND mc.deleted_at IS NULL) AS comments
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<AchievementProgressModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn award(
+        &self,
+        awards: Vec<AddBrawlerAchievementEntity>,
+    ) -> Result<Vec<BrawlerAchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let awarded = insert_into(brawler_achievements::table)
+            .values(awards)
+            .on_conflict((
+                brawler_achievements::brawler_id,
+                brawler_achievements::achievement_code,
+            ))
+            .do_nothing()
+            .returning(BrawlerAchievementEntity::as_returning())
+            .get_results::<BrawlerAchievementEntity>(&mut conn)?;
+
+        Ok(awarded)
+    }
+
+    async fn find_by_brawler(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let achievements = brawler_achievements::table
+            .filter(brawler_achievements::brawler_id.eq(brawler_id))
+            .order(brawler_achievements::awarded_at.asc())
+            .select(BrawlerAchievementEntity::as_select())
+            .load::<BrawlerAchievementEntity>(&mut conn)?;
+
+        Ok(achievements)
+    }
+}
@@ -0,0 +1,169 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, QueryableByName, RunQueryDsl,
+    SelectableHelper, insert_into,
+    sql_types::{Bool, Int4},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::attachments::{AddAttachmentEntity, AttachmentEntity},
+        repositories::attachments::AttachmentRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::attachments},
+};
+
+#[derive(QueryableByName)]
+struct AccessRow {
+    #[diesel(sql_type = Bool)]
+    allowed: bool,
+}
+
+pub struct AttachmentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AttachmentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for AttachmentPostgres {
+    async fn add(&self, attachment: AddAttachmentEntity) -> Result<AttachmentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let attachment = insert_into(attachments::table)
+            .values(attachment)
+            .returning(AttachmentEntity::as_returning())
+            .get_result::<AttachmentEntity>(&mut conn)?;
+
+        Ok(attachment)
+    }
+
+    async fn find_by_id(&self, attachment_id: i32) -> Result<Option<AttachmentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let attachment = attachments::table
+            .find(attachment_id)
+            .select(AttachmentEntity::as_select())
+            .first::<AttachmentEntity>(&mut conn)
+            .optional()?;
+
+        Ok(attachment)
+    }
+
+    async fn can_access(&self, attachment_id: i32, brawler_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Mission chat files follow the chat (chief and crew); DM files follow the
+        // conversation. Removed comments and unsent messages take their files with them.
+        let sql = r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM attachments a
+                LEFT JOIN mission_comments c ON c.id = a.mission_comment_id
+                LEFT JOIN missions mi ON mi.id = c.mission_id
+                LEFT JOIN private_messages m ON m.id = a.private_message_id
+                WHERE a.id = $1
+                  AND (
+                      a.uploader_id = $2
+                      OR (c.id IS NOT NULL AND c.deleted_at IS NULL AND (
+                          mi.chief_id = $2
+                          OR EXISTS (
+                              SELECT 1 FROM crew_memberships cm
+                              WHERE cm.mission_id = c.mission_id AND cm.brawler_id = $2
+                          )
+                      ))
+                      OR (m.id IS NOT NULL AND m.deleted_at IS NULL AND EXISTS (
+                          SELECT 1 FROM conversation_participants cp
+                          WHERE cp.conversation_id = m.conversation_id AND cp.brawler_id = $2
+                      ))
+                  )
+            ) AS allowed
+        "#;
+
+        let row = diesel::sql_query(sql)
+            .bind::<Int4, _>(attachment_id)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<AccessRow>(&mut conn)?;
+
+        Ok(row.allowed)
+    }
+
+    async fn delete(&self, attachment_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(attachments::table)
+            .filter(attachments::id.eq(attachment_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
+
+/// Links the uploader's still-unsent attachments to a comment. Meant to run in the
+/// transaction that inserts the comment, so a bad id rolls the comment back too.
+pub fn link_to_comment(
+    conn: &mut PgConnection,
+    attachment_ids: Vec<i32>,
+    uploader_id: i32,
+    comment_id: i32,
+) -> Result<()> {
+    let attachment_ids = distinct(attachment_ids);
+    if attachment_ids.is_empty() {
+        return Ok(());
+    }
+
+    let linked = diesel::update(attachments::table)
+        .filter(attachments::id.eq_any(&attachment_ids))
+        .filter(attachments::uploader_id.eq(uploader_id))
+        .filter(attachments::mission_comment_id.is_null())
+        .filter(attachments::private_message_id.is_null())
+        .set(attachments::mission_comment_id.eq(comment_id))
+        .execute(conn)?;
+
+    ensure_all_linked(attachment_ids.len(), linked)
+}
+
+/// Same as `link_to_comment`, for a private or conversation message
+pub fn link_to_message(
+    conn: &mut PgConnection,
+    attachment_ids: Vec<i32>,
+    uploader_id: i32,
+    message_id: i32,
+) -> Result<()> {
+    let attachment_ids = distinct(attachment_ids);
+    if attachment_ids.is_empty() {
+        return Ok(());
+    }
+
+    let linked = diesel::update(attachments::table)
+        .filter(attachments::id.eq_any(&attachment_ids))
+        .filter(attachments::uploader_id.eq(uploader_id))
+        .filter(attachments::mission_comment_id.is_null())
+        .filter(attachments::private_message_id.is_null())
+        .set(attachments::private_message_id.eq(message_id))
+        .execute(conn)?;
+
+    ensure_all_linked(attachment_ids.len(), linked)
+}
+
+fn distinct(mut attachment_ids: Vec<i32>) -> Vec<i32> {
+    attachment_ids.sort_unstable();
+    attachment_ids.dedup();
+    attachment_ids
+}
+
+/// Someone else's upload, or one already sent, is refused rather than quietly left out
+fn ensure_all_linked(requested: usize, linked: usize) -> Result<()> {
+    if linked != requested {
+        return Err(anyhow!(
+            "Some attachments were not found or have already been sent"
+        ));
+    }
+    Ok(())
+}
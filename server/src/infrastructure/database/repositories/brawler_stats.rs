@@ -0,0 +1,115 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    RunQueryDsl,
+    sql_types::{BigInt, Int4},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        repositories::brawler_stats::BrawlerStatsRepository,
+        value_objects::brawler_stats_model::{
+            BrawlerStatsSummaryModel, CategoryCountModel, CrewmateModel, WeeklyActivityModel,
+        },
+    },
+    infrastructure::database::postgresql_connection::PgPoolSquad,
+};
+
+pub struct BrawlerStatsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl BrawlerStatsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl BrawlerStatsRepository for BrawlerStatsPostgres {
+    async fn get_summary(&self, brawler_id: i32) -> Result<BrawlerStatsSummaryModel> {
+        let sql = r#"
+SELECT COUNT(*) FILTER (WHERE role = 'Chief') AS missions_led,
+       COUNT(*) FILTER (WHERE role = 'Crew') AS missions_joined,
+       COUNT(*) FILTER (WHERE status = 'Open') AS open_count,
+       COUNT(*) FILTER (WHERE status = 'InProgress') AS in_progress_count,
+       COUNT(*) FILTER (WHERE status = 'Completed') AS completed_count,
+       COUNT(*) FILTER (WHERE status = 'Failed') AS failed_count
+FROM brawler_mission_activity
+WHERE brawler_id = $1
+        "#;
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<BrawlerStatsSummaryModel>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_top_categories(
+        &self,
+        brawler_id: i32,
+        limit: i64,
+    ) -> Result<Vec<CategoryCountModel>> {
+        let sql = r#"
+SELECT category, COUNT(*) AS count
+FROM brawler_mission_activity
+WHERE brawler_id = $1
+GROUP BY category
+ORDER BY count DESC, category
+LIMIT $2
+        "#;
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .bind::<BigInt, _>(limit)
+            .load::<CategoryCountModel>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_weekly_activity(&self, brawler_id: i32) -> Result<Vec<WeeklyActivityModel>> {
+        let sql = r#"
+SELECT DATE_TRUNC('week', activity_at)::date AS week_start,
+       COUNT(*) AS mission_count
+FROM brawler_mission_activity
+WHERE brawler_id = $1
+GROUP BY week_start
+ORDER BY week_start
+        "#;
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .load::<WeeklyActivityModel>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_top_crewmates(&self, brawler_id: i32, limit: i64) -> Result<Vec<CrewmateModel>> {
+        let sql = r#"
+SELECT b.id AS brawler_id,
+       b.display_name,
+       COALESCE(b.avatar_url, '') AS avatar_url,
+       COUNT(*) AS missions_together
+FROM brawler_mission_activity me
+INNER JOIN brawler_mission_activity mate
+        ON mate.mission_id = me.mission_id AND mate.brawler_id <> me.brawler_id
+INNER JOIN brawlers b ON b.id = mate.brawler_id
+WHERE me.brawler_id = $1
+GROUP BY b.id, b.display_name, b.avatar_url
+ORDER BY missions_together DESC, b.display_name
+LIMIT $2
+        "#;
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .bind::<BigInt, _>(limit)
+            .load::<CrewmateModel>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn refresh(&self) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        diesel::sql_query("REFRESH MATERIALIZED VIEW CONCURRENTLY brawler_mission_activity")
+            .execute(&mut conn)?;
+        Ok(())
+    }
+}
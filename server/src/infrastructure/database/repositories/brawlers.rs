@@ -7,12 +7,9 @@ use crate::{
     domain::{
         entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
         repositories::brawlers::BrawlerRepository,
-        value_objects::{
-            base64_img::Base64Img, mission_model::MissionModel, uploaded_img::UploadedImg,
-        },
+        value_objects::mission_model::MissionModel,
     },
     infrastructure::{
-        cloudinary::{self, UploadImageOptions},
         database::{
             postgresql_connection::PgPoolSquad,
             schema::{brawlers, crew_memberships},
@@ -78,25 +75,23 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(results)
     }
 
-    async fn upload_base64img(
+    async fn update_avatar(
         &self,
         user_id: i32,
-        base64img: Base64Img,
-        opt: UploadImageOptions,
-    ) -> Result<UploadedImg> {
-        let uploaded_img = cloudinary::upload(base64img, opt).await?;
-
+        avatar_url: String,
+        avatar_public_id: String,
+    ) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         diesel::update(brawlers::table)
             .filter(brawlers::id.eq(user_id))
             .set((
-                brawlers::avatar_url.eq(uploaded_img.url.clone()),
-                brawlers::avatar_public_id.eq(uploaded_img.public_id.clone()),
+                brawlers::avatar_url.eq(avatar_url),
+                brawlers::avatar_public_id.eq(avatar_public_id),
             ))
             .execute(&mut conn)?;
 
-        Ok(uploaded_img)
+        Ok(())
     }
 
     async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
@@ -129,7 +124,10 @@ SELECT
     missions.scheduled_at,
     missions.location,
     missions.deleted_at,
-    missions.category
+    missions.category,
+    missions.finished_at,
+    missions.min_reputation,
+    missions.cover_url
 FROM missions
 LEFT JOIN brawlers ON brawlers.id = missions.chief_id
 WHERE missions.deleted_at IS NULL
@@ -0,0 +1,421 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::now,
+    insert_into,
+    sql_types::{BigInt, Int4, Nullable},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::conversations::{ConversationEntity, ConversationParticipantEntity},
+        repositories::conversations::ConversationRepository,
+        value_objects::{
+            conversation_model::{
+                ConversationMessageModel, ConversationParticipantModel, ConversationSummaryModel,
+            },
+            conversation_roles::ConversationRoles,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::attachments,
+        schema::{conversation_participants, conversations, private_messages},
+    },
+};
+
+const MESSAGE_COLUMNS: &str = r#"
+    m.id, m.conversation_id, m.sender_id,
+    s.display_name AS sender_display_name, s.avatar_url AS sender_avatar_url,
+    m.content, m.created_at, m.edited_at, m.deleted_at,
+    COALESCE((
+        SELECT json_agg(json_build_object(
+            'id', a.id, 'file_name', a.file_name, 'content_type', a.content_type,
+            'size_bytes', a.size_bytes, 'url', '/api/attachments/' || a.id
+        ) ORDER BY a.id)
+        FROM attachments a
+        WHERE a.private_message_id = m.id AND m.deleted_at IS NULL
+    ), '[]'::json) AS attachments
+"#;
+
+pub struct ConversationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ConversationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ConversationRepository for ConversationPostgres {
+    async fn create_group(
+        &self,
+        creator_id: i32,
+        name: String,
+        participant_ids: Vec<i32>,
+    ) -> Result<ConversationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let conversation = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let conversation = insert_into(conversations::table)
+                .values((
+                    conversations::name.eq(name),
+                    conversations::is_group.eq(true),
+                    conversations::created_by.eq(creator_id),
+                ))
+                .returning(ConversationEntity::as_returning())
+                .get_result::<ConversationEntity>(conn)?;
+
+            let mut rows = vec![(
+                conversation_participants::conversation_id.eq(conversation.id),
+                conversation_participants::brawler_id.eq(creator_id),
+                conversation_participants::role.eq(ConversationRoles::Owner.to_string()),
+            )];
+            rows.extend(
+                participant_ids
+                    .into_iter()
+                    .filter(|id| *id != creator_id)
+                    .map(|id| {
+                        (
+                            conversation_participants::conversation_id.eq(conversation.id),
+                            conversation_participants::brawler_id.eq(id),
+                            conversation_participants::role
+                                .eq(ConversationRoles::Member.to_string()),
+                        )
+                    }),
+            );
+
+            insert_into(conversation_participants::table)
+                .values(rows)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            Ok(conversation)
+        })?;
+
+        Ok(conversation)
+    }
+
+    async fn find_by_id(&self, conversation_id: i32) -> Result<Option<ConversationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let conversation = conversations::table
+            .find(conversation_id)
+            .select(ConversationEntity::as_select())
+            .first::<ConversationEntity>(&mut conn)
+            .optional()?;
+
+        Ok(conversation)
+    }
+
+    async fn get_participants(
+        &self,
+        conversation_id: i32,
+    ) -> Result<Vec<ConversationParticipantModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT p.brawler_id, b.display_name, b.avatar_url, p.role,
+                   p.last_read_message_id, p.joined_at
+            FROM conversation_participants p
+            INNER JOIN brawlers b ON b.id = p.brawler_id
+            WHERE p.conversation_id = $1
+            ORDER BY p.joined_at ASC, p.brawler_id ASC
+        "#;
+
+        let participants = diesel::sql_query(sql)
+            .bind::<Int4, _>(conversation_id)
+            .load::<ConversationParticipantModel>(&mut conn)?;
+
+        Ok(participants)
+    }
+
+    async fn get_participant_ids(&self, conversation_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = conversation_participants::table
+            .filter(conversation_participants::conversation_id.eq(conversation_id))
+            .order(conversation_participants::joined_at.asc())
+            .select(conversation_participants::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn find_participant(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<Option<ConversationParticipantEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let participant = conversation_participants::table
+            .find((conversation_id, brawler_id))
+            .select(ConversationParticipantEntity::as_select())
+            .first::<ConversationParticipantEntity>(&mut conn)
+            .optional()?;
+
+        Ok(participant)
+    }
+
+    async fn rename(&self, conversation_id: i32, name: String) -> Result<ConversationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let conversation = diesel::update(conversations::table)
+            .filter(conversations::id.eq(conversation_id))
+            .set((
+                conversations::name.eq(name),
+                conversations::updated_at.eq(now),
+            ))
+            .returning(ConversationEntity::as_returning())
+            .get_result::<ConversationEntity>(&mut conn)?;
+
+        Ok(conversation)
+    }
+
+    async fn add_participants(
+        &self,
+        conversation_id: i32,
+        brawler_ids: Vec<i32>,
+    ) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows: Vec<_> = brawler_ids
+            .into_iter()
+            .map(|id| {
+                (
+                    conversation_participants::conversation_id.eq(conversation_id),
+                    conversation_participants::brawler_id.eq(id),
+                    conversation_participants::role.eq(ConversationRoles::Member.to_string()),
+                )
+            })
+            .collect();
+
+        let added = insert_into(conversation_participants::table)
+            .values(rows)
+            .on_conflict_do_nothing()
+            .returning(conversation_participants::brawler_id)
+            .get_results::<i32>(&mut conn)?;
+
+        Ok(added)
+    }
+
+    async fn remove_participant(&self, conversation_id: i32, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(conversation_participants::table)
+            .filter(conversation_participants::conversation_id.eq(conversation_id))
+            .filter(conversation_participants::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn set_role(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        role: ConversationRoles,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(conversation_participants::table)
+            .filter(conversation_participants::conversation_id.eq(conversation_id))
+            .filter(conversation_participants::brawler_id.eq(brawler_id))
+            .set(conversation_participants::role.eq(role.to_string()))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn list_for_brawler(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Groups count unread from the participant's read marker; one-to-one conversations
+        // keep using the per-message is_read flag the DM endpoints maintain
+        let sql = r#"
+            SELECT c.id, c.name, c.is_group, p.role,
+                   ARRAY(
+                       SELECT cp.brawler_id FROM conversation_participants cp
+                       WHERE cp.conversation_id = c.id
+                       ORDER BY cp.joined_at ASC, cp.brawler_id ASC
+                   ) AS participant_ids,
+                   lm.id AS last_message_id,
+                   lm.sender_id AS last_message_sender_id,
+                   lm.content AS last_message_content,
+                   lm.created_at AS last_message_at,
+                   (
+                       SELECT COUNT(*) FROM private_messages u
+                       WHERE u.conversation_id = c.id
+                         AND u.sender_id <> $1
+                         AND u.deleted_at IS NULL
+                         AND CASE WHEN c.is_group
+                                  THEN u.id > COALESCE(p.last_read_message_id, 0)
+                                  ELSE u.receiver_id = $1 AND u.is_read = FALSE AND NOT u.hidden_for_receiver
+                             END
+                   ) AS unread_count,
+                   c.updated_at
+            FROM conversation_participants p
+            INNER JOIN conversations c ON c.id = p.conversation_id
+            LEFT JOIN LATERAL (
+                SELECT m.id, m.sender_id, m.content, m.created_at
+                FROM private_messages m
+                WHERE m.conversation_id = c.id
+                  AND NOT ((m.sender_id = $1 AND m.hidden_for_sender) OR (m.receiver_id = $1 AND m.hidden_for_receiver))
+                ORDER BY m.id DESC
+                LIMIT 1
+            ) lm ON TRUE
+            WHERE p.brawler_id = $1
+            ORDER BY COALESCE(lm.created_at, c.updated_at) DESC, c.id DESC
+        "#;
+
+        let conversations = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .load::<ConversationSummaryModel>(&mut conn)?;
+
+        Ok(conversations)
+    }
+
+    async fn add_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        receiver_id: Option<i32>,
+        content: String,
+        attachment_ids: Vec<i32>,
+    ) -> Result<ConversationMessageModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let message_id = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let message_id = insert_into(private_messages::table)
+                .values((
+                    private_messages::conversation_id.eq(conversation_id),
+                    private_messages::sender_id.eq(sender_id),
+                    private_messages::receiver_id.eq(receiver_id),
+                    private_messages::content.eq(content),
+                ))
+                .returning(private_messages::id)
+                .get_result::<i32>(conn)?;
+
+            attachments::link_to_message(conn, attachment_ids, sender_id, message_id)?;
+
+            diesel::update(conversations::table)
+                .filter(conversations::id.eq(conversation_id))
+                .set(conversations::updated_at.eq(now))
+                .execute(conn)?;
+
+            // Your own message never counts as unread
+            diesel::update(conversation_participants::table)
+                .filter(conversation_participants::conversation_id.eq(conversation_id))
+                .filter(conversation_participants::brawler_id.eq(sender_id))
+                .set(conversation_participants::last_read_message_id.eq(message_id))
+                .execute(conn)?;
+
+            Ok(message_id)
+        })?;
+
+        self.find_message(message_id).await
+    }
+
+    async fn find_message(&self, message_id: i32) -> Result<ConversationMessageModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            r#"
+            SELECT {MESSAGE_COLUMNS}
+            FROM private_messages m
+            LEFT JOIN brawlers s ON s.id = m.sender_id
+            WHERE m.id = $1
+        "#
+        );
+
+        let message = diesel::sql_query(sql)
+            .bind::<Int4, _>(message_id)
+            .get_result::<ConversationMessageModel>(&mut conn)?;
+
+        Ok(message)
+    }
+
+    async fn get_messages(
+        &self,
+        conversation_id: i32,
+        viewer_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<ConversationMessageModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT {MESSAGE_COLUMNS}
+                FROM private_messages m
+                LEFT JOIN brawlers s ON s.id = m.sender_id
+                WHERE m.conversation_id = $1
+                  AND ($3::int4 IS NULL OR m.id < $3)
+                  AND NOT ((m.sender_id = $2 AND m.hidden_for_sender) OR (m.receiver_id = $2 AND m.hidden_for_receiver))
+                ORDER BY m.id DESC
+                LIMIT $4
+            ) page
+            ORDER BY page.id ASC
+        "#
+        );
+
+        let messages = diesel::sql_query(sql)
+            .bind::<Int4, _>(conversation_id)
+            .bind::<Int4, _>(viewer_id)
+            .bind::<Nullable<Int4>, _>(before_id)
+            .bind::<BigInt, _>(limit)
+            .load::<ConversationMessageModel>(&mut conn)?;
+
+        Ok(messages)
+    }
+
+    async fn mark_read(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+        last_read_message_id: Option<i32>,
+    ) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let newest = private_messages::table
+                .filter(private_messages::conversation_id.eq(conversation_id))
+                .select(diesel::dsl::max(private_messages::id))
+                .first::<Option<i32>>(conn)?;
+            let Some(newest) = newest else {
+                return Ok(None);
+            };
+            let target = last_read_message_id.map_or(newest, |id| id.min(newest));
+
+            let current = conversation_participants::table
+                .find((conversation_id, brawler_id))
+                .select(conversation_participants::last_read_message_id)
+                .first::<Option<i32>>(conn)?;
+            // The marker only ever moves forward
+            let marker = current.map_or(target, |current| current.max(target));
+
+            diesel::update(conversation_participants::table)
+                .filter(conversation_participants::conversation_id.eq(conversation_id))
+                .filter(conversation_participants::brawler_id.eq(brawler_id))
+                .set(conversation_participants::last_read_message_id.eq(marker))
+                .execute(conn)?;
+
+            // Keep the per-message flag of one-to-one messages in step with the marker
+            diesel::update(private_messages::table)
+                .filter(private_messages::conversation_id.eq(conversation_id))
+                .filter(private_messages::receiver_id.eq(brawler_id))
+                .filter(private_messages::id.le(marker))
+                .filter(private_messages::is_read.eq(false))
+                .set(private_messages::is_read.eq(true))
+                .execute(conn)?;
+
+            Ok(Some(marker))
+        })
+    }
+}
@@ -1,13 +1,16 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, insert_into};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl, insert_into};
 use std::sync::Arc;
 
 use crate::{
     domain::{
         entities::crew_memberships::CrewMemberShips,
         repositories::crew_operation::CrewOperationRepository,
-        value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+        value_objects::{
+            attendance_statuses::AttendanceStatuses, mission_model::JoinedMissionModel,
+            mission_statuses::MissionStatuses,
+        },
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
@@ -48,44 +51,34 @@ impl CrewOperationRepository for CrewOperationPostgres {
     async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        // 1. Remove the membership
         diesel::delete(crew_memberships::table)
             .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
             .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
             .execute(&mut conn)?;
 
-        // 2. Check if the mission is soft-deleted and has 0 members remaining
-        use diesel::OptionalExtension;
-        let mission_info: Option<(bool, i32)> = missions::table
-            .select((missions::deleted_at.is_not_null(), missions::id))
-            .filter(missions::id.eq(crew_member_ships.mission_id))
-            .first::<(bool, i32)>(&mut conn)
-            .optional()?;
-
-        if let Some((is_deleted, mid)) = mission_info {
-            if is_deleted {
-                let count: i64 = crew_memberships::table
-                    .filter(crew_memberships::mission_id.eq(mid))
-                    .count()
-                    .get_result(&mut conn)?;
-
-                if count == 0 {
-                    // 3. HARD DELETE: Last crew member left a deleted mission. Clean up DB.
-                    diesel::delete(mission_comments::table)
-                        .filter(mission_comments::mission_id.eq(mid))
-                        .execute(&mut conn)?;
-
-                    diesel::delete(missions::table)
-                        .filter(missions::id.eq(mid))
-                        .execute(&mut conn)?;
-                }
-            }
-        }
+        Ok(())
+    }
+
+    async fn purge_removed_mission(&self, mission_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            diesel::delete(mission_comments::table)
+                .filter(mission_comments::mission_id.eq(mission_id))
+                .execute(conn)?;
+
+            diesel::delete(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_not_null())
+                .execute(conn)?;
+
+            Ok(())
+        })?;
 
         Ok(())
     }
 
-    async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    async fn get_my_joined_missions(&self, brawler_id: i32) -> Result<Vec<JoinedMissionModel>> {
         let sql = r#"
 SELECT m.id,
        m.name,
@@ -101,10 +94,19 @@ SELECT m.id,
        m.scheduled_at,
        m.location,
        m.deleted_at,
-       m.category
+       m.category,
+       m.finished_at,
+       m.min_reputation,
+       m.cover_url,
+       (SELECT COUNT(*) FROM mission_comments c
+         WHERE c.mission_id = m.id
+           AND c.brawler_id <> $1
+           AND c.deleted_at IS NULL
+           AND c.id > COALESCE(rm.last_read_comment_id, 0)) AS unread_count
 FROM missions m
 INNER JOIN crew_memberships cm ON cm.mission_id = m.id AND cm.brawler_id = $1
 LEFT JOIN brawlers b ON b.id = m.chief_id
+LEFT JOIN mission_read_markers rm ON rm.mission_id = m.id AND rm.brawler_id = $1
 -- WHERE m.deleted_at IS NULL -- Allow seeing deleted missions so user can visit and leave
 ORDER BY cm.joined_at DESC
         "#;
@@ -112,8 +114,51 @@ ORDER BY cm.joined_at DESC
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let rows = diesel::sql_query(sql)
             .bind::<diesel::sql_types::Int4, _>(brawler_id)
-            .load::<MissionModel>(&mut conn)?;
+            .load::<JoinedMissionModel>(&mut conn)?;
 
         Ok(rows)
     }
+
+    async fn set_attendance(
+        &self,
+        crew_member_ships: CrewMemberShips,
+        status: AttendanceStatuses,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Only a real check-in stamps the time, overrides back to Pending/NoShow clear it
+        let checked_in_at = match status {
+            AttendanceStatuses::Present => Some(chrono::Utc::now().naive_utc()),
+            _ => None,
+        };
+
+        let updated = diesel::update(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+            .set((
+                crew_memberships::attendance_status.eq(status.to_string()),
+                crew_memberships::checked_in_at.eq(checked_in_at),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(anyhow::anyhow!(
+                "Brawler is not a crew member of this mission"
+            ));
+        }
+        Ok(())
+    }
+
+    async fn mark_pending_as_no_show(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let marked = diesel::update(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .filter(crew_memberships::attendance_status.eq(AttendanceStatuses::Pending.to_string()))
+            .set(crew_memberships::attendance_status.eq(AttendanceStatuses::NoShow.to_string()))
+            .returning(crew_memberships::brawler_id)
+            .get_results::<i32>(&mut conn)?;
+
+        Ok(marked)
+    }
 }
@@ -0,0 +1,169 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper, delete,
+    dsl::now, insert_into, sql_types::Timestamp, update, upsert::excluded,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::email_digest_subscriptions::{
+            EmailDigestSubscriptionEntity, UpsertEmailDigestSubscriptionEntity,
+        },
+        repositories::email_digests::EmailDigestRepository,
+        value_objects::{
+            email_digest_model::{DigestDmModel, DigestMissionModel, DigestRecipientModel},
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, schema::email_digest_subscriptions,
+    },
+};
+
+pub struct EmailDigestPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl EmailDigestPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl EmailDigestRepository for EmailDigestPostgres {
+    async fn get(&self, brawler_id: i32) -> Result<Option<EmailDigestSubscriptionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let subscription = email_digest_subscriptions::table
+            .find(brawler_id)
+            .select(EmailDigestSubscriptionEntity::as_select())
+            .first::<EmailDigestSubscriptionEntity>(&mut conn)
+            .optional()?;
+
+        Ok(subscription)
+    }
+
+    async fn upsert(
+        &self,
+        subscription: UpsertEmailDigestSubscriptionEntity,
+    ) -> Result<EmailDigestSubscriptionEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let subscription = insert_into(email_digest_subscriptions::table)
+            .values(subscription)
+            .on_conflict(email_digest_subscriptions::brawler_id)
+            .do_update()
+            .set((
+                email_digest_subscriptions::frequency
+                    .eq(excluded(email_digest_subscriptions::frequency)),
+                email_digest_subscriptions::updated_at.eq(now),
+            ))
+            .returning(EmailDigestSubscriptionEntity::as_returning())
+            .get_result::<EmailDigestSubscriptionEntity>(&mut conn)?;
+
+        Ok(subscription)
+    }
+
+    async fn remove(&self, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        delete(email_digest_subscriptions::table.find(brawler_id)).execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn find_due(&self, at: NaiveDateTime, limit: i64) -> Result<Vec<DigestRecipientModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Due once per calendar day or ISO week, so a late run doesn't shift the next one
+        let sql = r#"
+            SELECT s.brawler_id, b.display_name, b.contact_email, s.frequency
+            FROM email_digest_subscriptions s
+            JOIN brawlers b ON b.id = s.brawler_id
+            WHERE b.contact_email IS NOT NULL
+              AND b.contact_email <> ''
+              AND (
+                  s.last_sent_at IS NULL
+                  OR (s.frequency = 'daily'
+                      AND date_trunc('day', s.last_sent_at) < date_trunc('day', $1))
+                  OR (s.frequency = 'weekly'
+                      AND date_trunc('week', s.last_sent_at) < date_trunc('week', $1))
+              )
+            ORDER BY s.last_sent_at ASC NULLS FIRST
+            LIMIT $2
+        "#;
+
+        let recipients = diesel::sql_query(sql)
+            .bind::<Timestamp, _>(at)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<DigestRecipientModel>(&mut conn)?;
+
+        Ok(recipients)
+    }
+
+    async fn get_upcoming_missions(
+        &self,
+        brawler_id: i32,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Result<Vec<DigestMissionModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT m.id, m.name, m.scheduled_at, m.location
+            FROM missions m
+            JOIN crew_memberships cm ON cm.mission_id = m.id
+            WHERE cm.brawler_id = $1
+              AND m.status = $2
+              AND m.deleted_at IS NULL
+              AND m.scheduled_at >= $3
+              AND m.scheduled_at < $4
+            ORDER BY m.scheduled_at ASC
+        "#;
+
+        let missions = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .bind::<diesel::sql_types::Varchar, _>(MissionStatuses::Open.to_string())
+            .bind::<Timestamp, _>(from)
+            .bind::<Timestamp, _>(until)
+            .load::<DigestMissionModel>(&mut conn)?;
+
+        Ok(missions)
+    }
+
+    async fn get_unread_dms(&self, brawler_id: i32) -> Result<Vec<DigestDmModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT pm.sender_id, b.display_name AS sender_display_name, COUNT(*) AS unread_count
+            FROM private_messages pm
+            JOIN brawlers b ON b.id = pm.sender_id
+            WHERE pm.receiver_id = $1
+              AND pm.is_read = FALSE
+              AND pm.deleted_at IS NULL
+              AND pm.hidden_for_receiver = FALSE
+            GROUP BY pm.sender_id, b.display_name
+            ORDER BY unread_count DESC, b.display_name ASC
+        "#;
+
+        let dms = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .load::<DigestDmModel>(&mut conn)?;
+
+        Ok(dms)
+    }
+
+    async fn mark_sent(&self, brawler_id: i32, sent_at: NaiveDateTime) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(email_digest_subscriptions::table.find(brawler_id))
+            .set(email_digest_subscriptions::last_sent_at.eq(sent_at))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
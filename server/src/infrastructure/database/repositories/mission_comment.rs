@@ -1,15 +1,37 @@
 use crate::{
     domain::{
+        entities::{
+            mission_comment_revisions::MissionCommentRevisionEntity,
+            mission_read_markers::MissionReadMarkerEntity,
+        },
         repositories::mission_comment::MissionCommentRepository,
-        value_objects::mission_comment_model::MissionCommentModel,
+        value_objects::mission_comment_model::{CommentReactionSummaryModel, MissionCommentModel},
     },
-    infrastructure::database::postgresql_connection::PgPoolSquad,
+    infrastructure::database::{postgresql_connection::PgPoolSquad, repositories::attachments},
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::RunQueryDsl;
+use diesel::{Connection, RunQueryDsl};
 use std::sync::Arc;
 
+/// Deleted comments keep their row (so replies still have a parent) but hide the text
+const COMMENT_COLUMNS: &str = r#"
+    c.id, c.mission_id, c.brawler_id,
+    b.display_name as brawler_display_name,
+    COALESCE(b.avatar_url, '') as brawler_avatar_url,
+    CASE WHEN c.deleted_at IS NULL THEN c.content ELSE '' END as content,
+    c.created_at, c.parent_id, c.edited_at, c.deleted_at, c.deleted_by,
+    c.pinned_at, c.pinned_by,
+    COALESCE((
+        SELECT json_agg(json_build_object(
+            'id', a.id, 'file_name', a.file_name, 'content_type', a.content_type,
+            'size_bytes', a.size_bytes, 'url', '/api/attachments/' || a.id
+        ) ORDER BY a.id)
+        FROM attachments a
+        WHERE a.mission_comment_id = c.id AND c.deleted_at IS NULL
+    ), '[]'::json) as attachments
+"#;
+
 pub struct MissionCommentPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -27,48 +49,208 @@ impl MissionCommentRepository for MissionCommentPostgres {
         mission_id: i32,
         brawler_id: i32,
         content: &str,
+        parent_id: Option<i32>,
+        attachment_ids: Vec<i32>,
     ) -> Result<MissionCommentModel> {
         use crate::infrastructure::database::schema::mission_comments;
         use diesel::ExpressionMethods;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let inserted_id: i32 = diesel::insert_into(mission_comments::table)
-            .values((
-                mission_comments::mission_id.eq(mission_id),
-                mission_comments::brawler_id.eq(brawler_id),
-                mission_comments::content.eq(content),
-            ))
-            .returning(mission_comments::id)
-            .get_result(&mut conn)?;
+        let inserted_id = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let inserted_id: i32 = diesel::insert_into(mission_comments::table)
+                .values((
+                    mission_comments::mission_id.eq(mission_id),
+                    mission_comments::brawler_id.eq(brawler_id),
+                    mission_comments::content.eq(content),
+                    mission_comments::parent_id.eq(parent_id),
+                ))
+                .returning(mission_comments::id)
+                .get_result(conn)?;
 
-        let sql = r#"
-            SELECT c.id, c.mission_id, c.brawler_id, 
-                   b.display_name as brawler_display_name,
-                   COALESCE(b.avatar_url, '') as brawler_avatar_url,
-                   c.content, c.created_at
+            attachments::link_to_comment(conn, attachment_ids, brawler_id, inserted_id)?;
+
+            Ok(inserted_id)
+        })?;
+
+        self.find_by_id(inserted_id).await
+    }
+
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentModel> {
+        let sql = format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
             FROM mission_comments c
             JOIN brawlers b ON b.id = c.brawler_id
             WHERE c.id = $1
-        "#;
+        "#
+        );
 
+        let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(inserted_id)
+            .bind::<diesel::sql_types::Int4, _>(comment_id)
             .get_result::<MissionCommentModel>(&mut conn)?;
 
         Ok(result)
     }
 
-    async fn get_by_mission_id(&self, mission_id: i32) -> Result<Vec<MissionCommentModel>> {
-        let sql = r#"
-            SELECT c.id, c.mission_id, c.brawler_id, 
-                   b.display_name as brawler_display_name,
-                   COALESCE(b.avatar_url, '') as brawler_avatar_url,
-                   c.content, c.created_at
+    async fn get_transcript_page(
+        &self,
+        mission_id: i32,
+        after_id: i32,
+        limit: i64,
+    ) -> Result<Vec<MissionCommentModel>> {
+        let sql = format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
             FROM mission_comments c
             JOIN brawlers b ON b.id = c.brawler_id
             WHERE c.mission_id = $1
-            ORDER BY c.created_at ASC
-        "#;
+              AND c.deleted_at IS NULL
+              AND c.id > $2
+            ORDER BY c.id ASC
+            LIMIT $3
+        "#
+        );
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let comments = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(mission_id)
+            .bind::<diesel::sql_types::Int4, _>(after_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<MissionCommentModel>(&mut conn)?;
+        Ok(comments)
+    }
+
+    async fn get_by_mission_id(
+        &self,
+        mission_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<MissionCommentModel>> {
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT {COMMENT_COLUMNS}
+                FROM mission_comments c
+                JOIN brawlers b ON b.id = c.brawler_id
+                WHERE c.mission_id = $1
+                  AND ($2::int4 IS NULL OR c.id < $2)
+                ORDER BY c.id DESC
+                LIMIT $3
+            ) page
+            ORDER BY page.id ASC
+        "#
+        );
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let comments = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(mission_id)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Int4>, _>(before_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<MissionCommentModel>(&mut conn)?;
+        Ok(comments)
+    }
+
+    async fn edit(&self, comment_id: i32, content: &str) -> Result<MissionCommentModel> {
+        use crate::infrastructure::database::schema::{
+            mission_comment_revisions, mission_comments,
+        };
+        use diesel::{ExpressionMethods, QueryDsl};
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let previous: String = mission_comments::table
+                .filter(mission_comments::id.eq(comment_id))
+                .select(mission_comments::content)
+                .for_update()
+                .first(conn)?;
+
+            diesel::insert_into(mission_comment_revisions::table)
+                .values((
+                    mission_comment_revisions::comment_id.eq(comment_id),
+                    mission_comment_revisions::content.eq(previous),
+                ))
+                .execute(conn)?;
+
+            diesel::update(mission_comments::table)
+                .filter(mission_comments::id.eq(comment_id))
+                .set((
+                    mission_comments::content.eq(content),
+                    mission_comments::edited_at.eq(diesel::dsl::now),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        self.find_by_id(comment_id).await
+    }
+
+    async fn soft_delete(&self, comment_id: i32, deleted_by: i32) -> Result<()> {
+        use crate::infrastructure::database::schema::mission_comments;
+        use diesel::ExpressionMethods;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        diesel::update(mission_comments::table)
+            .filter(mission_comments::id.eq(comment_id))
+            .filter(mission_comments::deleted_at.is_null())
+            .set((
+                mission_comments::deleted_at.eq(diesel::dsl::now),
+                mission_comments::deleted_by.eq(deleted_by),
+                mission_comments::pinned_at.eq(None::<chrono::NaiveDateTime>),
+                mission_comments::pinned_by.eq(None::<i32>),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_revisions(&self, comment_id: i32) -> Result<Vec<MissionCommentRevisionEntity>> {
+        use crate::infrastructure::database::schema::mission_comment_revisions;
+        use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let revisions = mission_comment_revisions::table
+            .filter(mission_comment_revisions::comment_id.eq(comment_id))
+            .order(mission_comment_revisions::created_at.asc())
+            .select(MissionCommentRevisionEntity::as_select())
+            .load::<MissionCommentRevisionEntity>(&mut conn)?;
+        Ok(revisions)
+    }
+
+    async fn set_pinned(
+        &self,
+        comment_id: i32,
+        pinned_by: Option<i32>,
+    ) -> Result<MissionCommentModel> {
+        use crate::infrastructure::database::schema::mission_comments;
+        use diesel::ExpressionMethods;
+
+        let pinned_at = pinned_by.map(|_| chrono::Utc::now().naive_utc());
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        diesel::update(mission_comments::table)
+            .filter(mission_comments::id.eq(comment_id))
+            .set((
+                mission_comments::pinned_at.eq(pinned_at),
+                mission_comments::pinned_by.eq(pinned_by),
+            ))
+            .execute(&mut conn)?;
+
+        self.find_by_id(comment_id).await
+    }
+
+    async fn get_pinned(&self, mission_id: i32) -> Result<Vec<MissionCommentModel>> {
+        let sql = format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
+            FROM mission_comments c
+            JOIN brawlers b ON b.id = c.brawler_id
+            WHERE c.mission_id = $1
+              AND c.pinned_at IS NOT NULL
+              AND c.deleted_at IS NULL
+            ORDER BY c.pinned_at DESC
+        "#
+        );
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let comments = diesel::sql_query(sql)
@@ -87,4 +269,98 @@ impl MissionCommentRepository for MissionCommentPostgres {
             .execute(&mut conn)?;
         Ok(())
     }
+
+    async fn toggle_reaction(&self, comment_id: i32, brawler_id: i32, emoji: &str) -> Result<bool> {
+        use crate::infrastructure::database::schema::mission_comment_reactions;
+        use diesel::ExpressionMethods;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let removed = diesel::delete(mission_comment_reactions::table)
+            .filter(mission_comment_reactions::comment_id.eq(comment_id))
+            .filter(mission_comment_reactions::brawler_id.eq(brawler_id))
+            .filter(mission_comment_reactions::emoji.eq(emoji))
+            .execute(&mut conn)?;
+        if removed > 0 {
+            return Ok(false);
+        }
+
+        diesel::insert_into(mission_comment_reactions::table)
+            .values((
+                mission_comment_reactions::comment_id.eq(comment_id),
+                mission_comment_reactions::brawler_id.eq(brawler_id),
+                mission_comment_reactions::emoji.eq(emoji),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+        Ok(true)
+    }
+
+    async fn upsert_read_marker(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        last_read_comment_id: i32,
+    ) -> Result<MissionReadMarkerEntity> {
+        use crate::infrastructure::database::schema::mission_read_markers;
+        use diesel::{ExpressionMethods, SelectableHelper};
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let marker = diesel::insert_into(mission_read_markers::table)
+            .values((
+                mission_read_markers::mission_id.eq(mission_id),
+                mission_read_markers::brawler_id.eq(brawler_id),
+                mission_read_markers::last_read_comment_id.eq(last_read_comment_id),
+            ))
+            .on_conflict((
+                mission_read_markers::mission_id,
+                mission_read_markers::brawler_id,
+            ))
+            .do_update()
+            .set((
+                mission_read_markers::last_read_comment_id.eq(diesel::dsl::sql::<
+                    diesel::sql_types::Int4,
+                >(
+                    "GREATEST(mission_read_markers.last_read_comment_id, EXCLUDED.last_read_comment_id)",
+                )),
+                mission_read_markers::updated_at.eq(diesel::dsl::now),
+            ))
+            .returning(MissionReadMarkerEntity::as_returning())
+            .get_result::<MissionReadMarkerEntity>(&mut conn)?;
+        Ok(marker)
+    }
+
+    async fn get_read_markers(&self, mission_id: i32) -> Result<Vec<MissionReadMarkerEntity>> {
+        use crate::infrastructure::database::schema::mission_read_markers;
+        use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let markers = mission_read_markers::table
+            .filter(mission_read_markers::mission_id.eq(mission_id))
+            .select(MissionReadMarkerEntity::as_select())
+            .load::<MissionReadMarkerEntity>(&mut conn)?;
+        Ok(markers)
+    }
+
+    async fn get_reactions(
+        &self,
+        comment_ids: Vec<i32>,
+        viewer_id: i32,
+    ) -> Result<Vec<CommentReactionSummaryModel>> {
+        let sql = r#"
+            SELECT comment_id, emoji, COUNT(*) AS count,
+                   BOOL_OR(brawler_id = $2) AS reacted_by_me
+            FROM mission_comment_reactions
+            WHERE comment_id = ANY($1)
+            GROUP BY comment_id, emoji
+            ORDER BY comment_id, MIN(created_at)
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let reactions = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Array<diesel::sql_types::Int4>, _>(comment_ids)
+            .bind::<diesel::sql_types::Int4, _>(viewer_id)
+            .load::<CommentReactionSummaryModel>(&mut conn)?;
+        Ok(reactions)
+    }
 }
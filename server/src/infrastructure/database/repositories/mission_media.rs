@@ -0,0 +1,131 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    insert_into,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_gallery_items::{AddMissionGalleryItemEntity, MissionGalleryItemEntity},
+        repositories::mission_media::MissionMediaRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_gallery_items, missions},
+    },
+};
+
+pub struct MissionMediaPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionMediaPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionMediaRepository for MissionMediaPostgres {
+    async fn update_cover(
+        &self,
+        mission_id: i32,
+        cover_url: String,
+        cover_public_id: String,
+    ) -> Result<Option<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let previous = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let previous = missions::table
+                .find(mission_id)
+                .select(missions::cover_public_id)
+                .for_update()
+                .first::<Option<String>>(conn)?;
+
+            diesel::update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    missions::cover_url.eq(cover_url),
+                    missions::cover_public_id.eq(cover_public_id),
+                ))
+                .execute(conn)?;
+
+            Ok(previous)
+        })?;
+
+        Ok(previous)
+    }
+
+    async fn add_gallery_item(
+        &self,
+        item: AddMissionGalleryItemEntity,
+    ) -> Result<MissionGalleryItemEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let item = insert_into(mission_gallery_items::table)
+            .values(item)
+            .returning(MissionGalleryItemEntity::as_returning())
+            .get_result::<MissionGalleryItemEntity>(&mut conn)?;
+
+        Ok(item)
+    }
+
+    async fn get_gallery(&self, mission_id: i32) -> Result<Vec<MissionGalleryItemEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let items = mission_gallery_items::table
+            .filter(mission_gallery_items::mission_id.eq(mission_id))
+            .order(mission_gallery_items::id.asc())
+            .select(MissionGalleryItemEntity::as_select())
+            .load::<MissionGalleryItemEntity>(&mut conn)?;
+
+        Ok(items)
+    }
+
+    async fn find_gallery_item(
+        &self,
+        mission_id: i32,
+        item_id: i32,
+    ) -> Result<Option<MissionGalleryItemEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let item = mission_gallery_items::table
+            .filter(mission_gallery_items::id.eq(item_id))
+            .filter(mission_gallery_items::mission_id.eq(mission_id))
+            .select(MissionGalleryItemEntity::as_select())
+            .first::<MissionGalleryItemEntity>(&mut conn)
+            .optional()?;
+
+        Ok(item)
+    }
+
+    async fn remove_gallery_item(&self, item_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(mission_gallery_items::table)
+            .filter(mission_gallery_items::id.eq(item_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn get_public_ids(&self, mission_id: i32) -> Result<(Option<String>, Vec<String>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let cover_public_id = missions::table
+            .find(mission_id)
+            .select(missions::cover_public_id)
+            .first::<Option<String>>(&mut conn)
+            .optional()?
+            .flatten();
+
+        let gallery_public_ids = mission_gallery_items::table
+            .filter(mission_gallery_items::mission_id.eq(mission_id))
+            .select(mission_gallery_items::image_public_id)
+            .load::<String>(&mut conn)?;
+
+        Ok((cover_public_id, gallery_public_ids))
+    }
+}
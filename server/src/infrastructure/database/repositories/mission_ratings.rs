@@ -0,0 +1,96 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, QueryDsl, QueryableByName, RunQueryDsl, SelectableHelper, insert_into,
+    sql_types::{BigInt, Double, Int4, Nullable},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity},
+        repositories::mission_ratings::MissionRatingRepository,
+        value_objects::mission_rating_model::{RatingTagCountModel, ReputationModel},
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_ratings},
+};
+
+#[derive(QueryableByName)]
+struct RatingAggregate {
+    #[diesel(sql_type = BigInt)]
+    rating_count: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    average_score: Option<f64>,
+}
+
+pub struct MissionRatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionRatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionRatingRepository for MissionRatingPostgres {
+    async fn add(&self, rating: AddMissionRatingEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let id = insert_into(mission_ratings::table)
+            .values(rating)
+            .returning(mission_ratings::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(id)
+    }
+
+    async fn find_by_rater(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+    ) -> Result<Vec<MissionRatingEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ratings = mission_ratings::table
+            .filter(mission_ratings::mission_id.eq(mission_id))
+            .filter(mission_ratings::rater_id.eq(rater_id))
+            .select(MissionRatingEntity::as_select())
+            .load::<MissionRatingEntity>(&mut conn)?;
+
+        Ok(ratings)
+    }
+
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let aggregate = diesel::sql_query(
+            r#"
+            SELECT COUNT(*) AS rating_count, AVG(score)::float8 AS average_score
+            FROM mission_ratings
+            WHERE ratee_id = $1
+        "#,
+        )
+        .bind::<Int4, _>(brawler_id)
+        .get_result::<RatingAggregate>(&mut conn)?;
+
+        let sql = r#"
+            SELECT tag, COUNT(*) AS count
+            FROM mission_ratings, UNNEST(tags) AS tag
+            WHERE ratee_id = $1
+            GROUP BY tag
+            ORDER BY count DESC, tag
+        "#;
+
+        let tags = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .load::<RatingTagCountModel>(&mut conn)?;
+
+        Ok(ReputationModel::new(
+            aggregate.rating_count,
+            aggregate.average_score,
+            tags,
+        ))
+    }
+}
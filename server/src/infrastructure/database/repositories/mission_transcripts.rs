@@ -0,0 +1,60 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgArrayExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper, insert_into,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_transcripts::{AddMissionTranscriptEntity, MissionTranscriptEntity},
+        repositories::mission_transcripts::MissionTranscriptRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_transcripts},
+};
+
+pub struct MissionTranscriptPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionTranscriptPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionTranscriptRepository for MissionTranscriptPostgres {
+    async fn add(&self, transcript: AddMissionTranscriptEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let id = insert_into(mission_transcripts::table)
+            .values(transcript)
+            .returning(mission_transcripts::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(id)
+    }
+
+    async fn find_by_mission(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<MissionTranscriptEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let transcripts = mission_transcripts::table
+            .filter(mission_transcripts::mission_id.eq(mission_id))
+            .filter(
+                mission_transcripts::chief_id
+                    .eq(brawler_id)
+                    .or(mission_transcripts::participant_ids.contains(vec![brawler_id])),
+            )
+            .order(mission_transcripts::created_at.desc())
+            .select(MissionTranscriptEntity::as_select())
+            .load::<MissionTranscriptEntity>(&mut conn)?;
+
+        Ok(transcripts)
+    }
+}
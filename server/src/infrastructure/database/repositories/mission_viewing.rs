@@ -42,13 +42,17 @@ SELECT m.id,
        m.scheduled_at,
        m.location,
        m.deleted_at,
-       m.category
+       m.category,
+       m.finished_at,
+       m.min_reputation,
+       m.cover_url
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
 WHERE m.id = $1
 GROUP BY m.id, b.display_name, b.avatar_url, m.name, m.description, m.status,
-         m.chief_id, m.max_crew, m.created_at, m.updated_at, m.scheduled_at, m.location, m.deleted_at, m.category
+         m.chief_id, m.max_crew, m.created_at, m.updated_at, m.scheduled_at, m.location, m.deleted_at, m.category,
+         m.finished_at, m.min_reputation, m.cover_url
 LIMIT 1
         "#;
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -77,7 +81,10 @@ SELECT m.id,
        m.scheduled_at,
        m.location,
        m.deleted_at,
-       m.category
+       m.category,
+       m.finished_at,
+       m.min_reputation,
+       m.cover_url
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
@@ -91,7 +98,8 @@ WHERE m.deleted_at IS NULL
   )))
   AND ($4::varchar IS NULL OR m.category = $4)
 GROUP BY m.id, b.display_name, b.avatar_url, m.name, m.description, m.status,
-         m.chief_id, m.max_crew, m.created_at, m.updated_at, m.scheduled_at, m.location, m.deleted_at, m.category
+         m.chief_id, m.max_crew, m.created_at, m.updated_at, m.scheduled_at, m.location, m.deleted_at, m.category,
+         m.finished_at, m.min_reputation, m.cover_url
 HAVING ($5::bool IS NULL OR ($5 = true AND COUNT(cm.brawler_id) < m.max_crew) OR ($5 = false AND COUNT(cm.brawler_id) >= m.max_crew))
 ORDER BY m.created_at DESC
         "#;
@@ -131,21 +139,25 @@ ORDER BY m.created_at DESC
         let sql = r#"SELECT b.id, b.display_name,
         COALESCE(b.avatar_url, '') AS avatar_url,
         COALESCE(s.success_count, 0::bigint) AS mission_success_count,
-        COALESCE(j.joined_count, 0::bigint) AS mission_join_count
+        COALESCE(s.joined_count, 0::bigint) AS mission_join_count,
+        COALESCE(s.no_show_count, 0::bigint) AS mission_no_show_count,
+        CASE WHEN COALESCE(s.present_count + s.no_show_count, 0) = 0 THEN NULL
+             ELSE s.present_count::float8 / (s.present_count + s.no_show_count)
+        END AS reliability_rate,
+        cm.attendance_status,
+        cm.checked_in_at
 FROM crew_memberships cm
 INNER JOIN brawlers b ON b.id = cm.brawler_id
 LEFT JOIN (
-    SELECT cm2.brawler_id, COUNT(*) AS success_count
+    SELECT cm2.brawler_id,
+           COUNT(*) AS joined_count,
+           COUNT(*) FILTER (WHERE m2.status = 'Completed' AND cm2.attendance_status <> 'NoShow') AS success_count,
+           COUNT(*) FILTER (WHERE cm2.attendance_status = 'Present') AS present_count,
+           COUNT(*) FILTER (WHERE cm2.attendance_status = 'NoShow') AS no_show_count
     FROM crew_memberships cm2
     INNER JOIN missions m2 ON m2.id = cm2.mission_id
-    WHERE m2.status = 'Completed'
     GROUP BY cm2.brawler_id
 ) s ON s.brawler_id = b.id
-LEFT JOIN (
-    SELECT cm3.brawler_id, COUNT(*) AS joined_count
-    FROM crew_memberships cm3
-    GROUP BY cm3.brawler_id
-) j ON j.brawler_id = b.id
 WHERE cm.mission_id = $1
 "#;
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, dsl::now, insert_into,
+    upsert::excluded,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::notification_preferences::{
+            NotificationPreferenceEntity, UpsertNotificationPreferenceEntity,
+        },
+        repositories::notification_preferences::NotificationPreferenceRepository,
+        value_objects::{
+            notification_channels::NotificationChannels, notification_types::NotificationTypes,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, schema::notification_preferences,
+    },
+};
+
+pub struct NotificationPreferencePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl NotificationPreferencePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for NotificationPreferencePostgres {
+    async fn get_by_brawler(&self, brawler_id: i32) -> Result<Vec<NotificationPreferenceEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let preferences = notification_preferences::table
+            .filter(notification_preferences::brawler_id.eq(brawler_id))
+            .select(NotificationPreferenceEntity::as_select())
+            .load::<NotificationPreferenceEntity>(&mut conn)?;
+
+        Ok(preferences)
+    }
+
+    async fn upsert(&self, preferences: Vec<UpsertNotificationPreferenceEntity>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(notification_preferences::table)
+            .values(preferences)
+            .on_conflict((
+                notification_preferences::brawler_id,
+                notification_preferences::notification_type,
+                notification_preferences::channel,
+            ))
+            .do_update()
+            .set((
+                notification_preferences::enabled.eq(excluded(notification_preferences::enabled)),
+                notification_preferences::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn find_opted_out(
+        &self,
+        brawler_ids: Vec<i32>,
+        notification_type: NotificationTypes,
+        channel: NotificationChannels,
+    ) -> Result<Vec<i32>> {
+        if brawler_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = notification_preferences::table
+            .filter(notification_preferences::brawler_id.eq_any(brawler_ids))
+            .filter(notification_preferences::notification_type.eq(notification_type.to_string()))
+            .filter(notification_preferences::channel.eq(channel.to_string()))
+            .filter(notification_preferences::enabled.eq(false))
+            .select(notification_preferences::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+}
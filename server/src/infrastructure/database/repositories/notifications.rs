@@ -1,13 +1,21 @@
 use crate::{
     domain::{
-        entities::notifications::{AddNotificationEntity, NotificationEntity},
+        entities::notifications::{
+            AddGroupedNotificationEntity, AddNotificationEntity, NotificationEntity,
+        },
         repositories::notifications::NotificationRepository,
+        value_objects::notification_model::{NotificationCursor, NotificationFilter},
     },
     infrastructure::database::postgresql_connection::PgPoolSquad,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
+use chrono::NaiveDateTime;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    dsl::count_star,
+    sql_types::{Array, Int4, Text, Timestamp, Varchar},
+};
 use std::sync::Arc;
 
 pub struct NotificationPostgres {
@@ -38,19 +46,136 @@ impl NotificationRepository for NotificationPostgres {
         Ok(result)
     }
 
-    async fn get_by_user(&self, user_id: i32) -> Result<Vec<NotificationEntity>> {
+    async fn add_many(&self, notifications: Vec<AddNotificationEntity>) -> Result<()> {
         use crate::infrastructure::database::schema::notifications;
 
+        if notifications.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        diesel::insert_into(notifications::table)
+            .values(notifications)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn add_grouped(
+        &self,
+        notification: AddGroupedNotificationEntity,
+        since: NaiveDateTime,
+    ) -> Result<()> {
+        if notification.brawler_ids.is_empty() {
+            return Ok(());
+        }
+
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let results = notifications::table
+
+        // Bump every recipient's open group, then insert for whoever had none
+        let sql = r#"
+            WITH grouped AS (
+                UPDATE notifications
+                SET group_count = group_count + 1,
+                    content = $5 || (group_count + 1)::TEXT || $6,
+                    updated_at = NOW()
+                WHERE brawler_id = ANY($1)
+                  AND type = $2
+                  AND related_id = $4
+                  AND is_read = FALSE
+                  AND updated_at > $7
+                RETURNING brawler_id
+            )
+            INSERT INTO notifications (brawler_id, type, content, related_id)
+            SELECT recipient_id, $2, $3, $4
+            FROM UNNEST($1) AS recipient_id
+            WHERE recipient_id NOT IN (SELECT brawler_id FROM grouped)
+        "#;
+
+        diesel::sql_query(sql)
+            .bind::<Array<Int4>, _>(notification.brawler_ids)
+            .bind::<Varchar, _>(notification.type_)
+            .bind::<Text, _>(notification.content)
+            .bind::<Int4, _>(notification.related_id)
+            .bind::<Text, _>(notification.count_prefix)
+            .bind::<Text, _>(notification.count_suffix)
+            .bind::<Timestamp, _>(since)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn get_page(
+        &self,
+        user_id: i32,
+        filter: NotificationFilter,
+        cursor: Option<NotificationCursor>,
+        limit: i64,
+    ) -> Result<Vec<NotificationEntity>> {
+        use crate::infrastructure::database::schema::notifications;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mut query = notifications::table
             .filter(notifications::brawler_id.eq(user_id))
-            .order(notifications::created_at.desc())
-            .limit(50)
+            .into_boxed();
+
+        // (updated_at, id) < cursor
+        if let Some(cursor) = cursor {
+            query = query.filter(
+                notifications::updated_at
+                    .lt(cursor.updated_at)
+                    .or(notifications::updated_at
+                        .eq(cursor.updated_at)
+                        .and(notifications::id.lt(cursor.id))),
+            );
+        }
+        if let Some(type_) = filter.type_ {
+            query = query.filter(notifications::type_.eq(type_));
+        }
+        if filter.unread_only {
+            query = query.filter(notifications::is_read.eq(false));
+        }
+
+        // A group bumped by add_grouped rises back to the top; the id breaks ties
+        let results = query
+            .order((notifications::updated_at.desc(), notifications::id.desc()))
+            .limit(limit)
             .load::<NotificationEntity>(&mut conn)?;
 
         Ok(results)
     }
 
+    async fn count_unread(&self, user_id: i32) -> Result<i64> {
+        use crate::infrastructure::database::schema::notifications;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let count = notifications::table
+            .filter(notifications::brawler_id.eq(user_id))
+            .filter(notifications::is_read.eq(false))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn count_unread_many(&self, user_ids: Vec<i32>) -> Result<Vec<(i32, i64)>> {
+        use crate::infrastructure::database::schema::notifications;
+
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let counts = notifications::table
+            .filter(notifications::brawler_id.eq_any(user_ids))
+            .filter(notifications::is_read.eq(false))
+            .group_by(notifications::brawler_id)
+            .select((notifications::brawler_id, count_star()))
+            .load::<(i32, i64)>(&mut conn)?;
+
+        Ok(counts)
+    }
+
     async fn mark_as_read(&self, notification_id: i32, user_id: i32) -> Result<()> {
         use crate::infrastructure::database::schema::notifications;
 
@@ -79,6 +204,22 @@ impl NotificationRepository for NotificationPostgres {
         Ok(())
     }
 
+    async fn delete(&self, notification_id: i32, user_id: i32) -> Result<bool> {
+        use crate::infrastructure::database::schema::notifications;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let deleted = diesel::delete(
+            notifications::table.filter(
+                notifications::id
+                    .eq(notification_id)
+                    .and(notifications::brawler_id.eq(user_id)),
+            ),
+        )
+        .execute(&mut conn)?;
+
+        Ok(deleted > 0)
+    }
+
     async fn delete_for_user(&self, user_id: i32) -> Result<()> {
         use crate::infrastructure::database::schema::notifications;
 
@@ -88,4 +229,20 @@ impl NotificationRepository for NotificationPostgres {
 
         Ok(())
     }
+
+    async fn purge_read_before(&self, cutoff: NaiveDateTime) -> Result<usize> {
+        use crate::infrastructure::database::schema::notifications;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let purged = diesel::delete(
+            notifications::table.filter(
+                notifications::is_read
+                    .eq(true)
+                    .and(notifications::created_at.lt(cutoff)),
+            ),
+        )
+        .execute(&mut conn)?;
+
+        Ok(purged)
+    }
 }
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    dsl::exists, insert_into, select,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{repositories::privacy::PrivacyRepository, value_objects::dm_privacy::DmPrivacy},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_blocks, brawler_mutes, brawlers},
+    },
+};
+
+pub struct PrivacyPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PrivacyPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PrivacyRepository for PrivacyPostgres {
+    async fn block(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(brawler_blocks::table)
+            .values((
+                brawler_blocks::blocker_id.eq(blocker_id),
+                brawler_blocks::blocked_id.eq(blocked_id),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn unblock(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(brawler_blocks::table)
+            .filter(brawler_blocks::blocker_id.eq(blocker_id))
+            .filter(brawler_blocks::blocked_id.eq(blocked_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn list_blocked(&self, blocker_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = brawler_blocks::table
+            .filter(brawler_blocks::blocker_id.eq(blocker_id))
+            .order(brawler_blocks::created_at.desc())
+            .select(brawler_blocks::blocked_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn list_blockers(&self, blocked_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = brawler_blocks::table
+            .filter(brawler_blocks::blocked_id.eq(blocked_id))
+            .select(brawler_blocks::blocker_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn is_blocked_between(&self, brawler1_id: i32, brawler2_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let blocked = select(exists(
+            brawler_blocks::table.filter(
+                brawler_blocks::blocker_id
+                    .eq(brawler1_id)
+                    .and(brawler_blocks::blocked_id.eq(brawler2_id))
+                    .or(brawler_blocks::blocker_id
+                        .eq(brawler2_id)
+                        .and(brawler_blocks::blocked_id.eq(brawler1_id))),
+            ),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(blocked)
+    }
+
+    async fn is_blocked(&self, blocker_id: i32, blocked_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let blocked = select(exists(
+            brawler_blocks::table
+                .filter(brawler_blocks::blocker_id.eq(blocker_id))
+                .filter(brawler_blocks::blocked_id.eq(blocked_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(blocked)
+    }
+
+    async fn mute(&self, muter_id: i32, muted_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(brawler_mutes::table)
+            .values((
+                brawler_mutes::muter_id.eq(muter_id),
+                brawler_mutes::muted_id.eq(muted_id),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn unmute(&self, muter_id: i32, muted_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(brawler_mutes::table)
+            .filter(brawler_mutes::muter_id.eq(muter_id))
+            .filter(brawler_mutes::muted_id.eq(muted_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn list_muted(&self, muter_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = brawler_mutes::table
+            .filter(brawler_mutes::muter_id.eq(muter_id))
+            .order(brawler_mutes::created_at.desc())
+            .select(brawler_mutes::muted_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn find_muters(&self, actor_id: i32, recipient_ids: Vec<i32>) -> Result<Vec<i32>> {
+        if recipient_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = brawler_mutes::table
+            .filter(brawler_mutes::muted_id.eq(actor_id))
+            .filter(brawler_mutes::muter_id.eq_any(recipient_ids))
+            .select(brawler_mutes::muter_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn get_dm_privacy(&self, brawler_id: i32) -> Result<DmPrivacy> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let dm_privacy = brawlers::table
+            .find(brawler_id)
+            .select(brawlers::dm_privacy)
+            .first::<String>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Brawler not found"))?;
+
+        dm_privacy.parse()
+    }
+
+    async fn set_dm_privacy(&self, brawler_id: i32, dm_privacy: DmPrivacy) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::dm_privacy.eq(dm_privacy.to_string()))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
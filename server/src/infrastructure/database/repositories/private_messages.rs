@@ -1,11 +1,21 @@
 use crate::domain::entities::private_messages::PrivateMessage;
 use crate::domain::repositories::private_messages::PrivateMessageRepository;
-use crate::infrastructure::database::schema::private_messages;
+use crate::infrastructure::database::{repositories::attachments, schema::private_messages};
 use async_trait::async_trait;
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool};
 use std::sync::Arc;
 
+/// The message's `AttachmentModel`s as a JSON array; files of unsent messages are dropped
+const ATTACHMENTS_COLUMN: &str = r#"COALESCE((
+        SELECT json_agg(json_build_object(
+            'id', a.id, 'file_name', a.file_name, 'content_type', a.content_type,
+            'size_bytes', a.size_bytes, 'url', '/api/attachments/' || a.id
+        ) ORDER BY a.id)
+        FROM attachments a
+        WHERE a.private_message_id = m.id AND m.deleted_at IS NULL
+    ), '[]'::json) as attachments"#;
+
 pub struct PrivateMessagePostgres {
     pool: Arc<Pool<ConnectionManager<PgConnection>>>,
 }
@@ -16,17 +26,6 @@ impl PrivateMessagePostgres {
     }
 }
 
-#[derive(Queryable, Selectable, Insertable)]
-#[diesel(table_name = private_messages)]
-struct PrivateMessageDb {
-    id: i32,
-    sender_id: i32,
-    receiver_id: i32,
-    content: String,
-    is_read: bool,
-    created_at: chrono::NaiveDateTime,
-}
-
 #[derive(QueryableByName, Debug)]
 struct RecentChatDb {
     #[diesel(sql_type = diesel::sql_types::Integer)]
@@ -49,23 +48,12 @@ struct RecentChatDb {
     is_read: bool,
     #[diesel(sql_type = diesel::sql_types::Timestamp)]
     created_at: chrono::NaiveDateTime,
-}
-
-impl From<PrivateMessageDb> for PrivateMessage {
-    fn from(db: PrivateMessageDb) -> Self {
-        Self {
-            id: db.id,
-            sender_id: db.sender_id,
-            sender_display_name: None,
-            sender_avatar_url: None,
-            receiver_id: db.receiver_id,
-            receiver_display_name: None,
-            receiver_avatar_url: None,
-            content: db.content,
-            is_read: db.is_read,
-            created_at: db.created_at,
-        }
-    }
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Timestamp>)]
+    edited_at: Option<chrono::NaiveDateTime>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Timestamp>)]
+    deleted_at: Option<chrono::NaiveDateTime>,
+    #[diesel(sql_type = diesel::sql_types::Json)]
+    attachments: serde_json::Value,
 }
 
 impl From<RecentChatDb> for PrivateMessage {
@@ -81,81 +69,117 @@ impl From<RecentChatDb> for PrivateMessage {
             content: db.content,
             is_read: db.is_read,
             created_at: db.created_at,
+            edited_at: db.edited_at,
+            deleted_at: db.deleted_at,
+            attachments: db.attachments,
         }
     }
 }
 
 #[async_trait]
 impl PrivateMessageRepository for PrivateMessagePostgres {
-    async fn save(&self, s_id: i32, r_id: i32, msg: String) -> Result<PrivateMessage, String> {
+    async fn save(
+        &self,
+        s_id: i32,
+        r_id: i32,
+        msg: String,
+        attachment_ids: Vec<i32>,
+    ) -> Result<PrivateMessage, String> {
+        use crate::infrastructure::database::schema::{conversation_participants, conversations};
+
         let mut conn = self.pool.get().map_err(|e| e.to_string())?;
 
-        let saved = diesel::insert_into(private_messages::table)
-            .values((
-                private_messages::sender_id.eq(s_id),
-                private_messages::receiver_id.eq(r_id),
-                private_messages::content.eq(msg),
-            ))
-            .get_result::<PrivateMessageDb>(&mut conn)
-            .map_err(|e| e.to_string())?;
+        // Every DM lives in the pair's one-to-one conversation, created on first contact
+        let message_id = conn
+            .transaction::<i32, anyhow::Error, _>(|conn| {
+                let direct_key = format!("{}:{}", s_id.min(r_id), s_id.max(r_id));
+                let conversation_id = diesel::insert_into(conversations::table)
+                    .values((
+                        conversations::direct_key.eq(&direct_key),
+                        conversations::created_by.eq(s_id),
+                    ))
+                    .on_conflict(conversations::direct_key)
+                    .do_update()
+                    .set(conversations::updated_at.eq(diesel::dsl::now))
+                    .returning(conversations::id)
+                    .get_result::<i32>(conn)?;
 
-        use crate::infrastructure::database::schema::brawlers;
-        let s_info = brawlers::table
-            .find(s_id)
-            .select((brawlers::display_name, brawlers::avatar_url))
-            .first::<(String, Option<String>)>(&mut conn)
-            .optional()
-            .map_err(|e| e.to_string())?;
+                diesel::insert_into(conversation_participants::table)
+                    .values(vec![
+                        (
+                            conversation_participants::conversation_id.eq(conversation_id),
+                            conversation_participants::brawler_id.eq(s_id),
+                        ),
+                        (
+                            conversation_participants::conversation_id.eq(conversation_id),
+                            conversation_participants::brawler_id.eq(r_id),
+                        ),
+                    ])
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
 
-        let r_info = brawlers::table
-            .find(r_id)
-            .select((brawlers::display_name, brawlers::avatar_url))
-            .first::<(String, Option<String>)>(&mut conn)
-            .optional()
+                let message_id = diesel::insert_into(private_messages::table)
+                    .values((
+                        private_messages::conversation_id.eq(conversation_id),
+                        private_messages::sender_id.eq(s_id),
+                        private_messages::receiver_id.eq(r_id),
+                        private_messages::content.eq(msg),
+                    ))
+                    .returning(private_messages::id)
+                    .get_result::<i32>(conn)?;
+
+                attachments::link_to_message(conn, attachment_ids, s_id, message_id)?;
+
+                Ok(message_id)
+            })
             .map_err(|e| e.to_string())?;
 
-        let mut entity: PrivateMessage = saved.into();
-        if let Some((name, avatar)) = s_info {
-            entity.sender_display_name = Some(name);
-            entity.sender_avatar_url = avatar;
-        }
-        if let Some((name, avatar)) = r_info {
-            entity.receiver_display_name = Some(name);
-            entity.receiver_avatar_url = avatar;
-        }
-        Ok(entity)
+        self.find_by_id(message_id).await
     }
 
     async fn get_conversation(
         &self,
         user1: i32,
         user2: i32,
+        before_id: Option<i32>,
+        limit: i64,
     ) -> Result<Vec<PrivateMessage>, String> {
         let mut conn = self.pool.get().map_err(|e| e.to_string())?;
 
-        let sql = r#"
-            SELECT m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
-                   m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
-                   m.content, m.is_read, m.created_at
-            FROM private_messages m
-            LEFT JOIN brawlers s ON m.sender_id = s.id
-            LEFT JOIN brawlers r ON m.receiver_id = r.id
-            WHERE (m.sender_id = $1 AND m.receiver_id = $2)
-               OR (m.sender_id = $3 AND m.receiver_id = $4)
-            ORDER BY m.created_at ASC
-        "#;
+        // Matching on LEAST/GREATEST lets Postgres walk idx_pm_pair_id backwards from the cursor
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
+                       m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
+                       m.content, m.is_read, m.created_at, m.edited_at, m.deleted_at,
+                    {ATTACHMENTS_COLUMN}
+                FROM private_messages m
+                LEFT JOIN brawlers s ON m.sender_id = s.id
+                LEFT JOIN brawlers r ON m.receiver_id = r.id
+                WHERE m.receiver_id IS NOT NULL
+                  AND LEAST(m.sender_id, m.receiver_id) = LEAST($1, $2)
+                  AND GREATEST(m.sender_id, m.receiver_id) = GREATEST($1, $2)
+                  AND ($3::int4 IS NULL OR m.id < $3)
+                  AND NOT ((m.sender_id = $1 AND m.hidden_for_sender) OR (m.receiver_id = $1 AND m.hidden_for_receiver))
+                ORDER BY m.id DESC
+                LIMIT $4
+            ) page
+            ORDER BY page.id ASC
+        "#
+        );
 
         diesel::sql_query(sql)
             .bind::<diesel::sql_types::Integer, _>(user1)
             .bind::<diesel::sql_types::Integer, _>(user2)
-            .bind::<diesel::sql_types::Integer, _>(user2)
-            .bind::<diesel::sql_types::Integer, _>(user1)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Integer>, _>(before_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
             .load::<RecentChatDb>(&mut conn)
             .map(|msgs| msgs.into_iter().map(Into::into).collect())
             .map_err(|e| e.to_string())
     }
 
-    async fn mark_as_read(&self, r_id: i32, s_id: i32) -> Result<(), String> {
+    async fn mark_as_read(&self, r_id: i32, s_id: i32) -> Result<Option<i32>, String> {
         let mut conn = self.pool.get().map_err(|e| e.to_string())?;
 
         diesel::update(private_messages::table)
@@ -163,8 +187,9 @@ impl PrivateMessageRepository for PrivateMessagePostgres {
             .filter(private_messages::sender_id.eq(s_id))
             .filter(private_messages::is_read.eq(false))
             .set(private_messages::is_read.eq(true))
-            .execute(&mut conn)
-            .map(|_| ())
+            .returning(private_messages::id)
+            .get_results::<i32>(&mut conn)
+            .map(|ids| ids.into_iter().max())
             .map_err(|e| e.to_string())
     }
 
@@ -174,31 +199,172 @@ impl PrivateMessageRepository for PrivateMessagePostgres {
         private_messages::table
             .filter(private_messages::receiver_id.eq(u_id))
             .filter(private_messages::is_read.eq(false))
+            .filter(private_messages::deleted_at.is_null())
+            .filter(private_messages::hidden_for_receiver.eq(false))
             .count()
             .get_result(&mut conn)
             .map_err(|e| e.to_string())
     }
 
-    async fn get_recent_chats(&self, u_id: i32) -> Result<Vec<PrivateMessage>, String> {
+    async fn get_recent_chats(
+        &self,
+        u_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<PrivateMessage>, String> {
         let mut conn = self.pool.get().map_err(|e| e.to_string())?;
 
         // Get the latest message for each conversation with display names
-        let sql = r#"
-            SELECT DISTINCT ON (LEAST(m.sender_id, m.receiver_id), GREATEST(m.sender_id, m.receiver_id))
-                m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
-                m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
-                m.content, m.is_read, m.created_at
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT DISTINCT ON (LEAST(m.sender_id, m.receiver_id), GREATEST(m.sender_id, m.receiver_id))
+                    m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
+                    m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
+                    m.content, m.is_read, m.created_at, m.edited_at, m.deleted_at,
+                    {ATTACHMENTS_COLUMN}
+                FROM private_messages m
+                LEFT JOIN brawlers s ON m.sender_id = s.id
+                LEFT JOIN brawlers r ON m.receiver_id = r.id
+                WHERE (m.sender_id = $1 OR m.receiver_id = $1)
+                  AND m.receiver_id IS NOT NULL
+                  AND NOT ((m.sender_id = $1 AND m.hidden_for_sender) OR (m.receiver_id = $1 AND m.hidden_for_receiver))
+                ORDER BY LEAST(m.sender_id, m.receiver_id), GREATEST(m.sender_id, m.receiver_id), m.id DESC
+            ) latest
+            WHERE ($2::int4 IS NULL OR latest.id < $2)
+            ORDER BY latest.id DESC
+            LIMIT $3
+        "#
+        );
+
+        diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Integer, _>(u_id)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Integer>, _>(before_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<RecentChatDb>(&mut conn)
+            .map(|msgs| msgs.into_iter().map(Into::into).collect())
+            .map_err(|e| e.to_string())
+    }
+
+    async fn search(
+        &self,
+        u_id: i32,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<PrivateMessage>, String> {
+        // Each word becomes a prefix match, so "meet" finds "meetup". Only letters and digits
+        // survive, which keeps user input from being parsed as tsquery syntax.
+        let terms: Vec<String> = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(|term| format!("{}:*", term.to_lowercase()))
+            .collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        let sql = format!(
+            r#"
+            SELECT m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
+                   m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
+                   m.content, m.is_read, m.created_at, m.edited_at, m.deleted_at,
+                    {ATTACHMENTS_COLUMN}
             FROM private_messages m
             LEFT JOIN brawlers s ON m.sender_id = s.id
             LEFT JOIN brawlers r ON m.receiver_id = r.id
-            WHERE m.sender_id = $1 OR m.receiver_id = $1
-            ORDER BY LEAST(m.sender_id, m.receiver_id), GREATEST(m.sender_id, m.receiver_id), m.created_at DESC
-        "#;
+            WHERE (m.sender_id = $1 OR m.receiver_id = $1)
+              AND m.receiver_id IS NOT NULL
+              AND m.deleted_at IS NULL
+              AND NOT ((m.sender_id = $1 AND m.hidden_for_sender) OR (m.receiver_id = $1 AND m.hidden_for_receiver))
+              AND to_tsvector('simple', m.content) @@ to_tsquery('simple', $2)
+            ORDER BY m.id DESC
+            LIMIT $3
+        "#
+        );
 
         diesel::sql_query(sql)
             .bind::<diesel::sql_types::Integer, _>(u_id)
+            .bind::<diesel::sql_types::Text, _>(terms.join(" & "))
+            .bind::<diesel::sql_types::BigInt, _>(limit)
             .load::<RecentChatDb>(&mut conn)
             .map(|msgs| msgs.into_iter().map(Into::into).collect())
             .map_err(|e| e.to_string())
     }
+
+    async fn find_by_id(&self, message_id: i32) -> Result<PrivateMessage, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        let sql = format!(
+            r#"
+            SELECT m.id, m.sender_id, s.display_name as sender_name, s.avatar_url as sender_avatar_url, 
+                   m.receiver_id, r.display_name as receiver_name, r.avatar_url as receiver_avatar_url, 
+                   m.content, m.is_read, m.created_at, m.edited_at, m.deleted_at,
+                    {ATTACHMENTS_COLUMN}
+            FROM private_messages m
+            LEFT JOIN brawlers s ON m.sender_id = s.id
+            LEFT JOIN brawlers r ON m.receiver_id = r.id
+            WHERE m.id = $1
+              AND m.receiver_id IS NOT NULL
+        "#
+        );
+
+        diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Integer, _>(message_id)
+            .get_result::<RecentChatDb>(&mut conn)
+            .map(Into::into)
+            .map_err(|e| e.to_string())
+    }
+
+    async fn edit(&self, message_id: i32, content: String) -> Result<PrivateMessage, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        diesel::update(private_messages::table)
+            .filter(private_messages::id.eq(message_id))
+            .filter(private_messages::deleted_at.is_null())
+            .set((
+                private_messages::content.eq(content),
+                private_messages::edited_at.eq(diesel::dsl::now),
+            ))
+            .execute(&mut conn)
+            .map_err(|e| e.to_string())?;
+
+        self.find_by_id(message_id).await
+    }
+
+    async fn unsend(&self, message_id: i32) -> Result<PrivateMessage, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        diesel::update(private_messages::table)
+            .filter(private_messages::id.eq(message_id))
+            .filter(private_messages::deleted_at.is_null())
+            .set((
+                private_messages::content.eq(""),
+                private_messages::deleted_at.eq(diesel::dsl::now),
+            ))
+            .execute(&mut conn)
+            .map_err(|e| e.to_string())?;
+
+        self.find_by_id(message_id).await
+    }
+
+    async fn hide_for(&self, message_id: i32, user_id: i32) -> Result<(), String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        diesel::update(private_messages::table)
+            .filter(private_messages::id.eq(message_id))
+            .filter(private_messages::sender_id.eq(user_id))
+            .set(private_messages::hidden_for_sender.eq(true))
+            .execute(&mut conn)
+            .map_err(|e| e.to_string())?;
+
+        diesel::update(private_messages::table)
+            .filter(private_messages::id.eq(message_id))
+            .filter(private_messages::receiver_id.eq(user_id))
+            .set(private_messages::hidden_for_receiver.eq(true))
+            .execute(&mut conn)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
 }
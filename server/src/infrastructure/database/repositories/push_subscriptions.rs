@@ -0,0 +1,103 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, delete, dsl::now, insert_into,
+    upsert::excluded,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::push_subscriptions::{PushSubscriptionEntity, UpsertPushSubscriptionEntity},
+        repositories::push_subscriptions::PushSubscriptionRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::push_subscriptions},
+};
+
+pub struct PushSubscriptionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PushSubscriptionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PushSubscriptionRepository for PushSubscriptionPostgres {
+    async fn upsert(&self, subscription: UpsertPushSubscriptionEntity) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let upsert = insert_into(push_subscriptions::table)
+            .values(subscription)
+            .on_conflict(push_subscriptions::endpoint)
+            .do_update()
+            .set((
+                push_subscriptions::p256dh.eq(excluded(push_subscriptions::p256dh)),
+                push_subscriptions::auth.eq(excluded(push_subscriptions::auth)),
+                push_subscriptions::expires_at.eq(excluded(push_subscriptions::expires_at)),
+                push_subscriptions::updated_at.eq(now),
+            ));
+        // Someone else's endpoint hits the conflict but not this filter, so nothing is written
+        let written = diesel::query_dsl::methods::FilterDsl::filter(
+            upsert,
+            push_subscriptions::brawler_id.eq(excluded(push_subscriptions::brawler_id)),
+        )
+        .execute(&mut conn)?;
+
+        Ok(written > 0)
+    }
+
+    async fn remove(&self, brawler_id: i32, endpoint: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = delete(
+            push_subscriptions::table
+                .filter(push_subscriptions::brawler_id.eq(brawler_id))
+                .filter(push_subscriptions::endpoint.eq(endpoint)),
+        )
+        .execute(&mut conn)?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn find_by_brawlers(&self, brawler_ids: Vec<i32>) -> Result<Vec<PushSubscriptionEntity>> {
+        if brawler_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let subscriptions = push_subscriptions::table
+            .filter(push_subscriptions::brawler_id.eq_any(brawler_ids))
+            .select(PushSubscriptionEntity::as_select())
+            .load::<PushSubscriptionEntity>(&mut conn)?;
+
+        Ok(subscriptions)
+    }
+
+    async fn remove_many(&self, ids: Vec<i32>) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = delete(push_subscriptions::table.filter(push_subscriptions::id.eq_any(ids)))
+            .execute(&mut conn)?;
+
+        Ok(deleted)
+    }
+
+    async fn purge_expired(&self, at: NaiveDateTime) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted =
+            delete(push_subscriptions::table.filter(push_subscriptions::expires_at.lt(at)))
+                .execute(&mut conn)?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, insert_into};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::scheduled_jobs::{AddScheduledJobEntity, ScheduledJobEntity},
+        repositories::scheduled_jobs::ScheduledJobRepository,
+        value_objects::{
+            scheduled_job_statuses::ScheduledJobStatuses,
+            scheduled_job_types::{JobLane, ScheduledJobTypes},
+        },
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::scheduled_jobs},
+};
+
+pub struct ScheduledJobPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ScheduledJobPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ScheduledJobRepository for ScheduledJobPostgres {
+    async fn add(&self, job: AddScheduledJobEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let id = insert_into(scheduled_jobs::table)
+            .values(job)
+            .returning(scheduled_jobs::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(id)
+    }
+
+    async fn has_pending(&self, job_type: ScheduledJobTypes) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let exists = diesel::select(diesel::dsl::exists(
+            scheduled_jobs::table
+                .filter(scheduled_jobs::job_type.eq(job_type.to_string()))
+                .filter(scheduled_jobs::status.eq_any(vec![
+                    ScheduledJobStatuses::Pending.to_string(),
+                    ScheduledJobStatuses::Running.to_string(),
+                ])),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(exists)
+    }
+
+    async fn cancel_pending(
+        &self,
+        related_id: i32,
+        job_types: Vec<ScheduledJobTypes>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let job_types: Vec<String> = job_types.iter().map(|t| t.to_string()).collect();
+
+        diesel::delete(scheduled_jobs::table)
+            .filter(scheduled_jobs::related_id.eq(related_id))
+            .filter(scheduled_jobs::job_type.eq_any(job_types))
+            .filter(scheduled_jobs::status.eq(ScheduledJobStatuses::Pending.to_string()))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn claim_due(&self, limit: i64, lane: JobLane) -> Result<Vec<ScheduledJobEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (job_type, matching) = match lane {
+            JobLane::Only(job_type) => (job_type, true),
+            JobLane::Except(job_type) => (job_type, false),
+        };
+
+        let sql = r#"
+            UPDATE scheduled_jobs
+            SET status = 'Running', attempts = attempts + 1
+            WHERE id IN (
+                SELECT id FROM scheduled_jobs
+                WHERE status = 'Pending' AND run_at <= NOW()
+                  AND (job_type = $2) = $3
+                ORDER BY run_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+        "#;
+
+        let jobs = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .bind::<diesel::sql_types::Text, _>(job_type.to_string())
+            .bind::<diesel::sql_types::Bool, _>(matching)
+            .load::<ScheduledJobEntity>(&mut conn)?;
+
+        Ok(jobs)
+    }
+
+    async fn mark_done(&self, job_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(scheduled_jobs::table)
+            .filter(scheduled_jobs::id.eq(job_id))
+            .set(scheduled_jobs::status.eq(ScheduledJobStatuses::Done.to_string()))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        job_id: i32,
+        error: String,
+        retry_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        match retry_at {
+            Some(run_at) => {
+                diesel::update(scheduled_jobs::table)
+                    .filter(scheduled_jobs::id.eq(job_id))
+                    .set((
+                        scheduled_jobs::status.eq(ScheduledJobStatuses::Pending.to_string()),
+                        scheduled_jobs::last_error.eq(Some(error)),
+                        scheduled_jobs::run_at.eq(run_at),
+                    ))
+                    .execute(&mut conn)?;
+            }
+            None => {
+                diesel::update(scheduled_jobs::table)
+                    .filter(scheduled_jobs::id.eq(job_id))
+                    .set((
+                        scheduled_jobs::status.eq(ScheduledJobStatuses::Failed.to_string()),
+                        scheduled_jobs::last_error.eq(Some(error)),
+                    ))
+                    .execute(&mut conn)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn requeue_running(&self) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count = diesel::update(scheduled_jobs::table)
+            .filter(scheduled_jobs::status.eq(ScheduledJobStatuses::Running.to_string()))
+            .set(scheduled_jobs::status.eq(ScheduledJobStatuses::Pending.to_string()))
+            .execute(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn purge_finished_before(&self, cutoff: NaiveDateTime) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(scheduled_jobs::table)
+            .filter(scheduled_jobs::status.eq_any(vec![
+                ScheduledJobStatuses::Done.to_string(),
+                ScheduledJobStatuses::Failed.to_string(),
+            ]))
+            .filter(scheduled_jobs::updated_at.lt(cutoff))
+            .execute(&mut conn)?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, PgArrayExpressionMethods,
+    QueryDsl, RunQueryDsl, SelectableHelper, delete, dsl::now, insert_into, update,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::webhooks::{
+            AddWebhookDeliveryEntity, AddWebhookEntity, EditWebhookEntity, WebhookDeliveryEntity,
+            WebhookEntity,
+        },
+        repositories::webhooks::WebhookRepository,
+        value_objects::webhook_delivery_statuses::WebhookDeliveryStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{webhook_deliveries, webhooks},
+    },
+};
+
+pub struct WebhookPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl WebhookPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookPostgres {
+    async fn add(&self, webhook: AddWebhookEntity) -> Result<WebhookEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let webhook = insert_into(webhooks::table)
+            .values(webhook)
+            .returning(WebhookEntity::as_returning())
+            .get_result::<WebhookEntity>(&mut conn)?;
+
+        Ok(webhook)
+    }
+
+    async fn find(&self, webhook_id: i32) -> Result<Option<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let webhook = webhooks::table
+            .find(webhook_id)
+            .select(WebhookEntity::as_select())
+            .first::<WebhookEntity>(&mut conn)
+            .optional()?;
+
+        Ok(webhook)
+    }
+
+    async fn list_by_owner(&self, owner_id: i32) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = webhooks::table
+            .filter(webhooks::owner_id.eq(owner_id))
+            .order(webhooks::id.asc())
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn edit(&self, webhook_id: i32, changes: EditWebhookEntity) -> Result<WebhookEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let webhook = update(webhooks::table.find(webhook_id))
+            .set((changes, webhooks::updated_at.eq(now)))
+            .returning(WebhookEntity::as_returning())
+            .get_result::<WebhookEntity>(&mut conn)?;
+
+        Ok(webhook)
+    }
+
+    async fn remove(&self, webhook_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        delete(webhooks::table.find(webhook_id)).execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn find_subscribed(
+        &self,
+        event: &str,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = webhooks::table
+            .filter(webhooks::active.eq(true))
+            .filter(webhooks::events.contains(vec![event.to_string()]))
+            .filter(
+                webhooks::all_missions
+                    .eq(true)
+                    .or(webhooks::mission_id.eq(mission_id))
+                    .or(webhooks::mission_id
+                        .is_null()
+                        .and(webhooks::owner_id.eq(chief_id))),
+            )
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn add_deliveries(&self, deliveries: Vec<AddWebhookDeliveryEntity>) -> Result<Vec<i32>> {
+        if deliveries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let ids = insert_into(webhook_deliveries::table)
+            .values(deliveries)
+            .returning(webhook_deliveries::id)
+            .get_results::<i32>(&mut conn)?;
+
+        Ok(ids)
+    }
+
+    async fn find_delivery(
+        &self,
+        delivery_id: i32,
+    ) -> Result<Option<(WebhookDeliveryEntity, WebhookEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhook_deliveries::table
+            .inner_join(webhooks::table)
+            .filter(webhook_deliveries::id.eq(delivery_id))
+            .select((
+                WebhookDeliveryEntity::as_select(),
+                WebhookEntity::as_select(),
+            ))
+            .first::<(WebhookDeliveryEntity, WebhookEntity)>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery_id: i32,
+        status: WebhookDeliveryStatuses,
+        response_status: Option<i32>,
+        error: Option<String>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let at = Utc::now().naive_utc();
+        let delivered_at = (status == WebhookDeliveryStatuses::Delivered).then_some(at);
+        update(webhook_deliveries::table.find(delivery_id))
+            .set((
+                webhook_deliveries::status.eq(status.to_string()),
+                webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                webhook_deliveries::response_status.eq(response_status),
+                webhook_deliveries::last_error.eq(error),
+                webhook_deliveries::last_attempt_at.eq(at),
+                webhook_deliveries::delivered_at.eq(delivered_at),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mut query = webhook_deliveries::table
+            .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+            .into_boxed();
+
+        if let Some(before_id) = before_id {
+            query = query.filter(webhook_deliveries::id.lt(before_id));
+        }
+
+        // Ids grow with time, so they double as a stable cursor
+        let results = query
+            .order(webhook_deliveries::id.desc())
+            .limit(limit)
+            .select(WebhookDeliveryEntity::as_select())
+            .load::<WebhookDeliveryEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
@@ -1,5 +1,48 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    attachments (id) {
+        id -> Int4,
+        uploader_id -> Int4,
+        #[max_length = 255]
+        storage_key -> Varchar,
+        #[max_length = 255]
+        file_name -> Varchar,
+        #[max_length = 100]
+        content_type -> Varchar,
+        size_bytes -> Int8,
+        mission_comment_id -> Nullable<Int4>,
+        private_message_id -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_achievements (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 100]
+        achievement_code -> Varchar,
+        awarded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_blocks (blocker_id, blocked_id) {
+        blocker_id -> Int4,
+        blocked_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_mutes (muter_id, muted_id) {
+        muter_id -> Int4,
+        muted_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -24,6 +67,33 @@ diesel::table! {
         instagram -> Nullable<Varchar>,
         #[max_length = 255]
         facebook -> Nullable<Varchar>,
+        #[max_length = 16]
+        dm_privacy -> Varchar,
+    }
+}
+
+diesel::table! {
+    conversation_participants (conversation_id, brawler_id) {
+        conversation_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 16]
+        role -> Varchar,
+        last_read_message_id -> Nullable<Int4>,
+        joined_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    conversations (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Nullable<Varchar>,
+        is_group -> Bool,
+        #[max_length = 32]
+        direct_key -> Nullable<Varchar>,
+        created_by -> Nullable<Int4>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -32,6 +102,20 @@ diesel::table! {
         mission_id -> Int4,
         brawler_id -> Int4,
         joined_at -> Timestamp,
+        #[max_length = 20]
+        attendance_status -> Varchar,
+        checked_in_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    email_digest_subscriptions (brawler_id) {
+        brawler_id -> Int4,
+        #[max_length = 16]
+        frequency -> Varchar,
+        last_sent_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -47,6 +131,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mission_comment_reactions (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 32]
+        emoji -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_comment_revisions (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        content -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     mission_comments (id) {
         id -> Int4,
@@ -54,6 +158,62 @@ diesel::table! {
         brawler_id -> Int4,
         content -> Text,
         created_at -> Timestamp,
+        parent_id -> Nullable<Int4>,
+        edited_at -> Nullable<Timestamp>,
+        deleted_at -> Nullable<Timestamp>,
+        deleted_by -> Nullable<Int4>,
+        pinned_at -> Nullable<Timestamp>,
+        pinned_by -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    mission_gallery_items (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        uploader_id -> Int4,
+        #[max_length = 512]
+        image_url -> Varchar,
+        #[max_length = 255]
+        image_public_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int4,
+        tags -> Array<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_read_markers (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        last_read_comment_id -> Int4,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_transcripts (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        #[max_length = 255]
+        mission_name -> Varchar,
+        chief_id -> Int4,
+        participant_ids -> Array<Int4>,
+        #[max_length = 32]
+        reason -> Varchar,
+        comments -> Jsonb,
+        comment_count -> Int4,
+        created_at -> Timestamp,
     }
 }
 
@@ -74,6 +234,24 @@ diesel::table! {
         #[max_length = 255]
         location -> Nullable<Varchar>,
         category -> Varchar,
+        finished_at -> Nullable<Timestamp>,
+        min_reputation -> Nullable<Float8>,
+        #[max_length = 512]
+        cover_url -> Nullable<Varchar>,
+        #[max_length = 255]
+        cover_public_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    notification_preferences (brawler_id, notification_type, channel) {
+        brawler_id -> Int4,
+        #[max_length = 64]
+        notification_type -> Varchar,
+        #[max_length = 32]
+        channel -> Varchar,
+        enabled -> Bool,
+        updated_at -> Timestamp,
     }
 }
 
@@ -88,6 +266,8 @@ diesel::table! {
         related_id -> Nullable<Int4>,
         is_read -> Bool,
         created_at -> Timestamp,
+        group_count -> Int4,
+        updated_at -> Timestamp,
     }
 }
 
@@ -95,26 +275,137 @@ diesel::table! {
     private_messages (id) {
         id -> Int4,
         sender_id -> Int4,
-        receiver_id -> Int4,
+        receiver_id -> Nullable<Int4>,
         content -> Text,
         is_read -> Bool,
         created_at -> Timestamp,
+        edited_at -> Nullable<Timestamp>,
+        deleted_at -> Nullable<Timestamp>,
+        hidden_for_sender -> Bool,
+        hidden_for_receiver -> Bool,
+        conversation_id -> Int4,
+    }
+}
+
+diesel::table! {
+    push_subscriptions (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        endpoint -> Text,
+        #[max_length = 128]
+        p256dh -> Varchar,
+        #[max_length = 64]
+        auth -> Varchar,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    scheduled_jobs (id) {
+        id -> Int4,
+        #[max_length = 100]
+        job_type -> Varchar,
+        related_id -> Nullable<Int4>,
+        payload -> Jsonb,
+        run_at -> Timestamp,
+        #[max_length = 20]
+        status -> Varchar,
+        attempts -> Int4,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        #[max_length = 32]
+        event -> Varchar,
+        payload -> Jsonb,
+        #[max_length = 16]
+        status -> Varchar,
+        attempts -> Int4,
+        response_status -> Nullable<Int4>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_attempt_at -> Nullable<Timestamp>,
+        delivered_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    webhooks (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        mission_id -> Nullable<Int4>,
+        url -> Text,
+        #[max_length = 64]
+        secret -> Varchar,
+        events -> Array<Text>,
+        all_missions -> Bool,
+        active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
+diesel::joinable!(attachments -> brawlers (uploader_id));
+diesel::joinable!(attachments -> mission_comments (mission_comment_id));
+diesel::joinable!(attachments -> private_messages (private_message_id));
+diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
+diesel::joinable!(conversation_participants -> brawlers (brawler_id));
+diesel::joinable!(conversation_participants -> conversations (conversation_id));
+diesel::joinable!(conversations -> brawlers (created_by));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
-diesel::joinable!(mission_comments -> brawlers (brawler_id));
+diesel::joinable!(email_digest_subscriptions -> brawlers (brawler_id));
+diesel::joinable!(mission_comment_reactions -> brawlers (brawler_id));
+diesel::joinable!(mission_comment_reactions -> mission_comments (comment_id));
+diesel::joinable!(mission_comment_revisions -> mission_comments (comment_id));
 diesel::joinable!(mission_comments -> missions (mission_id));
+diesel::joinable!(mission_gallery_items -> brawlers (uploader_id));
+diesel::joinable!(mission_gallery_items -> missions (mission_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_read_markers -> brawlers (brawler_id));
+diesel::joinable!(mission_read_markers -> missions (mission_id));
+diesel::joinable!(mission_transcripts -> brawlers (chief_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(notification_preferences -> brawlers (brawler_id));
 diesel::joinable!(notifications -> brawlers (brawler_id));
+diesel::joinable!(private_messages -> conversations (conversation_id));
+diesel::joinable!(push_subscriptions -> brawlers (brawler_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
+diesel::joinable!(webhooks -> brawlers (owner_id));
+diesel::joinable!(webhooks -> missions (mission_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    attachments,
+    brawler_achievements,
+    brawler_blocks,
+    brawler_mutes,
     brawlers,
+    conversation_participants,
+    conversations,
     crew_memberships,
+    email_digest_subscriptions,
     friendships,
+    mission_comment_reactions,
+    mission_comment_revisions,
     mission_comments,
+    mission_gallery_items,
+    mission_ratings,
+    mission_read_markers,
+    mission_transcripts,
     missions,
+    notification_preferences,
     notifications,
     private_messages,
+    push_subscriptions,
+    scheduled_jobs,
+    webhook_deliveries,
+    webhooks,
 );
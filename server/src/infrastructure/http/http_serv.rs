@@ -19,10 +19,35 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig,
+    application::use_cases::{
+        notification_service::NotificationService, privacy::PrivacyUseCase,
+        web_push::WebPushUseCase, webhook_service::WebhookService, webhooks::WebhookUseCase,
+    },
+    config::{
+        config_loader::{get_stage, get_webhook_env},
+        config_model::DotEnvyConfig,
+        stage::Stage,
+    },
+    domain::repositories::{
+        blob_storage::BlobStorage, mailer::Mailer, webhooks::WebhookRepository,
+    },
     infrastructure::{
-        database::postgresql_connection::PgPoolSquad,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                friendships::FriendshipPostgres, mission_viewing::MissionViewingPostgres,
+                notification_preferences::NotificationPreferencePostgres,
+                notifications::NotificationPostgres, privacy::PrivacyPostgres,
+                push_subscriptions::PushSubscriptionPostgres, scheduled_jobs::ScheduledJobPostgres,
+                webhooks::WebhookPostgres,
+            },
+        },
         http::{middlewares::auth::auth, routers},
+        mailer::build_mailer,
+        scheduler::job_runner::JobRunner,
+        storage::build_blob_storage,
+        web_push::build_push_sender,
+        webhooks::HttpWebhookSender,
         websocket::{
             handler::{global_ws_handler, ws_handler},
             manager::ConnectionManager,
@@ -36,33 +61,75 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+#[allow(clippy::too_many_arguments)]
+fn api_serve(
+    db_pool: Arc<PgPoolSquad>,
+    manager: Arc<ConnectionManager>,
+    notification_service: Arc<NotificationService>,
+    storage: Arc<dyn BlobStorage>,
+    mailer: Arc<dyn Mailer>,
+    web_push: Arc<WebPushUseCase>,
+    webhook_service: Arc<WebhookService>,
+    webhooks: Arc<WebhookUseCase>,
+) -> Router {
     // WebSocket routes
     let ws_router = Router::new()
         .route("/mission/{id}", axum::routing::get(ws_handler))
-        .route(
-            "/global",
-            axum::routing::get(global_ws_handler).route_layer(middleware::from_fn(auth)),
-        )
-        .with_state(Arc::clone(&manager));
+        .with_state(Arc::clone(&manager))
+        .merge(
+            Router::new()
+                .route(
+                    "/global",
+                    axum::routing::get(global_ws_handler).route_layer(middleware::from_fn(auth)),
+                )
+                .with_state((
+                    Arc::clone(&manager),
+                    Arc::new(PrivacyUseCase::new(
+                        Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
+                        Arc::new(FriendshipPostgres::new(Arc::clone(&db_pool))),
+                    )),
+                )),
+        );
 
-    Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
+    let router = Router::new()
+        .nest(
+            "/brawler",
+            routers::brawlers::routes(Arc::clone(&db_pool), Arc::clone(&storage)),
+        )
         .nest(
             "/view",
             routers::mission_viewing::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/mission",
-            routers::mission_operation::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+            routers::mission_operation::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&notification_service),
+                Arc::clone(&webhook_service),
+            ),
         )
         .nest(
             "/crew",
-            routers::crew_operation::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+            routers::crew_operation::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&manager),
+                Arc::clone(&notification_service),
+                Arc::clone(&webhook_service),
+                Arc::clone(&storage),
+            ),
         )
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+            routers::mission_management::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&notification_service),
+                Arc::clone(&webhook_service),
+                Arc::clone(&storage),
+            ),
+        )
+        .nest(
+            "/mission-media",
+            routers::mission_media::routes(Arc::clone(&db_pool), Arc::clone(&storage)),
         )
         .nest(
             "/authentication",
@@ -71,34 +138,124 @@ fn api_serve(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Rout
         .nest("/util", routers::default_router::routes())
         .nest(
             "/comment",
-            routers::mission_comment::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+            routers::mission_comment::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&manager),
+                Arc::clone(&notification_service),
+                webhook_service,
+            ),
+        )
+        .nest(
+            "/rating",
+            routers::mission_ratings::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/notifications",
-            routers::notifications::routes(Arc::clone(&db_pool)),
+            routers::notifications::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&notification_service),
+                mailer,
+            ),
         )
         .nest(
             "/friendship",
-            routers::friendships::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+            routers::friendships::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&manager),
+                Arc::clone(&notification_service),
+            ),
         )
         .nest(
             "/messages",
             routers::private_messages::routes(
                 Arc::new(crate::infrastructure::database::repositories::private_messages::PrivateMessagePostgres::new(Arc::clone(&db_pool))),
                 Arc::clone(&manager),
-                Arc::new(crate::infrastructure::database::repositories::notifications::NotificationPostgres::new(Arc::clone(&db_pool)))
+                notification_service,
+                Arc::new(PrivacyUseCase::new(
+                    Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
+                    Arc::new(FriendshipPostgres::new(Arc::clone(&db_pool))),
+                )),
             ).route_layer(middleware::from_fn(auth)),
         )
-        .nest("/ws", ws_router)
-        .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        .nest(
+            "/conversations",
+            routers::conversations::routes(Arc::clone(&db_pool), Arc::clone(&manager)),
+        )
+        .nest("/privacy", routers::privacy::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/attachments",
+            routers::attachments::routes(Arc::clone(&db_pool), Arc::clone(&storage)),
+        )
+        .nest("/media", routers::media::routes(storage))
+        .nest("/push", routers::push::routes(web_push))
+        .nest("/webhooks", routers::webhooks::routes(webhooks))
+        .nest("/ws", ws_router);
+
+    let router = if get_stage() == Stage::Local {
+        router.nest("/mock-push", routers::mock_push::routes())
+    } else {
+        router
+    };
+
+    router.fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
     let manager = Arc::new(ConnectionManager::new());
+    let storage = build_blob_storage()?;
+    let mailer = build_mailer()?;
+    let web_push = Arc::new(WebPushUseCase::new(
+        Arc::new(PushSubscriptionPostgres::new(Arc::clone(&db_pool))),
+        build_push_sender(&get_stage())?,
+    ));
+    let notification_service = Arc::new(NotificationService::new(
+        Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPreferencePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&manager),
+        Arc::clone(&web_push),
+    ));
+    let webhook_env = get_webhook_env()?;
+    let webhook_repository: Arc<dyn WebhookRepository> =
+        Arc::new(WebhookPostgres::new(Arc::clone(&db_pool)));
+    let webhooks = Arc::new(WebhookUseCase::new(
+        Arc::clone(&webhook_repository),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(HttpWebhookSender::new(webhook_env.timeout_secs)),
+        webhook_env.admin_ids,
+    ));
+    let webhook_service = Arc::new(WebhookService::new(
+        webhook_repository,
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(ScheduledJobPostgres::new(Arc::clone(&db_pool))),
+    ));
+
+    JobRunner::new(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_service),
+        Arc::clone(&mailer),
+        Arc::clone(&webhook_service),
+        Arc::clone(&webhooks),
+    )?
+    .spawn();
+    info!("Job runner started");
 
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api", api_serve(db_pool, manager))
+        .nest(
+            "/api",
+            api_serve(
+                db_pool,
+                manager,
+                notification_service,
+                storage,
+                mailer,
+                web_push,
+                webhook_service,
+                webhooks,
+            ),
+        )
         .layer(tower_http::timeout::TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(config.server.timeout),
@@ -0,0 +1,125 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{StatusCode, header},
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::attachments::AttachmentUseCase,
+    config::config_loader::get_storage_env,
+    domain::repositories::blob_storage::BlobStorage,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::attachments::AttachmentPostgres,
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+/// Room for the multipart boundaries and headers around the file itself
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// Expects a multipart form with the file in a `file` field
+pub async fn upload(
+    State(use_case): State<Arc<AttachmentUseCase>>,
+    Extension(user_id): Extension<i32>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => {
+                return (StatusCode::BAD_REQUEST, "Missing file field").into_response();
+            }
+            Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        };
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().map(str::to_string);
+        let bytes = match field.bytes().await {
+            Ok(bytes) => bytes.to_vec(),
+            Err(e) => return (StatusCode::PAYLOAD_TOO_LARGE, e.to_string()).into_response(),
+        };
+
+        return match use_case.upload(user_id, file_name, bytes).await {
+            Ok(attachment) => (StatusCode::CREATED, Json(attachment)).into_response(),
+            Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        };
+    }
+}
+
+pub async fn download(
+    State(use_case): State<Arc<AttachmentUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(attachment_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.download(attachment_id, user_id).await {
+        Ok((attachment, bytes)) => {
+            // Images open in the browser, everything else downloads
+            let disposition = if attachment.content_type.starts_with("image/") {
+                "inline"
+            } else {
+                "attachment"
+            };
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, attachment.content_type),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        format!(
+                            "{}; filename=\"{}\"",
+                            disposition,
+                            attachment.file_name.replace(['"', '\\'], "_")
+                        ),
+                    ),
+                    (header::CACHE_CONTROL, "private, max-age=3600".to_string()),
+                    (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+                ],
+                bytes,
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove(
+    State(use_case): State<Arc<AttachmentUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(attachment_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.delete(attachment_id, user_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, storage: Arc<dyn BlobStorage>) -> Router {
+    let max_attachment_bytes = get_storage_env()
+        .map(|storage_env| storage_env.max_attachment_bytes)
+        .unwrap_or(10 * 1024 * 1024);
+
+    let use_case = Arc::new(AttachmentUseCase::new(
+        Arc::new(AttachmentPostgres::new(Arc::clone(&db_pool))),
+        storage,
+    ));
+
+    Router::new()
+        .route(
+            "/",
+            post(upload).layer(DefaultBodyLimit::max(
+                max_attachment_bytes + MULTIPART_OVERHEAD_BYTES,
+            )),
+        )
+        .route("/{attachment_id}", get(download))
+        .route("/{attachment_id}", delete(remove))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(use_case)
+}
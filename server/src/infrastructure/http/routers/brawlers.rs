@@ -10,27 +10,43 @@ use std::sync::Arc;
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        repositories::{blob_storage::BlobStorage, brawlers::BrawlerRepository},
         value_objects::{
             brawler_model::{RegisterBrawlerModel, UpdateBrawlerModel},
             uploaded_img::UploadBase64Img,
         },
     },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementPostgres, brawler_stats::BrawlerStatsPostgres,
+                brawlers::BrawlerPostgres, mission_ratings::MissionRatingPostgres,
+            },
+        },
         http::middlewares::auth::auth,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = BrawlersUseCase::new(Arc::new(repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, storage: Arc<dyn BlobStorage>) -> Router {
+    let repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let rating_repository = MissionRatingPostgres::new(Arc::clone(&db_pool));
+    let stats_repository = BrawlerStatsPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(db_pool);
+    let user_case = BrawlersUseCase::new(
+        Arc::new(repository),
+        Arc::new(rating_repository),
+        Arc::new(stats_repository),
+        Arc::new(achievement_repository),
+        storage,
+    );
 
     let protected_routes: Router<_> = Router::new()
         .route("/avatar", post(upload_avatar))
         .route("/my-missions", get(get_missions))
         .route("/profile", patch(update_profile))
         .route("/{id}", get(get_brawler_by_id))
+        .route("/{id}/stats", get(get_stats))
         .route_layer(axum::middleware::from_fn(auth));
 
     Router::new()
@@ -115,3 +131,16 @@ where
         Err(e) => (AxumStatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn get_stats<T>(
+    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    match user_case.get_stats(id).await {
+        Ok(stats) => (AxumStatusCode::OK, Json(stats)).into_response(),
+        Err(e) => (AxumStatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
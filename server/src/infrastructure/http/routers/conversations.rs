@@ -0,0 +1,308 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::{conversations::ConversationUseCase, privacy::PrivacyUseCase},
+    domain::{
+        entities::private_messages::MessagePageQuery,
+        value_objects::conversation_model::{
+            AddParticipantsModel, CreateConversationModel, MarkConversationReadModel,
+            RenameConversationModel, SendConversationMessageModel,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                conversations::ConversationPostgres, friendships::FriendshipPostgres,
+                privacy::PrivacyPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+        websocket::{handler::WSMessage, manager::ConnectionManager},
+    },
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct ConversationRouterState {
+    pub use_case: ConversationUseCase,
+    pub manager: Arc<ConnectionManager>,
+}
+
+impl ConversationRouterState {
+    /// Delivers the event to every participant's sessions over the global channel
+    async fn notify_participants(&self, conversation_id: i32, msg: WSMessage) {
+        if let Ok(ids) = self.use_case.participant_ids(conversation_id).await {
+            for id in ids {
+                self.manager.notify_user(id, msg.clone()).await;
+            }
+        }
+    }
+}
+
+pub async fn list_conversations(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.use_case.list(user_id).await {
+        Ok(conversations) => (StatusCode::OK, Json(conversations)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn create_conversation(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<CreateConversationModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .create_group(user_id, model.name, model.participant_ids)
+        .await
+    {
+        Ok(detail) => {
+            state
+                .notify_participants(
+                    detail.conversation.id,
+                    WSMessage {
+                        msg_type: "conversation_created".to_string(),
+                        data: serde_json::json!(detail),
+                    },
+                )
+                .await;
+            (StatusCode::CREATED, Json(detail)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_conversation(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.get_detail(conversation_id, user_id).await {
+        Ok(detail) => (StatusCode::OK, Json(detail)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn rename_conversation(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    Json(model): Json<RenameConversationModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .rename(conversation_id, user_id, model.name)
+        .await
+    {
+        Ok(conversation) => {
+            state
+                .notify_participants(
+                    conversation_id,
+                    WSMessage {
+                        msg_type: "conversation_updated".to_string(),
+                        data: serde_json::json!({
+                            "conversation_id": conversation_id,
+                            "name": conversation.name,
+                            "updated_by": user_id,
+                        }),
+                    },
+                )
+                .await;
+            (StatusCode::OK, Json(conversation)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add_participants(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    Json(model): Json<AddParticipantsModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .add_participants(conversation_id, user_id, model.brawler_ids)
+        .await
+    {
+        Ok(added) => {
+            if !added.is_empty() {
+                state
+                    .notify_participants(
+                        conversation_id,
+                        WSMessage {
+                            msg_type: "conversation_updated".to_string(),
+                            data: serde_json::json!({
+                                "conversation_id": conversation_id,
+                                "added_participant_ids": added,
+                                "updated_by": user_id,
+                            }),
+                        },
+                    )
+                    .await;
+            }
+            (StatusCode::OK, Json(added)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_participant(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path((conversation_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .remove_participant(conversation_id, user_id, brawler_id)
+        .await
+    {
+        Ok(_) => {
+            let ws_msg = WSMessage {
+                msg_type: "conversation_participant_removed".to_string(),
+                data: serde_json::json!({
+                    "conversation_id": conversation_id,
+                    "brawler_id": brawler_id,
+                    "removed_by": user_id,
+                }),
+            };
+            // The removed brawler is no longer a participant, so tell them directly
+            state.manager.notify_user(brawler_id, ws_msg.clone()).await;
+            state.notify_participants(conversation_id, ws_msg).await;
+            StatusCode::OK.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_messages(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    Query(page): Query<MessagePageQuery>,
+) -> impl IntoResponse {
+    let limit = page
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+
+    match state
+        .use_case
+        .get_messages(conversation_id, user_id, page.before_id, limit)
+        .await
+    {
+        Ok(messages) => (StatusCode::OK, Json(messages)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn send_message(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    Json(model): Json<SendConversationMessageModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .send_message(
+            conversation_id,
+            user_id,
+            model.content,
+            model.attachment_ids,
+        )
+        .await
+    {
+        Ok(message) => {
+            state
+                .notify_participants(
+                    conversation_id,
+                    WSMessage {
+                        msg_type: "conversation_message".to_string(),
+                        data: serde_json::json!(message),
+                    },
+                )
+                .await;
+            (StatusCode::CREATED, Json(message)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_read(
+    State(state): State<Arc<ConversationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    model: Option<Json<MarkConversationReadModel>>,
+) -> impl IntoResponse {
+    let Json(model) = model.unwrap_or_default();
+
+    match state
+        .use_case
+        .mark_read(conversation_id, user_id, model.last_read_message_id)
+        .await
+    {
+        Ok(marker) => {
+            if let Some(last_read_message_id) = marker {
+                state
+                    .notify_participants(
+                        conversation_id,
+                        WSMessage {
+                            msg_type: "conversation_read".to_string(),
+                            data: serde_json::json!({
+                                "conversation_id": conversation_id,
+                                "reader_id": user_id,
+                                "last_read_message_id": last_read_message_id,
+                            }),
+                        },
+                    )
+                    .await;
+            }
+            (StatusCode::OK, Json(marker)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+    let privacy_repo = Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool)));
+    let privacy_use_case = Arc::new(PrivacyUseCase::new(
+        privacy_repo.clone(),
+        Arc::new(FriendshipPostgres::new(Arc::clone(&db_pool))),
+    ));
+    let use_case = ConversationUseCase::new(
+        Arc::new(ConversationPostgres::new(Arc::clone(&db_pool))),
+        privacy_repo,
+        privacy_use_case,
+    );
+
+    let state = Arc::new(ConversationRouterState { use_case, manager });
+
+    Router::new()
+        .route("/", get(list_conversations))
+        .route("/", post(create_conversation))
+        .route("/{conversation_id}", get(get_conversation))
+        .route("/{conversation_id}", patch(rename_conversation))
+        .route("/{conversation_id}/participants", post(add_participants))
+        .route(
+            "/{conversation_id}/participants/{brawler_id}",
+            delete(remove_participant),
+        )
+        .route("/{conversation_id}/messages", get(get_messages))
+        .route("/{conversation_id}/messages", post(send_message))
+        .route("/{conversation_id}/read", post(mark_read))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(state)
+}
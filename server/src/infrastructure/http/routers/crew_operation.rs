@@ -6,24 +6,36 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, get, post},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
-    application::use_cases::crew_operation::CrewOperationUseCase,
+    application::use_cases::{
+        achievements::AchievementUseCase, crew_operation::CrewOperationUseCase,
+        mission_media::MissionMediaUseCase, mission_transcript::MissionTranscriptUseCase,
+        notification_service::NotificationService, webhook_service::WebhookService,
+    },
     domain::{
-        entities::notifications::AddNotificationEntity,
         repositories::{
-            crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-            notifications::NotificationRepository,
+            blob_storage::BlobStorage, crew_operation::CrewOperationRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            achievements::AchievementEvents,
+            attendance_statuses::{AttendanceStatuses, UpdateAttendanceModel},
+            notification_events::NotificationEvent,
+            webhook_events::WebhookEvent,
         },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
-                notifications::NotificationPostgres,
+                achievements::AchievementPostgres, crew_operation::CrewOperationPostgres,
+                mission_comment::MissionCommentPostgres, mission_media::MissionMediaPostgres,
+                mission_ratings::MissionRatingPostgres,
+                mission_transcripts::MissionTranscriptPostgres,
+                mission_viewing::MissionViewingPostgres, privacy::PrivacyPostgres,
             },
         },
         http::middlewares::auth::auth,
@@ -38,8 +50,9 @@ where
 {
     pub use_case: CrewOperationUseCase<T1, T2>,
     pub manager: Arc<ConnectionManager>,
-    pub viewing_repository: Arc<T2>,
-    pub notification_repo: Arc<dyn NotificationRepository>,
+    pub notification_service: Arc<NotificationService>,
+    pub webhook_service: Arc<WebhookService>,
+    pub achievement_use_case: Arc<AchievementUseCase>,
 }
 
 pub async fn join<T1, T2>(
@@ -53,34 +66,25 @@ where
 {
     match state.use_case.join(mission_id, user_id).await {
         Ok(_) => {
-            if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                let ws_msg = WSMessage {
-                    msg_type: "new_crew_joined".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "brawler_id": user_id
-                    }),
-                };
-
-                // 1. Notify EVERYONE (for public list real-time update)
-                state.manager.broadcast_all(ws_msg.clone()).await;
-
-                // 2. Broadcast to the specific room (for in-room UI update)
-                state.manager.broadcast(mission_id, ws_msg.clone()).await;
-
-                // 3. Notify the CHIEF globally and save to DB
-                let _ = state
-                    .notification_repo
-                    .add(AddNotificationEntity {
-                        brawler_id: mission.chief_id,
-                        type_: "new_crew_joined".to_string(),
-                        content: format!("A new crew member joined your mission: {}", mission.name),
-                        related_id: Some(mission_id),
-                    })
-                    .await;
-                state.manager.notify_user(mission.chief_id, ws_msg).await;
-            }
+            let _ = state
+                .achievement_use_case
+                .evaluate(user_id, AchievementEvents::CrewJoined)
+                .await;
+
+            state
+                .notification_service
+                .notify(NotificationEvent::CrewJoined {
+                    mission_id,
+                    brawler_id: user_id,
+                })
+                .await;
+            state
+                .webhook_service
+                .emit(WebhookEvent::CrewJoined {
+                    mission_id,
+                    brawler_id: user_id,
+                })
+                .await;
             (
                 StatusCode::OK,
                 format!("Join Mission_id:{} completed", mission_id),
@@ -102,34 +106,20 @@ where
 {
     match state.use_case.leave(mission_id, user_id).await {
         Ok(_) => {
-            if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                let ws_msg = WSMessage {
-                    msg_type: "crew_left".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "brawler_id": user_id
-                    }),
-                };
-
-                // 1. Notify EVERYONE (for public list real-time update)
-                state.manager.broadcast_all(ws_msg.clone()).await;
-
-                // 2. Broadcast to the specific room (for in-room UI update)
-                state.manager.broadcast(mission_id, ws_msg.clone()).await;
-
-                // 3. PERSIST FOR CHIEF
-                let _ = state
-                    .notification_repo
-                    .add(AddNotificationEntity {
-                        brawler_id: mission.chief_id,
-                        type_: "crew_left".to_string(),
-                        content: format!("A crew member left your mission: {}", mission.name),
-                        related_id: Some(mission_id),
-                    })
-                    .await;
-                state.manager.notify_user(mission.chief_id, ws_msg).await;
-            }
+            state
+                .notification_service
+                .notify(NotificationEvent::CrewLeft {
+                    mission_id,
+                    brawler_id: user_id,
+                })
+                .await;
+            state
+                .webhook_service
+                .emit(WebhookEvent::CrewLeft {
+                    mission_id,
+                    brawler_id: user_id,
+                })
+                .await;
             (
                 StatusCode::OK,
                 format!("Leave Mission_id:{} completed", mission_id),
@@ -156,28 +146,176 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+pub async fn check_in<T1, T2>(
+    State(state): State<Arc<CrewState<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync + 'static,
+{
+    match state.use_case.check_in(mission_id, user_id).await {
+        Ok(_) => {
+            state
+                .manager
+                .broadcast(
+                    mission_id,
+                    attendance_ws_message(mission_id, user_id, AttendanceStatuses::Present),
+                )
+                .await;
+            (
+                StatusCode::OK,
+                format!("Check-in Mission_id:{} completed", mission_id),
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn update_attendance<T1, T2>(
+    State(state): State<Arc<CrewState<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Json(model): Json<UpdateAttendanceModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync + 'static,
+{
+    match state
+        .use_case
+        .update_attendance(mission_id, user_id, brawler_id, model.status.clone())
+        .await
+    {
+        Ok(_) => {
+            state
+                .manager
+                .broadcast(
+                    mission_id,
+                    attendance_ws_message(mission_id, brawler_id, model.status.clone()),
+                )
+                .await;
+
+            if model.status == AttendanceStatuses::NoShow {
+                state
+                    .notification_service
+                    .notify(NotificationEvent::MarkedNoShow {
+                        mission_id,
+                        brawler_ids: vec![brawler_id],
+                    })
+                    .await;
+            }
+            StatusCode::OK.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_no_shows<T1, T2>(
+    State(state): State<Arc<CrewState<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync + 'static,
+{
+    match state.use_case.mark_no_shows(mission_id, user_id).await {
+        Ok(marked) => {
+            for brawler_id in &marked {
+                state
+                    .manager
+                    .broadcast(
+                        mission_id,
+                        attendance_ws_message(mission_id, *brawler_id, AttendanceStatuses::NoShow),
+                    )
+                    .await;
+            }
+            state
+                .notification_service
+                .notify(NotificationEvent::MarkedNoShow {
+                    mission_id,
+                    brawler_ids: marked.clone(),
+                })
+                .await;
+            (StatusCode::OK, Json(marked)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+fn attendance_ws_message(
+    mission_id: i32,
+    brawler_id: i32,
+    status: AttendanceStatuses,
+) -> WSMessage {
+    WSMessage {
+        msg_type: "attendance_updated".to_string(),
+        data: serde_json::json!({
+            "mission_id": mission_id,
+            "brawler_id": brawler_id,
+            "attendance_status": status.to_string(),
+        }),
+    }
+}
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    manager: Arc<ConnectionManager>,
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+    storage: Arc<dyn BlobStorage>,
+) -> Router {
     let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
     let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let viewing_repository_arc = Arc::new(viewing_repository);
-    let notification_repo = Arc::new(NotificationPostgres::new(Arc::clone(&db_pool)));
+    let achievement_use_case = Arc::new(AchievementUseCase::new(
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&notification_service),
+    ));
+
+    let rating_repository = Arc::new(MissionRatingPostgres::new(Arc::clone(&db_pool)));
+    let transcript_use_case = Arc::new(MissionTranscriptUseCase::new(
+        Arc::new(MissionCommentPostgres::new(Arc::clone(&db_pool))),
+        viewing_repository_arc.clone(),
+        Arc::new(MissionTranscriptPostgres::new(Arc::clone(&db_pool))),
+    ));
+    let media_use_case = Arc::new(MissionMediaUseCase::new(
+        Arc::new(MissionMediaPostgres::new(Arc::clone(&db_pool))),
+        viewing_repository_arc.clone(),
+        storage,
+    ));
 
     let use_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
-        Arc::clone(&viewing_repository_arc),
+        viewing_repository_arc,
+        rating_repository,
+        Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
+        transcript_use_case,
+        media_use_case,
     );
 
     let state = Arc::new(CrewState {
         use_case,
         manager,
-        viewing_repository: viewing_repository_arc,
-        notification_repo,
+        notification_service,
+        webhook_service,
+        achievement_use_case,
     });
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
         .route("/my-missions", get(get_my_joined_missions))
+        .route("/check-in/{mission_id}", post(check_in))
+        .route(
+            "/attendance/{mission_id}/{brawler_id}",
+            patch(update_attendance),
+        )
+        .route("/no-shows/{mission_id}", patch(mark_no_shows))
         .route_layer(middleware::from_fn(auth))
         .with_state(state)
 }
@@ -9,14 +9,18 @@ use axum::{
 use std::sync::Arc;
 
 use crate::{
-    application::use_cases::{friendships::FriendshipUseCase, notifications::NotificationUseCase},
+    application::use_cases::{
+        achievements::AchievementUseCase, friendships::FriendshipUseCase,
+        notification_service::NotificationService,
+    },
     domain::repositories::brawlers::BrawlerRepository,
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                brawlers::BrawlerPostgres, friendships::FriendshipPostgres,
-                notifications::NotificationPostgres,
+                achievements::AchievementPostgres, brawlers::BrawlerPostgres,
+                friendships::FriendshipPostgres, mission_viewing::MissionViewingPostgres,
+                privacy::PrivacyPostgres,
             },
         },
         http::middlewares::auth::auth,
@@ -86,8 +90,13 @@ pub async fn get_pending(
 
 pub async fn get_online_users(
     State(state): State<Arc<FriendshipRouterState>>,
+    Extension(user_id): Extension<i32>,
 ) -> Json<serde_json::Value> {
     let online_ids = state.ws_manager.get_online_users().await;
+    let online_ids = match state.use_case.filter_visible(user_id, online_ids).await {
+        Ok(ids) => ids,
+        Err(e) => return Json(serde_json::json!({ "error": e.to_string() })),
+    };
     match state.brawler_repo.find_many(online_ids).await {
         Ok(users) => Json(serde_json::json!(users)),
         Err(e) => Json(serde_json::json!({ "error": e.to_string() })),
@@ -122,18 +131,26 @@ pub async fn get_friends(
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    manager: Arc<ConnectionManager>,
+    notification_service: Arc<NotificationService>,
+) -> Router {
     let friendship_repo = Arc::new(FriendshipPostgres::new(Arc::clone(&db_pool)));
-    let notification_repo = Arc::new(NotificationPostgres::new(Arc::clone(&db_pool)));
     let brawler_repo = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
 
-    let notification_use_case = Arc::new(NotificationUseCase::new(notification_repo));
+    let achievement_use_case = Arc::new(AchievementUseCase::new(
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&notification_service),
+    ));
 
     let use_case = FriendshipUseCase::new(
         friendship_repo,
         Arc::clone(&brawler_repo) as Arc<dyn BrawlerRepository + Send + Sync>,
-        notification_use_case,
-        Arc::clone(&manager),
+        notification_service,
+        achievement_use_case,
+        Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
     );
 
     let state = Arc::new(FriendshipRouterState {
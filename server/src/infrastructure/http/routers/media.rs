@@ -0,0 +1,45 @@
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use std::sync::Arc;
+
+use crate::domain::repositories::blob_storage::BlobStorage;
+
+/// Folders holding processed public images; attachments are never served from here
+const PUBLIC_MEDIA_PREFIXES: [&str; 1] = ["avatars/"];
+
+pub async fn get_media(
+    State(storage): State<Arc<dyn BlobStorage>>,
+    Path(key): Path<String>,
+) -> impl IntoResponse {
+    if !PUBLIC_MEDIA_PREFIXES
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+        || !key.ends_with(".webp")
+    {
+        return (StatusCode::NOT_FOUND, "Media not found").into_response();
+    }
+
+    match storage.get(&key).await {
+        Ok(bytes) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "image/webp"),
+                (header::CACHE_CONTROL, "public, max-age=86400"),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Err(_) => (StatusCode::NOT_FOUND, "Media not found").into_response(),
+    }
+}
+
+pub fn routes(storage: Arc<dyn BlobStorage>) -> Router {
+    Router::new()
+        .route("/{*key}", get(get_media))
+        .with_state(storage)
+}
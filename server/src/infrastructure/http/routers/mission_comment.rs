@@ -1,16 +1,23 @@
-use crate::domain::entities::notifications::AddNotificationEntity;
 use crate::{
-    application::use_cases::mission_comment::MissionCommentUseCase,
-    domain::repositories::{
-        mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+    application::use_cases::{
+        achievements::AchievementUseCase, mission_comment::MissionCommentUseCase,
+        mission_transcript::MissionTranscriptUseCase, notification_service::NotificationService,
+        webhook_service::WebhookService,
+    },
+    domain::value_objects::{
+        mission_comment_model::{
+            AddMissionCommentModel, CommentPageQuery, EditMissionCommentModel, ToggleReactionModel,
+            UpdateReadMarkerModel,
+        },
+        mission_transcript_model::{TranscriptExportQuery, TranscriptFormats},
     },
-    domain::value_objects::mission_comment_model::AddMissionCommentModel,
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                mission_comment::MissionCommentPostgres, mission_viewing::MissionViewingPostgres,
-                notifications::NotificationPostgres,
+                achievements::AchievementPostgres, mission_comment::MissionCommentPostgres,
+                mission_transcripts::MissionTranscriptPostgres,
+                mission_viewing::MissionViewingPostgres,
             },
         },
         http::middlewares::auth::auth,
@@ -19,46 +26,85 @@ use crate::{
 };
 use axum::{
     Extension, Json, Router,
-    extract::{Path, State},
-    http::StatusCode,
+    body::Body,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
     middleware,
     response::IntoResponse,
-    routing::{delete, get, post},
+    routing::{delete, get, patch, post, put},
 };
 use std::sync::Arc;
 
 pub struct CommentState {
     pub use_case: MissionCommentUseCase<MissionCommentPostgres, MissionViewingPostgres>,
     pub manager: Arc<ConnectionManager>,
-    pub notification_repo: Arc<dyn NotificationRepository>,
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    manager: Arc<ConnectionManager>,
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+) -> Router {
     let repository = MissionCommentPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let notification_repo = Arc::new(NotificationPostgres::new(Arc::clone(&db_pool)));
-    let use_case =
-        MissionCommentUseCase::new(Arc::new(repository), Arc::new(mission_viewing_repository));
+    let achievement_use_case = Arc::new(AchievementUseCase::new(
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&notification_service),
+    ));
+    let repository = Arc::new(repository);
+    let mission_viewing_repository = Arc::new(mission_viewing_repository);
+    let transcript_use_case = Arc::new(MissionTranscriptUseCase::new(
+        repository.clone(),
+        mission_viewing_repository.clone(),
+        Arc::new(MissionTranscriptPostgres::new(Arc::clone(&db_pool))),
+    ));
+    let use_case = MissionCommentUseCase::new(
+        repository,
+        mission_viewing_repository,
+        Arc::clone(&transcript_use_case),
+        Arc::clone(&manager),
+        notification_service,
+        webhook_service,
+        achievement_use_case,
+    );
 
-    let state = Arc::new(CommentState {
-        use_case,
-        manager,
-        notification_repo,
-    });
+    let state = Arc::new(CommentState { use_case, manager });
 
     Router::new()
         .route("/{mission_id}", get(get_comments))
         .route("/{mission_id}", post(add_comment))
         .route("/{mission_id}", delete(clear_comments))
+        .route("/{mission_id}/read", put(update_read_marker))
+        .route("/{mission_id}/read-markers", get(get_read_markers))
+        .route("/{mission_id}/pinned", get(get_announcements))
+        .route("/{mission_id}/export", get(export_transcript))
+        .route("/{mission_id}/archives", get(get_archives))
+        .route("/{mission_id}/{comment_id}", patch(edit_comment))
+        .route("/{mission_id}/{comment_id}", delete(delete_comment))
+        .route("/{mission_id}/{comment_id}/revisions", get(get_revisions))
+        .route("/{mission_id}/{comment_id}/pin", post(pin_comment))
+        .route("/{mission_id}/{comment_id}/pin", delete(unpin_comment))
+        .route(
+            "/{mission_id}/{comment_id}/reactions",
+            post(toggle_reaction),
+        )
         .route_layer(middleware::from_fn(auth))
         .with_state(state)
 }
 
 async fn get_comments(
     State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Query(query): Query<CommentPageQuery>,
 ) -> impl IntoResponse {
-    match state.use_case.get_comments(mission_id).await {
+    match state
+        .use_case
+        .get_comments(mission_id, user_id, query)
+        .await
+    {
         Ok(comments) => (StatusCode::OK, Json(comments)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -72,90 +118,16 @@ async fn add_comment(
 ) -> impl IntoResponse {
     match state
         .use_case
-        .add_comment(mission_id, user_id, &payload.content)
+        .add_comment(
+            mission_id,
+            user_id,
+            &payload.content,
+            payload.parent_id,
+            payload.attachment_ids,
+        )
         .await
     {
-        Ok(comment) => {
-            // 1. BROADCAST NEW COMMENT VIA ROOM-BASED WEBSOCKET (for people currently in the chat room)
-            let ws_msg = WSMessage {
-                msg_type: "new_comment".to_string(),
-                data: serde_json::to_value(&comment).unwrap_or_default(),
-            };
-            state.manager.broadcast(mission_id, ws_msg.clone()).await;
-
-            // 2. SEND GLOBAL NOTIFICATIONS (for people not currently in the room)
-            if let Ok(mission) = state
-                .use_case
-                .mission_viewing_repository
-                .get_one(mission_id)
-                .await
-            {
-                let notification = WSMessage {
-                    msg_type: "new_chat_message".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "sender_name": comment.brawler_display_name,
-                        "content": comment.content,
-                    }),
-                };
-
-                tracing::info!("Sending global chat notification: {:?}", notification);
-
-                // Notify Chief (if not the sender)
-                if mission.chief_id != user_id {
-                    let _ = state
-                        .notification_repo
-                        .add(AddNotificationEntity {
-                            brawler_id: mission.chief_id,
-                            type_: "new_chat_message".to_string(),
-                            content: format!(
-                                "[{}] {}: \"{}\"",
-                                mission.name, comment.brawler_display_name, comment.content
-                            ),
-                            related_id: Some(mission_id),
-                        })
-                        .await;
-
-                    state
-                        .manager
-                        .notify_user(mission.chief_id, notification.clone())
-                        .await;
-                }
-
-                // Notify all crew members (if not the sender)
-                if let Ok(crew) = state
-                    .use_case
-                    .mission_viewing_repository
-                    .get_crew(mission_id)
-                    .await
-                {
-                    for member in crew {
-                        if member.id != user_id {
-                            let _ = state
-                                .notification_repo
-                                .add(AddNotificationEntity {
-                                    brawler_id: member.id,
-                                    type_: "new_chat_message".to_string(),
-                                    content: format!(
-                                        "[{}] {}: \"{}\"",
-                                        mission.name, comment.brawler_display_name, comment.content
-                                    ),
-                                    related_id: Some(mission_id),
-                                })
-                                .await;
-
-                            state
-                                .manager
-                                .notify_user(member.id, notification.clone())
-                                .await;
-                        }
-                    }
-                }
-            }
-
-            (StatusCode::CREATED, Json(comment)).into_response()
-        }
+        Ok(comment) => (StatusCode::CREATED, Json(comment)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
@@ -179,3 +151,242 @@ async fn clear_comments(
         Err(e) => (StatusCode::FORBIDDEN, e.to_string()).into_response(),
     }
 }
+
+async fn edit_comment(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+    Json(payload): Json<EditMissionCommentModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .edit_comment(mission_id, comment_id, user_id, &payload.content)
+        .await
+    {
+        Ok(comment) => {
+            let ws_msg = WSMessage {
+                msg_type: "comment_edited".to_string(),
+                data: serde_json::to_value(&comment).unwrap_or_default(),
+            };
+            state.manager.broadcast(mission_id, ws_msg).await;
+
+            (StatusCode::OK, Json(comment)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn delete_comment(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .delete_comment(mission_id, comment_id, user_id)
+        .await
+    {
+        Ok(_) => {
+            let ws_msg = WSMessage {
+                msg_type: "comment_deleted".to_string(),
+                data: serde_json::json!({
+                    "mission_id": mission_id,
+                    "comment_id": comment_id,
+                    "deleted_by": user_id,
+                }),
+            };
+            state.manager.broadcast(mission_id, ws_msg).await;
+
+            (StatusCode::OK, "Message deleted").into_response()
+        }
+        Err(e) => (StatusCode::FORBIDDEN, e.to_string()).into_response(),
+    }
+}
+
+async fn get_revisions(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .get_revisions(mission_id, user_id, comment_id)
+        .await
+    {
+        Ok(revisions) => (StatusCode::OK, Json(revisions)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn toggle_reaction(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+    Json(payload): Json<ToggleReactionModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .toggle_reaction(mission_id, comment_id, user_id, &payload.emoji)
+        .await
+    {
+        Ok((added, reactions)) => {
+            // "reacted_by_me" is per viewer, so the room only gets who toggled what plus the counts
+            let ws_msg = WSMessage {
+                msg_type: "comment_reaction_updated".to_string(),
+                data: serde_json::json!({
+                    "mission_id": mission_id,
+                    "comment_id": comment_id,
+                    "brawler_id": user_id,
+                    "emoji": payload.emoji.trim(),
+                    "added": added,
+                    "counts": reactions
+                        .iter()
+                        .map(|r| serde_json::json!({ "emoji": r.emoji, "count": r.count }))
+                        .collect::<Vec<_>>(),
+                }),
+            };
+            state.manager.broadcast(mission_id, ws_msg).await;
+
+            (StatusCode::OK, Json(reactions)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn update_read_marker(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(payload): Json<UpdateReadMarkerModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .update_read_marker(mission_id, user_id, payload.last_read_comment_id)
+        .await
+    {
+        Ok(marker) => {
+            let ws_msg = WSMessage {
+                msg_type: "read_marker_updated".to_string(),
+                data: serde_json::to_value(&marker).unwrap_or_default(),
+            };
+            state.manager.broadcast(mission_id, ws_msg).await;
+
+            (StatusCode::OK, Json(marker)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn get_read_markers(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.get_read_markers(mission_id, user_id).await {
+        Ok(markers) => (StatusCode::OK, Json(markers)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn pin_comment(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .pin_comment(mission_id, comment_id, user_id)
+        .await
+    {
+        Ok(comment) => (StatusCode::OK, Json(comment)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn unpin_comment(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .unpin_comment(mission_id, comment_id, user_id)
+        .await
+    {
+        Ok(comment) => {
+            let ws_msg = WSMessage {
+                msg_type: "comment_unpinned".to_string(),
+                data: serde_json::to_value(&comment).unwrap_or_default(),
+            };
+            state.manager.broadcast(mission_id, ws_msg).await;
+
+            (StatusCode::OK, Json(comment)).into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn get_announcements(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.get_announcements(mission_id, user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn export_transcript(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<TranscriptExportQuery>,
+) -> impl IntoResponse {
+    let format = match query.format.as_deref().map(str::parse::<TranscriptFormats>) {
+        None => TranscriptFormats::default(),
+        Some(Ok(format)) => format,
+        Some(Err(e)) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+
+    match state
+        .use_case
+        .transcript_use_case
+        .export(mission_id, user_id, format)
+        .await
+    {
+        Ok(chunks) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, format.content_type().to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"mission-{}-transcript.{}\"",
+                        mission_id,
+                        format.extension()
+                    ),
+                ),
+            ],
+            Body::from_stream(chunks),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::FORBIDDEN, e.to_string()).into_response(),
+    }
+}
+
+async fn get_archives(
+    State(state): State<Arc<CommentState>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .transcript_use_case
+        .get_archives(mission_id, user_id)
+        .await
+    {
+        Ok(archives) => (StatusCode::OK, Json(archives)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
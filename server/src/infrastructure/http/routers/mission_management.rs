@@ -9,33 +9,38 @@ use axum::{
     routing::{delete, patch, post},
 };
 
-use crate::infrastructure::websocket::handler::WSMessage;
 use crate::{
-    application::use_cases::mission_management::MissionManagementUseCase,
+    application::use_cases::{
+        mission_management::MissionManagementUseCase, mission_media::MissionMediaUseCase,
+        mission_transcript::MissionTranscriptUseCase, notification_service::NotificationService,
+        webhook_service::WebhookService,
+    },
     domain::{
-        entities::notifications::AddNotificationEntity,
-        repositories::{
-            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        repositories::{blob_storage::BlobStorage, mission_viewing::MissionViewingRepository},
+        value_objects::{
+            mission_model::{AddMissionModel, EditMissionModel},
+            notification_events::NotificationEvent,
+            webhook_events::WebhookEvent,
         },
-        value_objects::mission_model::{AddMissionModel, EditMissionModel},
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                mission_management::MissionManagementPostgres,
-                mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres,
+                mission_comment::MissionCommentPostgres,
+                mission_management::MissionManagementPostgres, mission_media::MissionMediaPostgres,
+                mission_transcripts::MissionTranscriptPostgres,
+                mission_viewing::MissionViewingPostgres, scheduled_jobs::ScheduledJobPostgres,
             },
         },
         http::middlewares::auth::auth,
-        websocket::manager::ConnectionManager,
     },
 };
 
 pub struct MissionManagementState {
     pub use_case: MissionManagementUseCase<MissionManagementPostgres, MissionViewingPostgres>,
-    pub manager: Arc<ConnectionManager>,
-    pub notification_repo: Arc<dyn NotificationRepository>,
+    pub notification_service: Arc<NotificationService>,
+    pub webhook_service: Arc<WebhookService>,
 }
 
 pub async fn add(
@@ -45,7 +50,13 @@ pub async fn add(
 ) -> impl IntoResponse {
     println!("DEBUG: Backend received payload: {:?}", model);
     match state.use_case.add(user_id, model).await {
-        Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
+        Ok(mission_id) => {
+            state
+                .webhook_service
+                .emit(WebhookEvent::MissionCreated { mission_id })
+                .await;
+            (StatusCode::CREATED, mission_id.to_string()).into_response()
+        }
 
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -88,44 +99,21 @@ pub async fn remove(
     match state.use_case.remove(mission_id, user_id).await {
         Ok(_) => {
             if let (Ok(mission), Ok(crew)) = (mission_info, crew_info) {
-                let ws_msg = WSMessage {
-                    msg_type: "mission_deleted".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                    }),
-                };
-
                 tracing::info!(
                     "Mission {} deleted, notifying {} crew members and chief",
                     mission_id,
                     crew.len()
                 );
 
-                // Notify all crew members globally and save to DB
-                for member in crew {
-                    if member.id != user_id {
-                        // Save notification to DB
-                        let _ = state
-                            .notification_repo
-                            .add(AddNotificationEntity {
-                                brawler_id: member.id,
-                                type_: "mission_deleted".to_string(),
-                                content: format!(
-                                    "Mission '{}' has been removed by the chief.",
-                                    mission.name
-                                ),
-                                related_id: Some(mission_id),
-                            })
-                            .await;
-
-                        // Don't notify the chief who deleted it
-                        state.manager.notify_user(member.id, ws_msg.clone()).await;
-                    }
-                }
-
-                // Broadcast to the room (for people currently in chat)
-                state.manager.broadcast(mission_id, ws_msg).await;
+                state
+                    .notification_service
+                    .notify(NotificationEvent::MissionDeleted {
+                        mission_id,
+                        mission_name: mission.name,
+                        deleted_by: user_id,
+                        crew_ids: crew.iter().map(|member| member.id).collect(),
+                    })
+                    .await;
             }
 
             (
@@ -139,17 +127,38 @@ pub async fn remove(
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+    storage: Arc<dyn BlobStorage>,
+) -> Router {
     let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let notification_repo = Arc::new(NotificationPostgres::new(Arc::clone(&db_pool)));
-    let use_case =
-        MissionManagementUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
+    let scheduled_job_repository = Arc::new(ScheduledJobPostgres::new(Arc::clone(&db_pool)));
+    let viewing_repositiory = Arc::new(viewing_repositiory);
+    let transcript_use_case = Arc::new(MissionTranscriptUseCase::new(
+        Arc::new(MissionCommentPostgres::new(Arc::clone(&db_pool))),
+        viewing_repositiory.clone(),
+        Arc::new(MissionTranscriptPostgres::new(Arc::clone(&db_pool))),
+    ));
+    let media_use_case = Arc::new(MissionMediaUseCase::new(
+        Arc::new(MissionMediaPostgres::new(Arc::clone(&db_pool))),
+        viewing_repositiory.clone(),
+        storage,
+    ));
+    let use_case = MissionManagementUseCase::new(
+        Arc::new(mission_repository),
+        viewing_repositiory,
+        scheduled_job_repository,
+        transcript_use_case,
+        media_use_case,
+    );
 
     let state = Arc::new(MissionManagementState {
         use_case,
-        manager,
-        notification_repo,
+        notification_service,
+        webhook_service,
     });
 
     Router::new()
@@ -0,0 +1,98 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::mission_media::MissionMediaUseCase,
+    domain::{
+        repositories::blob_storage::BlobStorage, value_objects::uploaded_img::UploadBase64Img,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_media::MissionMediaPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn upload_cover(
+    State(use_case): State<Arc<MissionMediaUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadBase64Img>,
+) -> impl IntoResponse {
+    match use_case
+        .upload_cover(mission_id, user_id, model.base64_string)
+        .await
+    {
+        Ok(upload_img) => (StatusCode::OK, Json(upload_img)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_gallery(
+    State(use_case): State<Arc<MissionMediaUseCase>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get_gallery(mission_id).await {
+        Ok(items) => (StatusCode::OK, Json(items)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add_gallery_item(
+    State(use_case): State<Arc<MissionMediaUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadBase64Img>,
+) -> impl IntoResponse {
+    match use_case
+        .add_gallery_item(mission_id, user_id, model.base64_string)
+        .await
+    {
+        Ok(item) => (StatusCode::CREATED, Json(item)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_gallery_item(
+    State(use_case): State<Arc<MissionMediaUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, item_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match use_case
+        .remove_gallery_item(mission_id, item_id, user_id)
+        .await
+    {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, storage: Arc<dyn BlobStorage>) -> Router {
+    let use_case = Arc::new(MissionMediaUseCase::new(
+        Arc::new(MissionMediaPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        storage,
+    ));
+
+    Router::new()
+        .route("/{mission_id}/cover", put(upload_cover))
+        .route("/{mission_id}/gallery", get(get_gallery))
+        .route("/{mission_id}/gallery", post(add_gallery_item))
+        .route(
+            "/{mission_id}/gallery/{item_id}",
+            delete(remove_gallery_item),
+        )
+        .route_layer(middleware::from_fn(auth))
+        .with_state(use_case)
+}
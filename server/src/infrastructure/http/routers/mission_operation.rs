@@ -10,24 +10,23 @@ use axum::{
 };
 
 use crate::{
-    application::use_cases::mission_operation::MissionOperationUseCase,
-    domain::{
-        entities::notifications::AddNotificationEntity,
-        repositories::{
-            mission_operation::MissionOperationRepository,
-            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
-        },
+    application::use_cases::{
+        achievements::AchievementUseCase, mission_events::MissionEventService,
+        mission_operation::MissionOperationUseCase, notification_service::NotificationService,
+        webhook_service::WebhookService,
+    },
+    domain::repositories::{
+        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                mission_operation::MissionOperationPostgres,
-                mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres,
+                achievements::AchievementPostgres, mission_operation::MissionOperationPostgres,
+                mission_viewing::MissionViewingPostgres,
             },
         },
         http::middlewares::auth::auth,
-        websocket::{handler::WSMessage, manager::ConnectionManager},
     },
 };
 
@@ -37,9 +36,6 @@ where
     T2: MissionViewingRepository + Send + Sync,
 {
     pub use_case: MissionOperationUseCase<T1, T2>,
-    pub manager: Arc<ConnectionManager>,
-    pub viewing_repository: Arc<T2>,
-    pub notification_repo: Arc<dyn NotificationRepository>,
 }
 
 pub async fn in_progress<T1, T2>(
@@ -52,42 +48,7 @@ where
     T2: MissionViewingRepository + Send + Sync + 'static,
 {
     match state.use_case.in_progress(mission_id, user_id).await {
-        Ok(_) => {
-            // 1. Notify all crew members globally (for toast)
-            if let Ok(crew) = state.viewing_repository.get_crew(mission_id).await {
-                if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                    let ws_msg = WSMessage {
-                        msg_type: "mission_started".to_string(),
-                        data: serde_json::json!({
-                            "mission_id": mission_id,
-                            "mission_name": mission.name,
-                            "new_status": "InProgress"
-                        }),
-                    };
-                    for member in crew {
-                        // Save notification to DB for each member
-                        let _ = state
-                            .notification_repo
-                            .add(AddNotificationEntity {
-                                brawler_id: member.id,
-                                type_: "mission_started".to_string(),
-                                content: format!("Mission '{}' has started!", mission.name),
-                                related_id: Some(mission_id),
-                            })
-                            .await;
-
-                        state.manager.notify_user(member.id, ws_msg.clone()).await;
-                    }
-
-                    // 2. Broadcast to EVERYONE (for public list/manager/dashboard real-time update)
-                    state.manager.broadcast_all(ws_msg.clone()).await;
-
-                    // 3. Broadcast to the specific room (for in-room UI update)
-                    state.manager.broadcast(mission_id, ws_msg).await;
-                }
-            }
-            (StatusCode::OK, mission_id.to_string()).into_response()
-        }
+        Ok(_) => (StatusCode::OK, mission_id.to_string()).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
@@ -102,61 +63,7 @@ where
     T2: MissionViewingRepository + Send + Sync + 'static,
 {
     match state.use_case.to_completed(mission_id, user_id).await {
-        Ok(mission_id) => {
-            // Broadcast completion to the room
-            if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                let ws_msg = WSMessage {
-                    msg_type: "mission_completed".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "new_status": "Completed"
-                    }),
-                };
-                // 1. Broadcast to EVERYONE (Dashboard/Manager real-time update)
-                state.manager.broadcast_all(ws_msg.clone()).await;
-
-                // 2. Broadcast to the specific room (In-room UI update)
-                state.manager.broadcast(mission_id, ws_msg.clone()).await;
-
-                // 3. PERSIST FOR CHIEF & CREW
-                let crew = state
-                    .viewing_repository
-                    .get_crew(mission_id)
-                    .await
-                    .unwrap_or_default();
-
-                // For Chief
-                let _ = state
-                    .notification_repo
-                    .add(AddNotificationEntity {
-                        brawler_id: mission.chief_id,
-                        type_: "mission_completed".to_string(),
-                        content: format!("Mission '{}' has been COMPLETED!", mission.name),
-                        related_id: Some(mission_id),
-                    })
-                    .await;
-
-                // For Crew members
-                for member in crew {
-                    if member.id != mission.chief_id {
-                        let _ = state
-                            .notification_repo
-                            .add(AddNotificationEntity {
-                                brawler_id: member.id,
-                                type_: "mission_completed".to_string(),
-                                content: format!("Mission '{}' has been COMPLETED!", mission.name),
-                                related_id: Some(mission_id),
-                            })
-                            .await;
-
-                        // Also notify via Global WS for real-time toast
-                        state.manager.notify_user(member.id, ws_msg.clone()).await;
-                    }
-                }
-            }
-            (StatusCode::OK, mission_id.to_string()).into_response()
-        }
+        Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
@@ -171,61 +78,7 @@ where
     T2: MissionViewingRepository + Send + Sync + 'static,
 {
     match state.use_case.to_failed(mission_id, user_id).await {
-        Ok(mission_id) => {
-            // Broadcast failure to the room
-            if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                let ws_msg = WSMessage {
-                    msg_type: "mission_failed".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "new_status": "Failed"
-                    }),
-                };
-                // 1. Broadcast to EVERYONE (Dashboard/Manager real-time update)
-                state.manager.broadcast_all(ws_msg.clone()).await;
-
-                // 2. Broadcast to the specific room (In-room UI update)
-                state.manager.broadcast(mission_id, ws_msg.clone()).await;
-
-                // 3. PERSIST FOR CHIEF & CREW
-                let crew = state
-                    .viewing_repository
-                    .get_crew(mission_id)
-                    .await
-                    .unwrap_or_default();
-
-                // For Chief
-                let _ = state
-                    .notification_repo
-                    .add(AddNotificationEntity {
-                        brawler_id: mission.chief_id,
-                        type_: "mission_failed".to_string(),
-                        content: format!("Mission '{}' has FAILED.", mission.name),
-                        related_id: Some(mission_id),
-                    })
-                    .await;
-
-                // For Crew members
-                for member in crew {
-                    if member.id != mission.chief_id {
-                        let _ = state
-                            .notification_repo
-                            .add(AddNotificationEntity {
-                                brawler_id: member.id,
-                                type_: "mission_failed".to_string(),
-                                content: format!("Mission '{}' has FAILED.", mission.name),
-                                related_id: Some(mission_id),
-                            })
-                            .await;
-
-                        // Also notify via Global WS for real-time toast
-                        state.manager.notify_user(member.id, ws_msg.clone()).await;
-                    }
-                }
-            }
-            (StatusCode::OK, mission_id.to_string()).into_response()
-        }
+        Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
@@ -240,60 +93,38 @@ where
     T2: MissionViewingRepository + Send + Sync + 'static,
 {
     match state.use_case.kick(mission_id, brawler_id, user_id).await {
-        Ok(_) => {
-            // Notify the kicked member and the room
-            if let Ok(mission) = state.viewing_repository.get_one(mission_id).await {
-                let ws_msg = WSMessage {
-                    msg_type: "kicked_from_mission".to_string(),
-                    data: serde_json::json!({
-                        "mission_id": mission_id,
-                        "mission_name": mission.name,
-                        "brawler_id": brawler_id, // Include brawler_id so frontend knows who was kicked
-                    }),
-                };
-
-                // 1. Notify the kicked user globally (for toast) and save to DB
-                let _ = state
-                    .notification_repo
-                    .add(AddNotificationEntity {
-                        brawler_id,
-                        type_: "kicked_from_mission".to_string(),
-                        content: format!("You were removed from crew in mission: {}", mission.name),
-                        related_id: Some(mission_id),
-                    })
-                    .await;
-
-                state.manager.notify_user(brawler_id, ws_msg.clone()).await;
-
-                // 2. Broadcast to EVERYONE (for public list/manager real-time count update)
-                state.manager.broadcast_all(ws_msg.clone()).await;
-
-                // 3. Broadcast to the specific room (for in-room UI reaction)
-                state.manager.broadcast(mission_id, ws_msg).await;
-            }
-            StatusCode::OK.into_response()
-        }
+        Ok(_) => StatusCode::OK.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, manager: Arc<ConnectionManager>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+) -> Router {
     let mission_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
     let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let viewing_repository_arc = Arc::new(viewing_repository);
-    let notification_repo = Arc::new(NotificationPostgres::new(Arc::clone(&db_pool)));
+    let achievement_use_case = Arc::new(AchievementUseCase::new(
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&notification_service),
+    ));
+
+    let mission_events = Arc::new(MissionEventService::new(
+        notification_service,
+        webhook_service,
+        achievement_use_case,
+    ));
 
     let use_case = MissionOperationUseCase::new(
         Arc::new(mission_repository),
-        Arc::clone(&viewing_repository_arc),
+        viewing_repository_arc,
+        mission_events,
     );
 
-    let state = Arc::new(MissionOperationState {
-        use_case,
-        manager,
-        viewing_repository: viewing_repository_arc,
-        notification_repo,
-    });
+    let state = Arc::new(MissionOperationState { use_case });
 
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::mission_rating::MissionRatingUseCase,
+    domain::{
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::mission_rating_model::AddMissionRatingModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_ratings::MissionRatingPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn rate<T>(
+    State(use_case): State<Arc<MissionRatingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionRatingModel>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match use_case.rate(mission_id, user_id, model).await {
+        Ok(rating_id) => (StatusCode::CREATED, rating_id.to_string()).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_my_ratings<T>(
+    State(use_case): State<Arc<MissionRatingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match use_case.get_my_ratings(mission_id, user_id).await {
+        Ok(ratings) => (StatusCode::OK, Json(ratings)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_reputation<T>(
+    State(use_case): State<Arc<MissionRatingUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match use_case.get_reputation(brawler_id).await {
+        Ok(reputation) => (StatusCode::OK, Json(reputation)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let rating_repository = Arc::new(MissionRatingPostgres::new(Arc::clone(&db_pool)));
+    let viewing_repository = Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool)));
+    let use_case = MissionRatingUseCase::new(rating_repository, viewing_repository);
+
+    Router::new()
+        .route("/{mission_id}", post(rate))
+        .route("/{mission_id}", get(get_my_ratings))
+        .route("/reputation/{brawler_id}", get(get_reputation))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(Arc::new(use_case))
+}
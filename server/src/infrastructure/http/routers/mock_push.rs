@@ -0,0 +1,170 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+    routing::{get, post},
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::{SecretKey, elliptic_curve::sec1::ToEncodedPoint};
+use rand_core::{OsRng, RngCore};
+use serde::Serialize;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::value_objects::web_push_model::{PushSubscriptionKeys, PushSubscriptionModel},
+    infrastructure::web_push::{encryption, vapid},
+};
+
+/// Plays both the browser and its push service, so Web Push can be exercised end to end
+/// without a real browser. Memory only; mounted in the Local stage.
+#[derive(Default)]
+pub struct MockPushService {
+    browsers: RwLock<HashMap<String, MockBrowser>>,
+}
+
+struct MockBrowser {
+    secret_key: SecretKey,
+    auth_secret: [u8; 16],
+    messages: Vec<MockPushMessage>,
+    /// Once set, pushes are answered with 410 Gone like an expired subscription
+    unsubscribed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MockPushMessage {
+    pub ttl: Option<String>,
+    pub urgency: Option<String>,
+    pub vapid_key: String,
+    pub payload: serde_json::Value,
+}
+
+/// Creates a browser and returns its subscription, ready for `POST /api/push/subscriptions`
+pub async fn create_subscription(
+    State(service): State<Arc<MockPushService>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let id = uuid::Uuid::new_v4().to_string();
+    let secret_key = SecretKey::random(&mut OsRng);
+    let mut auth_secret = [0u8; 16];
+    OsRng.fill_bytes(&mut auth_secret);
+
+    let host = headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .unwrap_or("localhost");
+    let subscription = PushSubscriptionModel {
+        endpoint: format!("http://{}/api/mock-push/{}", host, id),
+        expiration_time: None,
+        keys: PushSubscriptionKeys {
+            p256dh: URL_SAFE_NO_PAD
+                .encode(secret_key.public_key().to_encoded_point(false).as_bytes()),
+            auth: URL_SAFE_NO_PAD.encode(auth_secret),
+        },
+    };
+
+    service.browsers.write().await.insert(
+        id,
+        MockBrowser {
+            secret_key,
+            auth_secret,
+            messages: Vec::new(),
+            unsubscribed: false,
+        },
+    );
+
+    (StatusCode::CREATED, Json(subscription)).into_response()
+}
+
+/// The push endpoint itself: checks the VAPID token, decrypts and keeps the message
+pub async fn receive_push(
+    State(service): State<Arc<MockPushService>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let mut browsers = service.browsers.write().await;
+    let Some(browser) = browsers.get_mut(&id) else {
+        return (StatusCode::NOT_FOUND, "No such subscription".to_string()).into_response();
+    };
+    if browser.unsubscribed {
+        return (StatusCode::GONE, "Subscription has expired".to_string()).into_response();
+    }
+
+    let header_value = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    if header_value(header::CONTENT_ENCODING.as_str()).as_deref() != Some("aes128gcm") {
+        return (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "Content-Encoding must be aes128gcm".to_string(),
+        )
+            .into_response();
+    }
+
+    let endpoint = format!(
+        "http://{}/api/mock-push/{}",
+        header_value(header::HOST.as_str()).unwrap_or_default(),
+        id
+    );
+    let vapid_key = match header_value(header::AUTHORIZATION.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing Authorization"))
+        .and_then(|authorization| vapid::verify(&authorization, &endpoint))
+    {
+        Ok(key) => key,
+        Err(e) => return (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+    };
+
+    let payload = match encryption::decrypt(&browser.secret_key, &browser.auth_secret, &body) {
+        Ok(plaintext) => serde_json::from_slice(&plaintext).unwrap_or_else(|_| {
+            serde_json::Value::String(String::from_utf8_lossy(&plaintext).to_string())
+        }),
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+
+    browser.messages.push(MockPushMessage {
+        ttl: header_value("ttl"),
+        urgency: header_value("urgency"),
+        vapid_key,
+        payload,
+    });
+
+    StatusCode::CREATED.into_response()
+}
+
+pub async fn get_messages(
+    State(service): State<Arc<MockPushService>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match service.browsers.read().await.get(&id) {
+        Some(browser) => (StatusCode::OK, Json(browser.messages.clone())).into_response(),
+        None => (StatusCode::NOT_FOUND, "No such subscription".to_string()).into_response(),
+    }
+}
+
+/// Simulates the browser dropping the subscription; the next push gets 410 Gone
+pub async fn expire_subscription(
+    State(service): State<Arc<MockPushService>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match service.browsers.write().await.get_mut(&id) {
+        Some(browser) => {
+            browser.unsubscribed = true;
+            StatusCode::NO_CONTENT.into_response()
+        }
+        None => (StatusCode::NOT_FOUND, "No such subscription".to_string()).into_response(),
+    }
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/subscriptions", post(create_subscription))
+        .route("/{id}", post(receive_push).delete(expire_subscription))
+        .route("/{id}/messages", get(get_messages))
+        .with_state(Arc::new(MockPushService::default()))
+}
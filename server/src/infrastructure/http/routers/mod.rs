@@ -1,11 +1,20 @@
+pub mod attachments;
 pub mod authentication;
 pub mod brawlers;
+pub mod conversations;
 pub mod crew_operation;
 pub mod default_router;
 pub mod friendships;
+pub mod media;
 pub mod mission_comment;
 pub mod mission_management;
+pub mod mission_media;
 pub mod mission_operation;
+pub mod mission_ratings;
 pub mod mission_viewing;
+pub mod mock_push;
 pub mod notifications;
+pub mod privacy;
 pub mod private_messages;
+pub mod push;
+pub mod webhooks;
@@ -1,31 +1,62 @@
 use crate::infrastructure::http::middlewares::auth::auth;
 use crate::{
-    domain::repositories::notifications::NotificationRepository,
+    application::use_cases::{
+        email_digests::EmailDigestUseCase, notification_preferences::NotificationPreferenceUseCase,
+        notification_service::NotificationService, notifications::NotificationUseCase,
+    },
+    domain::{
+        repositories::mailer::Mailer,
+        value_objects::{
+            email_digest_model::UpdateEmailDigestModel,
+            notification_channels::NotificationPreferenceModel,
+            notification_model::NotificationPageQuery,
+        },
+    },
     infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::notifications::NotificationPostgres,
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            brawlers::BrawlerPostgres, email_digests::EmailDigestPostgres,
+            notification_preferences::NotificationPreferencePostgres,
+            notifications::NotificationPostgres,
+        },
     },
 };
 use axum::{
     Extension, Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, get, patch},
+    routing::{delete, get, patch, put},
 };
 use std::sync::Arc;
 
 pub struct NotificationRouterState {
-    pub repo: Arc<dyn NotificationRepository>,
+    pub use_case: NotificationUseCase,
+    pub preference_use_case: NotificationPreferenceUseCase,
+    pub digest_use_case: EmailDigestUseCase,
 }
 
+/// Most recently updated first; pass the last notification's `id` as `cursor` and its
+/// `updated_at` as `cursor_updated_at` for the next page
 pub async fn get_my_notifications(
     State(state): State<Arc<NotificationRouterState>>,
     Extension(user_id): Extension<i32>,
-) -> Json<serde_json::Value> {
-    match state.repo.get_by_user(user_id).await {
-        Ok(notifications) => Json(serde_json::json!(notifications)),
-        Err(e) => Json(serde_json::json!({ "error": e.to_string() })),
+    Query(query): Query<NotificationPageQuery>,
+) -> impl IntoResponse {
+    match state.use_case.get_my_notifications(user_id, query).await {
+        Ok(notifications) => (StatusCode::OK, Json(notifications)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_unread_count(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.use_case.unread_count(user_id).await {
+        Ok(count) => (StatusCode::OK, Json(count)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
@@ -34,7 +65,7 @@ pub async fn mark_as_read(
     Extension(user_id): Extension<i32>,
     Path(id): Path<i32>,
 ) -> impl IntoResponse {
-    match state.repo.mark_as_read(id, user_id).await {
+    match state.use_case.mark_as_read(id, user_id).await {
         Ok(_) => StatusCode::OK.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -44,31 +75,114 @@ pub async fn mark_all_as_read(
     State(state): State<Arc<NotificationRouterState>>,
     Extension(user_id): Extension<i32>,
 ) -> impl IntoResponse {
-    match state.repo.mark_all_as_read(user_id).await {
+    match state.use_case.mark_all_as_read(user_id).await {
         Ok(_) => StatusCode::OK.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
+pub async fn delete_notification(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.delete(id, user_id).await {
+        Ok(true) => StatusCode::OK.into_response(),
+        Ok(false) => (StatusCode::NOT_FOUND, "Notification not found").into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub async fn clear_notifications(
     State(state): State<Arc<NotificationRouterState>>,
     Extension(user_id): Extension<i32>,
 ) -> impl IntoResponse {
-    match state.repo.delete_for_user(user_id).await {
+    match state.use_case.clear(user_id).await {
         Ok(_) => StatusCode::OK.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repo = Arc::new(NotificationPostgres::new(db_pool));
-    let state = Arc::new(NotificationRouterState { repo });
+pub async fn get_preferences(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.preference_use_case.get(user_id).await {
+        Ok(preferences) => (StatusCode::OK, Json(preferences)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Takes only the cells to change and answers with the full matrix
+pub async fn update_preferences(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Json(changes): Json<Vec<NotificationPreferenceModel>>,
+) -> impl IntoResponse {
+    match state.preference_use_case.update(user_id, changes).await {
+        Ok(preferences) => (StatusCode::OK, Json(preferences)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_digest(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.digest_use_case.get_settings(user_id).await {
+        Ok(settings) => (StatusCode::OK, Json(settings)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// `{"frequency": "daily" | "weekly"}` opts in, `{"frequency": null}` opts out
+pub async fn update_digest(
+    State(state): State<Arc<NotificationRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<UpdateEmailDigestModel>,
+) -> impl IntoResponse {
+    match state.digest_use_case.update_settings(user_id, model).await {
+        Ok(settings) => (StatusCode::OK, Json(settings)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<NotificationService>,
+    mailer: Arc<dyn Mailer>,
+) -> Router {
+    let use_case = NotificationUseCase::new(
+        Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+        notification_service,
+    );
+    let preference_use_case = NotificationPreferenceUseCase::new(Arc::new(
+        NotificationPreferencePostgres::new(Arc::clone(&db_pool)),
+    ));
+    let digest_use_case = EmailDigestUseCase::new(
+        Arc::new(EmailDigestPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPreferencePostgres::new(db_pool)),
+        mailer,
+    );
+    let state = Arc::new(NotificationRouterState {
+        use_case,
+        preference_use_case,
+        digest_use_case,
+    });
 
     Router::new()
         .route("/", get(get_my_notifications))
+        .route("/unread-count", get(get_unread_count))
         .route("/mark-all-read", patch(mark_all_as_read))
         .route("/{id}/read", patch(mark_as_read))
+        .route("/{id}", delete(delete_notification))
         .route("/", delete(clear_notifications))
+        .route("/preferences", get(get_preferences))
+        .route("/preferences", put(update_preferences))
+        .route("/digest", get(get_digest))
+        .route("/digest", put(update_digest))
         .route_layer(middleware::from_fn(auth))
         .with_state(state)
 }
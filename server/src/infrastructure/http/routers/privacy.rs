@@ -0,0 +1,151 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::privacy::PrivacyUseCase,
+    domain::{
+        repositories::brawlers::BrawlerRepository, value_objects::dm_privacy::DmPrivacyModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, friendships::FriendshipPostgres,
+                privacy::PrivacyPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub struct PrivacyRouterState {
+    pub use_case: PrivacyUseCase,
+    pub brawler_repo: Arc<dyn BrawlerRepository + Send + Sync>,
+}
+
+pub async fn get_blocked(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.use_case.list_blocked(user_id).await {
+        Ok(ids) => match state.brawler_repo.find_many(ids).await {
+            Ok(brawlers) => (StatusCode::OK, Json(brawlers)).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        },
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn block(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.block(user_id, brawler_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn unblock(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.unblock(user_id, brawler_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_muted(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.use_case.list_muted(user_id).await {
+        Ok(ids) => match state.brawler_repo.find_many(ids).await {
+            Ok(brawlers) => (StatusCode::OK, Json(brawlers)).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        },
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mute(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.mute(user_id, brawler_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn unmute(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse {
+    match state.use_case.unmute(user_id, brawler_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_dm_privacy(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match state.use_case.get_dm_privacy(user_id).await {
+        Ok(dm_privacy) => (StatusCode::OK, Json(DmPrivacyModel { dm_privacy })).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn set_dm_privacy(
+    State(state): State<Arc<PrivacyRouterState>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<DmPrivacyModel>,
+) -> impl IntoResponse {
+    match state
+        .use_case
+        .set_dm_privacy(user_id, model.dm_privacy)
+        .await
+    {
+        Ok(_) => (StatusCode::OK, Json(model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let use_case = PrivacyUseCase::new(
+        Arc::new(PrivacyPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(FriendshipPostgres::new(Arc::clone(&db_pool))),
+    );
+    let brawler_repo = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
+
+    let state = Arc::new(PrivacyRouterState {
+        use_case,
+        brawler_repo,
+    });
+
+    Router::new()
+        .route("/blocks", get(get_blocked))
+        .route("/blocks/{brawler_id}", post(block))
+        .route("/blocks/{brawler_id}", delete(unblock))
+        .route("/mutes", get(get_muted))
+        .route("/mutes/{brawler_id}", post(mute))
+        .route("/mutes/{brawler_id}", delete(unmute))
+        .route("/dm", get(get_dm_privacy))
+        .route("/dm", put(set_dm_privacy))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(state)
+}
@@ -1,62 +1,92 @@
 use axum::{
     Extension, Json, Router,
-    extract::State,
+    extract::{Query, State},
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, patch, post},
 };
 use std::sync::Arc;
 
-use crate::domain::entities::private_messages::CreatePrivateMessage;
-use crate::domain::repositories::{
-    notifications::NotificationRepository, private_messages::PrivateMessageRepository,
+use crate::application::use_cases::{
+    notification_service::NotificationService, privacy::PrivacyUseCase,
 };
+use crate::domain::entities::private_messages::{
+    CreatePrivateMessage, EditPrivateMessage, MessagePageQuery, MessageSearchQuery,
+};
+use crate::domain::repositories::private_messages::PrivateMessageRepository;
+use crate::domain::value_objects::notification_events::NotificationEvent;
 use crate::infrastructure::websocket::handler::WSMessage;
 use crate::infrastructure::websocket::manager::ConnectionManager;
 
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 100;
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
+/// How long after sending a message can still be unsent for both sides
+const UNSEND_WINDOW_MINUTES: i64 = 15;
+
+type PrivateMessageState = (
+    Arc<dyn PrivateMessageRepository>,
+    Arc<ConnectionManager>,
+    Arc<NotificationService>,
+    Arc<PrivacyUseCase>,
+);
+
 pub fn routes(
     pm_repo: Arc<dyn PrivateMessageRepository>,
     ws_manager: Arc<ConnectionManager>,
-    notification_repo: Arc<dyn NotificationRepository>,
+    notification_service: Arc<NotificationService>,
+    privacy_use_case: Arc<PrivacyUseCase>,
 ) -> Router {
     Router::new()
         .route("/", post(send_message))
         .route("/conversation/{with_id}", get(get_conversation))
         .route("/unread", get(get_unread_count))
         .route("/recent", get(get_recent_chats))
+        .route("/search", get(search_messages))
         .route("/read/{sender_id}", post(mark_as_read))
-        .with_state((pm_repo, ws_manager, notification_repo))
+        .route("/{message_id}", patch(edit_message))
+        .route("/{message_id}", delete(delete_for_me))
+        .route("/{message_id}/unsend", post(unsend_message))
+        .with_state((pm_repo, ws_manager, notification_service, privacy_use_case))
 }
 
 async fn send_message(
-    State((pm_repo, ws_manager, notification_repo)): State<(
-        Arc<dyn PrivateMessageRepository>,
-        Arc<ConnectionManager>,
-        Arc<dyn NotificationRepository>,
-    )>,
+    State((pm_repo, ws_manager, notification_service, privacy_use_case)): State<
+        PrivateMessageState,
+    >,
     Extension(user_id): Extension<i32>,
     Json(payload): Json<CreatePrivateMessage>,
 ) -> impl IntoResponse {
+    if let Err(e) = privacy_use_case
+        .ensure_can_message(user_id, payload.receiver_id)
+        .await
+    {
+        return (axum::http::StatusCode::FORBIDDEN, e.to_string()).into_response();
+    }
+
     match pm_repo
-        .save(user_id, payload.receiver_id, payload.content)
+        .save(
+            user_id,
+            payload.receiver_id,
+            payload.content,
+            payload.attachment_ids,
+        )
         .await
     {
         Ok(msg) => {
             // 1. Create a notification for the receiver (so it shows in the bell)
-            use crate::domain::entities::notifications::AddNotificationEntity;
-            let sender_name = msg
-                .sender_display_name
-                .clone()
-                .unwrap_or_else(|| "Agent".to_string());
-            let _ = notification_repo
-                .add(AddNotificationEntity {
-                    brawler_id: msg.receiver_id,
-                    type_: "private_message".to_string(),
-                    content: format!("{}: \"{}\"", sender_name, msg.content),
-                    related_id: Some(msg.sender_id),
+            notification_service
+                .notify(NotificationEvent::PrivateMessage {
+                    message: msg.clone(),
                 })
                 .await;
 
-            // 2. Send via WebSocket if recipient is online
+            // 2. Sending ends the sender's typing indicator
+            ws_manager
+                .relay_typing(msg.sender_id, msg.receiver_id, false)
+                .await;
+
+            // 3. Send via WebSocket if recipient is online; this is the chat itself rather than
+            // a notification, so preferences don't apply
             ws_manager
                 .notify_user(
                     msg.receiver_id,
@@ -74,26 +104,26 @@ async fn send_message(
 }
 
 async fn get_conversation(
-    State((pm_repo, _, _)): State<(
-        Arc<dyn PrivateMessageRepository>,
-        Arc<ConnectionManager>,
-        Arc<dyn NotificationRepository>,
-    )>,
+    State((pm_repo, _, _, _)): State<PrivateMessageState>,
     Extension(user_id): Extension<i32>,
     axum::extract::Path(with_id): axum::extract::Path<i32>,
+    Query(page): Query<MessagePageQuery>,
 ) -> impl IntoResponse {
-    match pm_repo.get_conversation(user_id, with_id).await {
+    let limit = page
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    match pm_repo
+        .get_conversation(user_id, with_id, page.before_id, limit)
+        .await
+    {
         Ok(msgs) => Json(msgs).into_response(),
         Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
     }
 }
 
 async fn get_unread_count(
-    State((pm_repo, _, _)): State<(
-        Arc<dyn PrivateMessageRepository>,
-        Arc<ConnectionManager>,
-        Arc<dyn NotificationRepository>,
-    )>,
+    State((pm_repo, _, _, _)): State<PrivateMessageState>,
     Extension(user_id): Extension<i32>,
 ) -> impl IntoResponse {
     match pm_repo.get_unread_count(user_id).await {
@@ -103,30 +133,215 @@ async fn get_unread_count(
 }
 
 async fn get_recent_chats(
-    State((pm_repo, _, _)): State<(
-        Arc<dyn PrivateMessageRepository>,
-        Arc<ConnectionManager>,
-        Arc<dyn NotificationRepository>,
-    )>,
+    State((pm_repo, _, _, _)): State<PrivateMessageState>,
     Extension(user_id): Extension<i32>,
+    Query(page): Query<MessagePageQuery>,
 ) -> impl IntoResponse {
-    match pm_repo.get_recent_chats(user_id).await {
+    let limit = page
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    match pm_repo
+        .get_recent_chats(user_id, page.before_id, limit)
+        .await
+    {
         Ok(chats) => Json(chats).into_response(),
         Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
     }
 }
 
+async fn search_messages(
+    State((pm_repo, _, _, _)): State<PrivateMessageState>,
+    Extension(user_id): Extension<i32>,
+    Query(search): Query<MessageSearchQuery>,
+) -> impl IntoResponse {
+    if search.q.trim().is_empty() {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            "Search query cannot be empty".to_string(),
+        )
+            .into_response();
+    }
+
+    let limit = search
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_PAGE_SIZE);
+    match pm_repo.search(user_id, &search.q, limit).await {
+        Ok(msgs) => Json(msgs).into_response(),
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
 async fn mark_as_read(
-    State((pm_repo, _, _)): State<(
-        Arc<dyn PrivateMessageRepository>,
-        Arc<ConnectionManager>,
-        Arc<dyn NotificationRepository>,
-    )>,
+    State((pm_repo, ws_manager, _, _)): State<PrivateMessageState>,
     Extension(user_id): Extension<i32>,
     axum::extract::Path(sender_id): axum::extract::Path<i32>,
 ) -> impl IntoResponse {
     match pm_repo.mark_as_read(user_id, sender_id).await {
-        Ok(_) => axum::http::StatusCode::OK.into_response(),
+        Ok(last_read_message_id) => {
+            // Read receipt for the original sender
+            if let Some(last_read_message_id) = last_read_message_id {
+                ws_manager
+                    .notify_user(
+                        sender_id,
+                        WSMessage {
+                            msg_type: "messages_read".to_string(),
+                            data: serde_json::json!({
+                                "reader_id": user_id,
+                                "last_read_message_id": last_read_message_id,
+                            }),
+                        },
+                    )
+                    .await;
+            }
+
+            axum::http::StatusCode::OK.into_response()
+        }
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
+async fn edit_message(
+    State((pm_repo, ws_manager, _, _)): State<PrivateMessageState>,
+    Extension(user_id): Extension<i32>,
+    axum::extract::Path(message_id): axum::extract::Path<i32>,
+    Json(payload): Json<EditPrivateMessage>,
+) -> impl IntoResponse {
+    if payload.content.trim().is_empty() {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            "Message cannot be empty".to_string(),
+        )
+            .into_response();
+    }
+
+    let msg = match pm_repo.find_by_id(message_id).await {
+        Ok(msg) => msg,
+        Err(e) => return (axum::http::StatusCode::NOT_FOUND, e).into_response(),
+    };
+    if msg.sender_id != user_id {
+        return (
+            axum::http::StatusCode::FORBIDDEN,
+            "You can only edit your own messages".to_string(),
+        )
+            .into_response();
+    }
+    if msg.deleted_at.is_some() {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            "This message was unsent".to_string(),
+        )
+            .into_response();
+    }
+
+    match pm_repo.edit(message_id, payload.content).await {
+        Ok(msg) => {
+            ws_manager
+                .notify_user(
+                    msg.receiver_id,
+                    WSMessage {
+                        msg_type: "private_message_updated".to_string(),
+                        data: serde_json::to_value(&msg).unwrap_or_default(),
+                    },
+                )
+                .await;
+
+            Json(msg).into_response()
+        }
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
+async fn unsend_message(
+    State((pm_repo, ws_manager, _, _)): State<PrivateMessageState>,
+    Extension(user_id): Extension<i32>,
+    axum::extract::Path(message_id): axum::extract::Path<i32>,
+) -> impl IntoResponse {
+    let msg = match pm_repo.find_by_id(message_id).await {
+        Ok(msg) => msg,
+        Err(e) => return (axum::http::StatusCode::NOT_FOUND, e).into_response(),
+    };
+    if msg.sender_id != user_id {
+        return (
+            axum::http::StatusCode::FORBIDDEN,
+            "You can only unsend your own messages".to_string(),
+        )
+            .into_response();
+    }
+    if msg.created_at
+        < chrono::Utc::now().naive_utc() - chrono::Duration::minutes(UNSEND_WINDOW_MINUTES)
+    {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            format!(
+                "Messages can only be unsent within {} minutes",
+                UNSEND_WINDOW_MINUTES
+            ),
+        )
+            .into_response();
+    }
+
+    match pm_repo.unsend(message_id).await {
+        Ok(msg) => {
+            ws_manager
+                .notify_user(
+                    msg.receiver_id,
+                    WSMessage {
+                        msg_type: "private_message_deleted".to_string(),
+                        data: serde_json::json!({
+                            "id": msg.id,
+                            "sender_id": msg.sender_id,
+                            "receiver_id": msg.receiver_id,
+                            "scope": "everyone",
+                        }),
+                    },
+                )
+                .await;
+
+            Json(msg).into_response()
+        }
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
+async fn delete_for_me(
+    State((pm_repo, ws_manager, _, _)): State<PrivateMessageState>,
+    Extension(user_id): Extension<i32>,
+    axum::extract::Path(message_id): axum::extract::Path<i32>,
+) -> impl IntoResponse {
+    let msg = match pm_repo.find_by_id(message_id).await {
+        Ok(msg) => msg,
+        Err(e) => return (axum::http::StatusCode::NOT_FOUND, e).into_response(),
+    };
+    if msg.sender_id != user_id && msg.receiver_id != user_id {
+        return (
+            axum::http::StatusCode::FORBIDDEN,
+            "This message is not part of your conversations".to_string(),
+        )
+            .into_response();
+    }
+
+    match pm_repo.hide_for(message_id, user_id).await {
+        Ok(_) => {
+            // The other side still sees the message, so only the caller's other sessions hear about it
+            ws_manager
+                .notify_user(
+                    user_id,
+                    WSMessage {
+                        msg_type: "private_message_deleted".to_string(),
+                        data: serde_json::json!({
+                            "id": msg.id,
+                            "sender_id": msg.sender_id,
+                            "receiver_id": msg.receiver_id,
+                            "scope": "me",
+                        }),
+                    },
+                )
+                .await;
+
+            axum::http::StatusCode::NO_CONTENT.into_response()
+        }
         Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
     }
 }
@@ -0,0 +1,56 @@
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::web_push::WebPushUseCase,
+    domain::value_objects::web_push_model::{PushSubscriptionModel, RemovePushSubscriptionModel},
+    infrastructure::http::middlewares::auth::auth,
+};
+
+pub async fn get_vapid_public_key(
+    State(use_case): State<Arc<WebPushUseCase>>,
+) -> impl IntoResponse {
+    match use_case.vapid_public_key() {
+        Ok(key) => (StatusCode::OK, Json(key)).into_response(),
+        Err(e) => (StatusCode::SERVICE_UNAVAILABLE, e.to_string()).into_response(),
+    }
+}
+
+/// Takes the browser's `PushSubscription.toJSON()` as is
+pub async fn subscribe(
+    State(use_case): State<Arc<WebPushUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<PushSubscriptionModel>,
+) -> impl IntoResponse {
+    match use_case.subscribe(user_id, model).await {
+        Ok(_) => StatusCode::CREATED.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn unsubscribe(
+    State(use_case): State<Arc<WebPushUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<RemovePushSubscriptionModel>,
+) -> impl IntoResponse {
+    match use_case.unsubscribe(user_id, &model.endpoint).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(web_push: Arc<WebPushUseCase>) -> Router {
+    Router::new()
+        .route("/vapid-public-key", get(get_vapid_public_key))
+        .route("/subscriptions", post(subscribe))
+        .route("/subscriptions", delete(unsubscribe))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(web_push)
+}
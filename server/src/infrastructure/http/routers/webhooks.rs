@@ -0,0 +1,111 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::webhooks::WebhookUseCase,
+    domain::value_objects::webhook_model::{
+        AddWebhookModel, EditWebhookModel, WebhookDeliveryPageQuery,
+    },
+    infrastructure::http::middlewares::auth::auth,
+};
+
+pub async fn list(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse {
+    match use_case.list(user_id).await {
+        Ok(webhooks) => (StatusCode::OK, Json(webhooks)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// The response carries the signing secret; it can't be read back later
+pub async fn add(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddWebhookModel>,
+) -> impl IntoResponse {
+    if model.all_missions && !use_case.is_admin(user_id) {
+        return (
+            StatusCode::FORBIDDEN,
+            "Only admins can subscribe to every mission",
+        )
+            .into_response();
+    }
+    if let Some(mission_id) = model.mission_id
+        && let Err(e) = use_case.ensure_can_watch_mission(user_id, mission_id).await
+    {
+        return (StatusCode::FORBIDDEN, e.to_string()).into_response();
+    }
+
+    match use_case.add(user_id, model).await {
+        Ok(webhook) => (StatusCode::CREATED, Json(webhook)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_one(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get(webhook_id, user_id).await {
+        Ok(webhook) => (StatusCode::OK, Json(webhook)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+    Json(model): Json<EditWebhookModel>,
+) -> impl IntoResponse {
+    match use_case.edit(webhook_id, user_id, model).await {
+        Ok(webhook) => (StatusCode::OK, Json(webhook)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.remove(webhook_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+/// Newest first; pass the last id as `cursor` for the next page
+pub async fn get_deliveries(
+    State(use_case): State<Arc<WebhookUseCase>>,
+    Extension(user_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+    Query(query): Query<WebhookDeliveryPageQuery>,
+) -> impl IntoResponse {
+    match use_case.get_deliveries(webhook_id, user_id, query).await {
+        Ok(deliveries) => (StatusCode::OK, Json(deliveries)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(webhooks: Arc<WebhookUseCase>) -> Router {
+    Router::new()
+        .route("/", get(list))
+        .route("/", post(add))
+        .route("/{webhook_id}", get(get_one))
+        .route("/{webhook_id}", patch(edit))
+        .route("/{webhook_id}", delete(remove))
+        .route("/{webhook_id}/deliveries", get(get_deliveries))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(webhooks)
+}
@@ -0,0 +1,170 @@
+use anyhow::{Context, Result, anyhow};
+use image::{
+    DynamicImage, ExtendedColorType, ImageDecoder, ImageFormat, ImageReader, Limits,
+    codecs::webp::WebPEncoder, imageops::FilterType,
+};
+use std::io::Cursor;
+
+use crate::domain::repositories::blob_storage::BlobStorage;
+
+/// Square avatar widths, smallest first
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 256];
+/// Mission card covers are 16:9
+pub const MISSION_COVER_ASPECT: (u32, u32) = (16, 9);
+pub const MISSION_COVER_WIDTHS: [u32; 2] = [640, 1280];
+/// Gallery photos keep their own aspect: a thumbnail and a full view
+pub const MISSION_GALLERY_WIDTHS: [u32; 2] = [320, 1280];
+
+/// Anything bigger is rejected before decoding, which keeps decompression bombs out
+const MAX_SOURCE_DIMENSION: u32 = 8192;
+const MAX_DECODE_ALLOC_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ImageShape {
+    /// Center-crop to a (width, height) aspect ratio
+    Crop(u32, u32),
+    /// Keep the source aspect ratio; small sources are never upscaled
+    Original,
+}
+
+pub struct ImageVariant {
+    pub width: u32,
+    pub height: u32,
+    /// WebP encoded
+    pub bytes: Vec<u8>,
+}
+
+/// Decodes a PNG, JPEG or WebP and renders one WebP per requested width. Only pixels are
+/// re-encoded, so EXIF/GPS and other metadata never reach the output; the EXIF orientation
+/// is applied first so photos stay upright.
+pub fn render_variants(
+    bytes: &[u8],
+    shape: ImageShape,
+    widths: &[u32],
+) -> Result<Vec<ImageVariant>> {
+    let source = decode(bytes)?;
+    let source = match shape {
+        ImageShape::Crop(aspect_w, aspect_h) => center_crop(&source, (aspect_w, aspect_h)),
+        ImageShape::Original => source,
+    };
+
+    widths
+        .iter()
+        .map(|&width| {
+            let (width, height) = match shape {
+                ImageShape::Crop(aspect_w, aspect_h) => {
+                    (width, (width * aspect_h).div_ceil(aspect_w).max(1))
+                }
+                ImageShape::Original => {
+                    let width = width.min(source.width());
+                    let height = (u64::from(width) * u64::from(source.height()))
+                        .div_ceil(u64::from(source.width()))
+                        as u32;
+                    (width, height.max(1))
+                }
+            };
+            let resized = source
+                .resize_exact(width, height, FilterType::Lanczos3)
+                .into_rgba8();
+
+            let mut out = Vec::new();
+            WebPEncoder::new_lossless(&mut out)
+                .encode(resized.as_raw(), width, height, ExtendedColorType::Rgba8)
+                .context("encode webp")?;
+
+            Ok(ImageVariant {
+                width,
+                height,
+                bytes: out,
+            })
+        })
+        .collect()
+}
+
+/// Storage key of one variant; variants are named after the requested width
+pub fn variant_key(folder: &str, width: u32) -> String {
+    format!("{}/{}.webp", folder, width)
+}
+
+/// Renders the variants off the async workers and stores them under `folder`.
+/// Nothing is left behind when a put fails halfway.
+pub async fn store_variants(
+    storage: &dyn BlobStorage,
+    bytes: Vec<u8>,
+    shape: ImageShape,
+    widths: &[u32],
+    folder: &str,
+) -> Result<()> {
+    let requested = widths.to_vec();
+    let variants =
+        tokio::task::spawn_blocking(move || render_variants(&bytes, shape, &requested)).await??;
+
+    for (&width, variant) in widths.iter().zip(variants) {
+        if let Err(e) = storage
+            .put(&variant_key(folder, width), variant.bytes, "image/webp")
+            .await
+        {
+            let _ = delete_variants(storage, folder, widths).await;
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn delete_variants(
+    storage: &dyn BlobStorage,
+    folder: &str,
+    widths: &[u32],
+) -> Result<()> {
+    for &width in widths {
+        storage.delete(&variant_key(folder, width)).await?;
+    }
+    Ok(())
+}
+
+fn decode(bytes: &[u8]) -> Result<DynamicImage> {
+    let format = image::guess_format(bytes).map_err(|_| anyhow!("Unrecognised image data"))?;
+    if !matches!(
+        format,
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP
+    ) {
+        return Err(anyhow!("Only PNG, JPEG and WebP images are supported"));
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC_BYTES);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+    let mut decoder = reader.into_decoder().context("read image")?;
+    let orientation = decoder.orientation()?;
+
+    let mut image = DynamicImage::from_decoder(decoder).context("decode image")?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// The largest centered region with the given aspect ratio
+fn center_crop(image: &DynamicImage, (aspect_w, aspect_h): (u32, u32)) -> DynamicImage {
+    let (width, height) = (image.width(), image.height());
+
+    let (crop_w, crop_h) =
+        if u64::from(width) * u64::from(aspect_h) > u64::from(height) * u64::from(aspect_w) {
+            // Too wide: keep the full height
+            (
+                (u64::from(height) * u64::from(aspect_w) / u64::from(aspect_h)) as u32,
+                height,
+            )
+        } else {
+            (
+                width,
+                (u64::from(width) * u64::from(aspect_h) / u64::from(aspect_w)) as u32,
+            )
+        };
+    let (crop_w, crop_h) = (crop_w.max(1), crop_h.max(1));
+
+    image.crop_imm((width - crop_w) / 2, (height - crop_h) / 2, crop_w, crop_h)
+}
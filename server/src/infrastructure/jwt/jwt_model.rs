@@ -21,6 +21,7 @@ pub struct Passport {
 }
 
 impl Passport {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_id: i32,
         display_name: String,
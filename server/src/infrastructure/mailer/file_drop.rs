@@ -0,0 +1,38 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::path::PathBuf;
+
+use crate::domain::{repositories::mailer::Mailer, value_objects::outgoing_email::OutgoingEmail};
+
+/// Writes every message to `<dir>/<timestamp>-<uuid>.eml`; open them with any mail client
+pub struct FileDropMailer {
+    from: String,
+    dir: PathBuf,
+}
+
+impl FileDropMailer {
+    pub fn new(from: String, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            from,
+            dir: dir.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileDropMailer {
+    async fn send(&self, email: OutgoingEmail) -> Result<()> {
+        let message = super::build_message(&self.from, email)?;
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let file_name = format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%dT%H%M%S"),
+            uuid::Uuid::new_v4()
+        );
+        tokio::fs::write(self.dir.join(file_name), message.formatted()).await?;
+
+        Ok(())
+    }
+}
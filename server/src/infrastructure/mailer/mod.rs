@@ -0,0 +1,40 @@
+pub mod file_drop;
+pub mod smtp;
+pub mod templates;
+
+use anyhow::Result;
+use lettre::{Message, message::MultiPart};
+use std::sync::Arc;
+
+use crate::{
+    config::{config_loader::get_mailer_env, config_model::MailerBackends},
+    domain::{repositories::mailer::Mailer, value_objects::outgoing_email::OutgoingEmail},
+};
+
+/// The backend selected by `MAILER_BACKEND`
+pub fn build_mailer() -> Result<Arc<dyn Mailer>> {
+    let mailer_env = get_mailer_env()?;
+
+    let mailer: Arc<dyn Mailer> = match mailer_env.backend {
+        MailerBackends::Smtp => Arc::new(smtp::SmtpMailer::new(&mailer_env)?),
+        MailerBackends::FileDrop => Arc::new(file_drop::FileDropMailer::new(
+            mailer_env.from,
+            mailer_env.file_drop_dir,
+        )),
+    };
+    Ok(mailer)
+}
+
+/// Both backends send the same MIME message, so what lands on disk is what SMTP would deliver
+fn build_message(from: &str, email: OutgoingEmail) -> Result<Message> {
+    let message = Message::builder()
+        .from(from.parse()?)
+        .to(email.to.parse()?)
+        .subject(email.subject)
+        .multipart(MultiPart::alternative_plain_html(
+            email.text_body,
+            email.html_body,
+        ))?;
+
+    Ok(message)
+}
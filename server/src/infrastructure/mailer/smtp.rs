@@ -0,0 +1,51 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::{
+    config::config_model::{MailerEnv, SmtpTls},
+    domain::{repositories::mailer::Mailer, value_objects::outgoing_email::OutgoingEmail},
+};
+
+pub struct SmtpMailer {
+    from: String,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn new(mailer_env: &MailerEnv) -> Result<Self> {
+        let mut builder = match mailer_env.smtp_tls {
+            SmtpTls::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&mailer_env.smtp_host)?
+            }
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&mailer_env.smtp_host)?,
+            SmtpTls::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&mailer_env.smtp_host)
+            }
+        }
+        .port(mailer_env.smtp_port);
+
+        if let (Some(username), Some(password)) =
+            (&mailer_env.smtp_username, &mailer_env.smtp_password)
+        {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            from: mailer_env.from.clone(),
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: OutgoingEmail) -> Result<()> {
+        let message = super::build_message(&self.from, email)?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use minijinja::{Environment, context};
+use std::sync::LazyLock;
+
+use crate::domain::value_objects::{
+    email_digest_model::DigestModel, outgoing_email::OutgoingEmail,
+};
+
+/// Templates are compiled into the binary; `.html` ones are auto-escaped by their extension
+static TEMPLATES: LazyLock<Environment<'static>> = LazyLock::new(|| {
+    let mut env = Environment::new();
+    env.add_template("digest.html", include_str!("templates/digest.html"))
+        .expect("digest.html is a valid template");
+    env.add_template("digest.txt", include_str!("templates/digest.txt"))
+        .expect("digest.txt is a valid template");
+    env.add_filter("datetime", |value: String| {
+        chrono::NaiveDateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S%.f")
+            .map(|at| at.format("%a %d %b, %H:%M UTC").to_string())
+            .unwrap_or(value)
+    });
+    env
+});
+
+pub fn render_digest(to: String, digest: &DigestModel) -> Result<OutgoingEmail> {
+    let ctx = context! { digest => digest };
+
+    Ok(OutgoingEmail {
+        to,
+        subject: format!("Your {} Brawlers digest", digest.frequency),
+        html_body: TEMPLATES.get_template("digest.html")?.render(&ctx)?,
+        text_body: TEMPLATES.get_template("digest.txt")?.render(&ctx)?,
+    })
+}
@@ -2,5 +2,11 @@ pub mod argon2;
 pub mod cloudinary;
 pub mod database;
 pub mod http;
+pub mod image_processing;
 pub mod jwt;
+pub mod mailer;
+pub mod scheduler;
+pub mod storage;
+pub mod web_push;
+pub mod webhooks;
 pub mod websocket;
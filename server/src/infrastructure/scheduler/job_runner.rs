@@ -0,0 +1,373 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use chrono::Utc;
+use futures::StreamExt;
+use tracing::{error, info, warn};
+
+use crate::{
+    application::use_cases::{
+        achievements::AchievementUseCase, email_digests::EmailDigestUseCase,
+        mission_automation::MissionAutomationUseCase, mission_events::MissionEventService,
+        notification_service::NotificationService, webhook_service::WebhookService,
+        webhooks::WebhookUseCase,
+    },
+    config::{
+        config_loader::{get_job_runner_env, get_webhook_env},
+        config_model::JobRunnerEnv,
+    },
+    domain::{
+        entities::scheduled_jobs::{AddScheduledJobEntity, ScheduledJobEntity},
+        repositories::{
+            brawler_stats::BrawlerStatsRepository, mailer::Mailer,
+            notifications::NotificationRepository, push_subscriptions::PushSubscriptionRepository,
+            scheduled_jobs::ScheduledJobRepository,
+        },
+        value_objects::scheduled_job_types::{JobLane, ScheduledJobTypes},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementPostgres, brawler_stats::BrawlerStatsPostgres,
+            brawlers::BrawlerPostgres, email_digests::EmailDigestPostgres,
+            mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres,
+            notification_preferences::NotificationPreferencePostgres,
+            notifications::NotificationPostgres, push_subscriptions::PushSubscriptionPostgres,
+            scheduled_jobs::ScheduledJobPostgres,
+        },
+    },
+};
+
+/// Polls the `scheduled_jobs` table and dispatches due jobs to their handlers.
+/// Jobs live in the database, so anything scheduled before a restart still fires afterwards.
+/// Webhook deliveries wait on outside endpoints, so they run in a lane of their own and
+/// never hold up mission jobs.
+pub struct JobRunner {
+    env: JobRunnerEnv,
+    webhook_concurrency: usize,
+    scheduled_job_repository: Arc<dyn ScheduledJobRepository>,
+    brawler_stats_repository: Arc<dyn BrawlerStatsRepository>,
+    notification_repository: Arc<dyn NotificationRepository>,
+    push_subscription_repository: Arc<dyn PushSubscriptionRepository>,
+    mission_automation: MissionAutomationUseCase<MissionOperationPostgres, MissionViewingPostgres>,
+    email_digests: EmailDigestUseCase,
+    webhooks: Arc<WebhookUseCase>,
+}
+
+impl JobRunner {
+    pub fn new(
+        db_pool: Arc<PgPoolSquad>,
+        notification_service: Arc<NotificationService>,
+        mailer: Arc<dyn Mailer>,
+        webhook_service: Arc<WebhookService>,
+        webhooks: Arc<WebhookUseCase>,
+    ) -> Result<Self> {
+        let achievement_use_case = Arc::new(AchievementUseCase::new(
+            Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+            Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+            Arc::clone(&notification_service),
+        ));
+        let mission_events = Arc::new(MissionEventService::new(
+            Arc::clone(&notification_service),
+            webhook_service,
+            achievement_use_case,
+        ));
+        let mission_automation = MissionAutomationUseCase::new(
+            Arc::new(MissionOperationPostgres::new(Arc::clone(&db_pool))),
+            Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+            notification_service,
+            mission_events,
+        );
+        let email_digests = EmailDigestUseCase::new(
+            Arc::new(EmailDigestPostgres::new(Arc::clone(&db_pool))),
+            Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+            Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+            Arc::new(NotificationPreferencePostgres::new(Arc::clone(&db_pool))),
+            mailer,
+        );
+
+        Ok(Self {
+            env: get_job_runner_env()?,
+            webhook_concurrency: get_webhook_env()?.concurrency.max(1),
+            scheduled_job_repository: Arc::new(ScheduledJobPostgres::new(Arc::clone(&db_pool))),
+            brawler_stats_repository: Arc::new(BrawlerStatsPostgres::new(Arc::clone(&db_pool))),
+            notification_repository: Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+            push_subscription_repository: Arc::new(PushSubscriptionPostgres::new(db_pool)),
+            mission_automation,
+            email_digests,
+            webhooks,
+        })
+    }
+
+    pub fn spawn(self) {
+        let runner = Arc::new(self);
+
+        tokio::spawn(async move {
+            // Jobs still marked Running were interrupted by the last shutdown
+            match runner.scheduled_job_repository.requeue_running().await {
+                Ok(count) if count > 0 => info!("Re-queued {} interrupted jobs", count),
+                Ok(_) => {}
+                Err(e) => error!("Failed to re-queue interrupted jobs: {}", e),
+            }
+
+            let webhook_runner = Arc::clone(&runner);
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(
+                    webhook_runner.env.poll_interval_secs,
+                ));
+
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = webhook_runner.run_due_webhooks().await {
+                        error!("Webhook delivery tick failed: {}", e);
+                    }
+                }
+            });
+
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(runner.env.poll_interval_secs));
+
+            loop {
+                interval.tick().await;
+                if let Err(e) = runner.ensure_recurring_jobs().await {
+                    error!("Failed to schedule recurring jobs: {}", e);
+                }
+                if let Err(e) = runner.run_due_jobs().await {
+                    error!("Job runner tick failed: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Seeds any recurring job that has nothing queued. Every run schedules the next one, so
+    /// this only kicks in on first start or after a chain was lost.
+    async fn ensure_recurring_jobs(&self) -> Result<()> {
+        for job_type in [
+            ScheduledJobTypes::RefreshBrawlerStats,
+            ScheduledJobTypes::PurgeNotifications,
+            ScheduledJobTypes::SendEmailDigests,
+            ScheduledJobTypes::PurgePushSubscriptions,
+            ScheduledJobTypes::PurgeScheduledJobs,
+        ] {
+            if !self.scheduled_job_repository.has_pending(job_type).await? {
+                self.schedule_next(job_type, chrono::Duration::zero())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn schedule_next(
+        &self,
+        job_type: ScheduledJobTypes,
+        delay: chrono::Duration,
+    ) -> Result<()> {
+        self.scheduled_job_repository
+            .add(AddScheduledJobEntity {
+                job_type: job_type.to_string(),
+                related_id: None,
+                payload: serde_json::json!({}),
+                run_at: Utc::now().naive_utc() + delay,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    fn recurring_interval(&self, job_type: ScheduledJobTypes) -> Option<chrono::Duration> {
+        match job_type {
+            ScheduledJobTypes::RefreshBrawlerStats => Some(chrono::Duration::minutes(
+                self.env.stats_refresh_interval_minutes,
+            )),
+            ScheduledJobTypes::PurgeNotifications => Some(chrono::Duration::days(1)),
+            // Hourly, so a digest goes out soon after its day or week rolls over
+            ScheduledJobTypes::SendEmailDigests => Some(chrono::Duration::hours(1)),
+            ScheduledJobTypes::PurgePushSubscriptions => Some(chrono::Duration::days(1)),
+            ScheduledJobTypes::PurgeScheduledJobs => Some(chrono::Duration::days(1)),
+            _ => None,
+        }
+    }
+
+    async fn run_due_jobs(&self) -> Result<()> {
+        let jobs = self
+            .scheduled_job_repository
+            .claim_due(
+                self.env.batch_size,
+                JobLane::Except(ScheduledJobTypes::DeliverWebhook),
+            )
+            .await?;
+
+        for job in jobs {
+            self.process_job(job).await;
+        }
+
+        Ok(())
+    }
+
+    /// Sends due deliveries a few at a time, so one slow endpoint only ties up its own slot
+    async fn run_due_webhooks(&self) -> Result<()> {
+        let jobs = self
+            .scheduled_job_repository
+            .claim_due(
+                self.env.batch_size,
+                JobLane::Only(ScheduledJobTypes::DeliverWebhook),
+            )
+            .await?;
+
+        futures::stream::iter(jobs)
+            .map(|job| self.process_job(job))
+            .buffer_unordered(self.webhook_concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        Ok(())
+    }
+
+    /// Runs one claimed job and records the outcome. Bookkeeping errors are logged rather than
+    /// returned so one bad row can't strand the rest of the batch in `Running`.
+    async fn process_job(&self, job: ScheduledJobEntity) {
+        let job_id = job.id;
+        let attempts = job.attempts;
+        let job_type = ScheduledJobTypes::from_str(&job.job_type).ok();
+
+        let finished = match self.run_job(job).await {
+            Ok(_) => {
+                if let Err(e) = self.scheduled_job_repository.mark_done(job_id).await {
+                    error!("Failed to mark job {} done: {}", job_id, e);
+                }
+                true
+            }
+            Err(e) => {
+                warn!("Job {} failed on attempt {}: {}", job_id, attempts, e);
+
+                let retry_at = (attempts < self.env.max_attempts)
+                    .then(|| Utc::now().naive_utc() + retry_delay(attempts));
+                let finished = retry_at.is_none();
+
+                if let Err(e) = self
+                    .scheduled_job_repository
+                    .mark_failed(job_id, e.to_string(), retry_at)
+                    .await
+                {
+                    error!("Failed to record failure of job {}: {}", job_id, e);
+                }
+                finished
+            }
+        };
+
+        // Only chain the next run once this one is done for good, so retries don't fork it.
+        // A lost link is re-seeded by `ensure_recurring_jobs` on the next tick.
+        if finished
+            && let Some(job_type) = job_type
+            && let Some(interval) = self.recurring_interval(job_type)
+            && let Err(e) = self.schedule_next(job_type, interval).await
+        {
+            error!("Failed to schedule the next {} job: {}", job_type, e);
+        }
+    }
+
+    async fn run_job(&self, job: ScheduledJobEntity) -> Result<()> {
+        let job_type = ScheduledJobTypes::from_str(&job.job_type)?;
+
+        let related_id = || {
+            job.related_id
+                .ok_or_else(|| anyhow::anyhow!("Job {} has no related_id", job.id))
+        };
+
+        match job_type {
+            ScheduledJobTypes::MissionReminder => {
+                self.mission_automation.send_reminder(related_id()?).await
+            }
+            ScheduledJobTypes::MissionAutoStart => {
+                self.mission_automation.auto_start(related_id()?).await
+            }
+            ScheduledJobTypes::MissionAutoExpire => {
+                self.mission_automation.auto_expire(related_id()?).await
+            }
+            ScheduledJobTypes::RefreshBrawlerStats => self.brawler_stats_repository.refresh().await,
+            ScheduledJobTypes::PurgeNotifications => self.purge_notifications().await,
+            ScheduledJobTypes::SendEmailDigests => self.email_digests.send_due().await,
+            ScheduledJobTypes::PurgePushSubscriptions => self.purge_push_subscriptions().await,
+            ScheduledJobTypes::PurgeScheduledJobs => self.purge_scheduled_jobs().await,
+            ScheduledJobTypes::DeliverWebhook => {
+                self.webhooks
+                    .deliver(related_id()?, job.attempts >= self.env.max_attempts)
+                    .await
+            }
+        }
+    }
+
+    async fn purge_notifications(&self) -> Result<()> {
+        let cutoff =
+            Utc::now().naive_utc() - chrono::Duration::days(self.env.notification_retention_days);
+        let purged = self
+            .notification_repository
+            .purge_read_before(cutoff)
+            .await?;
+        if purged > 0 {
+            info!("Purged {} read notifications older than {}", purged, cutoff);
+        }
+
+        Ok(())
+    }
+
+    /// Subscriptions whose `expirationTime` has passed; ones the push service drops are
+    /// pruned as soon as a push bounces
+    async fn purge_push_subscriptions(&self) -> Result<()> {
+        let purged = self
+            .push_subscription_repository
+            .purge_expired(Utc::now().naive_utc())
+            .await?;
+        if purged > 0 {
+            info!("Purged {} expired push subscriptions", purged);
+        }
+
+        Ok(())
+    }
+
+    async fn purge_scheduled_jobs(&self) -> Result<()> {
+        let cutoff = Utc::now().naive_utc() - chrono::Duration::days(self.env.job_retention_days);
+        let purged = self
+            .scheduled_job_repository
+            .purge_finished_before(cutoff)
+            .await?;
+        if purged > 0 {
+            info!("Purged {} finished jobs older than {}", purged, cutoff);
+        }
+
+        Ok(())
+    }
+}
+
+/// Exponential backoff before retrying a job that failed on its `attempts`-th try:
+/// 1, 2, 4, 8... minutes, capped at 1024
+fn retry_delay(attempts: i32) -> chrono::Duration {
+    chrono::Duration::minutes(1 << (attempts - 1).clamp(0, 10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_from_one_minute() {
+        let delays: Vec<i64> = (1..=5)
+            .map(|attempts| retry_delay(attempts).num_minutes())
+            .collect();
+
+        assert_eq!(delays, vec![1, 2, 4, 8, 16]);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(retry_delay(11).num_minutes(), 1024);
+        assert_eq!(retry_delay(50).num_minutes(), 1024);
+    }
+
+    #[test]
+    fn backoff_never_goes_below_a_minute() {
+        assert_eq!(retry_delay(0).num_minutes(), 1);
+        assert_eq!(retry_delay(-3).num_minutes(), 1);
+    }
+}
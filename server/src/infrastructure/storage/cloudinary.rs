@@ -0,0 +1,22 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{domain::repositories::blob_storage::BlobStorage, infrastructure::cloudinary};
+
+/// Stores blobs as Cloudinary `raw` resources, using the key as the public id
+pub struct CloudinaryStorage;
+
+#[async_trait]
+impl BlobStorage for CloudinaryStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> Result<()> {
+        cloudinary::upload_raw(bytes, key).await
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        cloudinary::download_raw(key).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        cloudinary::destroy(key, "raw").await
+    }
+}
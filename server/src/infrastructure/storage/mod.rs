@@ -0,0 +1,21 @@
+pub mod cloudinary;
+pub mod local;
+
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::{
+    config::{config_loader::get_storage_env, config_model::StorageBackends},
+    domain::repositories::blob_storage::BlobStorage,
+};
+
+/// The backend selected by `STORAGE_BACKEND`
+pub fn build_blob_storage() -> Result<Arc<dyn BlobStorage>> {
+    let storage_env = get_storage_env()?;
+
+    let storage: Arc<dyn BlobStorage> = match storage_env.backend {
+        StorageBackends::Local => Arc::new(local::LocalStorage::new(storage_env.local_dir)),
+        StorageBackends::Cloudinary => Arc::new(cloudinary::CloudinaryStorage),
+    };
+    Ok(storage)
+}
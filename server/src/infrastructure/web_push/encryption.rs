@@ -0,0 +1,211 @@
+use aes_gcm::{
+    Aes128Gcm, Key, KeyInit, Nonce,
+    aead::{Aead, Payload},
+};
+use anyhow::{Result, anyhow};
+use hkdf::Hkdf;
+use p256::{PublicKey, SecretKey, elliptic_curve::sec1::ToEncodedPoint};
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+
+/// Record size written to the header; the whole message always fits in one record
+const RECORD_SIZE: u32 = 4096;
+const SALT_LEN: usize = 16;
+/// An uncompressed P-256 point
+const PUBLIC_KEY_LEN: usize = 65;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = SALT_LEN + 4 + 1 + PUBLIC_KEY_LEN;
+/// Marks the last (and only) record; padding zeros would follow it
+const LAST_RECORD_DELIMITER: u8 = 0x02;
+
+/// Push services only promise to accept 4096-byte bodies, header included
+pub const MAX_PAYLOAD_LEN: usize = 4096 - HEADER_LEN - TAG_LEN - 1;
+
+/// Encrypts `payload` for one browser as described in RFC 8291, using the
+/// `aes128gcm` content coding of RFC 8188 with a fresh key pair and salt per message
+pub fn encrypt(ua_public: &[u8], auth_secret: &[u8], payload: &[u8]) -> Result<Vec<u8>> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(anyhow!(
+            "Push payload is {} bytes, the limit is {}",
+            payload.len(),
+            MAX_PAYLOAD_LEN
+        ));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    encrypt_with(
+        &SecretKey::random(&mut OsRng),
+        &salt,
+        ua_public,
+        auth_secret,
+        payload,
+    )
+}
+
+/// `encrypt` with the sender key and salt supplied, so known test vectors can be replayed
+fn encrypt_with(
+    as_secret: &SecretKey,
+    salt: &[u8; SALT_LEN],
+    ua_public: &[u8],
+    auth_secret: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>> {
+    let ua_key =
+        PublicKey::from_sec1_bytes(ua_public).map_err(|_| anyhow!("Invalid p256dh key"))?;
+    let as_public = as_secret.public_key().to_encoded_point(false);
+    let shared_secret =
+        p256::ecdh::diffie_hellman(as_secret.to_nonzero_scalar(), ua_key.as_affine());
+
+    let (cek, nonce) = derive_key_and_nonce(
+        shared_secret.raw_secret_bytes(),
+        auth_secret,
+        ua_public,
+        as_public.as_bytes(),
+        salt,
+    )?;
+
+    let mut plaintext = payload.to_vec();
+    plaintext.push(LAST_RECORD_DELIMITER);
+    let ciphertext = Aes128Gcm::new(Key::<Aes128Gcm>::from_slice(&cek))
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: &[],
+            },
+        )
+        .map_err(|_| anyhow!("Failed to encrypt push payload"))?;
+
+    let mut body = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+    body.extend_from_slice(salt);
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(PUBLIC_KEY_LEN as u8);
+    body.extend_from_slice(as_public.as_bytes());
+    body.extend_from_slice(&ciphertext);
+
+    Ok(body)
+}
+
+/// The browser's side of `encrypt`; only the mock push service needs it
+pub fn decrypt(ua_secret: &SecretKey, auth_secret: &[u8], body: &[u8]) -> Result<Vec<u8>> {
+    if body.len() < HEADER_LEN + TAG_LEN {
+        return Err(anyhow!("Push body is too short"));
+    }
+    let salt = &body[..SALT_LEN];
+    let key_id_len = body[SALT_LEN + 4] as usize;
+    if key_id_len != PUBLIC_KEY_LEN {
+        return Err(anyhow!("Unexpected key id length {}", key_id_len));
+    }
+    let as_public = &body[SALT_LEN + 5..HEADER_LEN];
+    let ciphertext = &body[HEADER_LEN..];
+
+    let as_key =
+        PublicKey::from_sec1_bytes(as_public).map_err(|_| anyhow!("Invalid sender key"))?;
+    let shared_secret =
+        p256::ecdh::diffie_hellman(ua_secret.to_nonzero_scalar(), as_key.as_affine());
+    let ua_public = ua_secret.public_key().to_encoded_point(false);
+
+    let (cek, nonce) = derive_key_and_nonce(
+        shared_secret.raw_secret_bytes(),
+        auth_secret,
+        ua_public.as_bytes(),
+        as_public,
+        salt,
+    )?;
+
+    let mut plaintext = Aes128Gcm::new(Key::<Aes128Gcm>::from_slice(&cek))
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: ciphertext,
+                aad: &[],
+            },
+        )
+        .map_err(|_| anyhow!("Failed to decrypt push payload"))?;
+
+    while plaintext.last() == Some(&0) {
+        plaintext.pop();
+    }
+    if plaintext.pop() != Some(LAST_RECORD_DELIMITER) {
+        return Err(anyhow!("Push payload is missing its record delimiter"));
+    }
+
+    Ok(plaintext)
+}
+
+fn derive_key_and_nonce(
+    ecdh_secret: &[u8],
+    auth_secret: &[u8],
+    ua_public: &[u8],
+    as_public: &[u8],
+    salt: &[u8],
+) -> Result<([u8; 16], [u8; 12])> {
+    let mut key_info = b"WebPush: info\0".to_vec();
+    key_info.extend_from_slice(ua_public);
+    key_info.extend_from_slice(as_public);
+
+    let mut ikm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(auth_secret), ecdh_secret)
+        .expand(&key_info, &mut ikm)
+        .map_err(|_| anyhow!("Failed to derive push key"))?;
+
+    let prk = Hkdf::<Sha256>::new(Some(salt), &ikm);
+    let mut cek = [0u8; 16];
+    prk.expand(b"Content-Encoding: aes128gcm\0", &mut cek)
+        .map_err(|_| anyhow!("Failed to derive push key"))?;
+    let mut nonce = [0u8; 12];
+    prk.expand(b"Content-Encoding: nonce\0", &mut nonce)
+        .map_err(|_| anyhow!("Failed to derive push nonce"))?;
+
+    Ok((cek, nonce))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::web_push::vapid::decode_base64url;
+
+    // RFC 8291, Appendix A
+    const PLAINTEXT: &[u8] = b"When I grow up, I want to be a watermelon";
+    const AS_PRIVATE: &str = "yfWPiYE-n46HLnH0KqZOF1fJJU3MYrct3AELtAQ-oRw";
+    const UA_PRIVATE: &str = "q1dXpw3UpT5VOmu_cf_v6ih07Aems3njxI-JWgLcM94";
+    const UA_PUBLIC: &str =
+        "BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4";
+    const SALT: &str = "DGv6ra1nlYgDCS1FRnbzlw";
+    const AUTH_SECRET: &str = "BTBZMqHH6r4Tts7J_aSIgg";
+    const MESSAGE: &str = "DGv6ra1nlYgDCS1FRnbzlwAAEABBBP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A_yl95bQpu6cVPTpK4Mqgkf1CXztLVBSt2Ks3oZwbuwXPXLWyouBWLVWGNWQexSgSxsj_Qulcy4a-fN";
+
+    fn secret_key(value: &str) -> SecretKey {
+        SecretKey::from_slice(&decode_base64url(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn encrypts_the_rfc_8291_test_vector() {
+        let salt: [u8; SALT_LEN] = decode_base64url(SALT).unwrap().try_into().unwrap();
+
+        let body = encrypt_with(
+            &secret_key(AS_PRIVATE),
+            &salt,
+            &decode_base64url(UA_PUBLIC).unwrap(),
+            &decode_base64url(AUTH_SECRET).unwrap(),
+            PLAINTEXT,
+        )
+        .unwrap();
+
+        assert_eq!(body, decode_base64url(MESSAGE).unwrap());
+    }
+
+    #[test]
+    fn decrypts_the_rfc_8291_test_vector() {
+        let plaintext = decrypt(
+            &secret_key(UA_PRIVATE),
+            &decode_base64url(AUTH_SECRET).unwrap(),
+            &decode_base64url(MESSAGE).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(plaintext, PLAINTEXT);
+    }
+}
@@ -0,0 +1,36 @@
+pub mod encryption;
+pub mod sender;
+pub mod vapid;
+
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    config::{config_loader::get_web_push_env, stage::Stage},
+    domain::repositories::push_sender::PushSender,
+};
+
+/// `None` while `VAPID_PRIVATE_KEY` is unset, which turns Web Push off
+pub fn build_push_sender(stage: &Stage) -> Result<Option<Arc<dyn PushSender>>> {
+    let web_push_env = get_web_push_env()?;
+
+    let Some(private_key) = web_push_env.vapid_private_key else {
+        if *stage == Stage::Local {
+            info!(
+                "Web Push is off; set VAPID_PRIVATE_KEY={} to turn it on",
+                vapid::VapidKey::generate().private_key()
+            );
+        } else {
+            info!("Web Push is off; set VAPID_PRIVATE_KEY to turn it on");
+        }
+        return Ok(None);
+    };
+
+    let sender = sender::WebPushSender::new(
+        vapid::VapidKey::from_base64url(&private_key)?,
+        web_push_env.vapid_subject,
+        web_push_env.ttl_secs,
+    );
+    Ok(Some(Arc::new(sender)))
+}
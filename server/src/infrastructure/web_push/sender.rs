@@ -0,0 +1,87 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use reqwest::{StatusCode, Url, header};
+use std::time::Duration;
+
+use super::{encryption, vapid::VapidKey};
+use crate::{
+    domain::{
+        entities::push_subscriptions::PushSubscriptionEntity,
+        repositories::push_sender::PushSender, value_objects::web_push_model::PushOutcomes,
+    },
+    infrastructure::webhooks::address,
+};
+
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Sends encrypted messages straight to each browser's push service
+pub struct WebPushSender {
+    vapid_key: VapidKey,
+    subject: String,
+    ttl_secs: u32,
+}
+
+impl WebPushSender {
+    pub fn new(vapid_key: VapidKey, subject: String, ttl_secs: u32) -> Self {
+        Self {
+            vapid_key,
+            subject,
+            ttl_secs,
+        }
+    }
+}
+
+#[async_trait]
+impl PushSender for WebPushSender {
+    fn public_key(&self) -> String {
+        self.vapid_key.public_key().to_string()
+    }
+
+    async fn send(
+        &self,
+        subscription: &PushSubscriptionEntity,
+        payload: &[u8],
+    ) -> Result<PushOutcomes> {
+        let body = encryption::encrypt(
+            &super::vapid::decode_base64url(&subscription.p256dh)?,
+            &super::vapid::decode_base64url(&subscription.auth)?,
+            payload,
+        )?;
+
+        // The endpoint comes from the browser, so it gets the same treatment as a webhook url:
+        // checked on every send and the connection pinned to the checked address
+        let url = Url::parse(&subscription.endpoint)?;
+        let addr = address::resolve_public(&url).await?;
+        let host = url.host_str().unwrap_or_default().to_string();
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .redirect(reqwest::redirect::Policy::none())
+            .resolve(&host, addr)
+            .build()?;
+
+        let response = client
+            .post(url)
+            .header(
+                header::AUTHORIZATION,
+                self.vapid_key
+                    .authorization(&subscription.endpoint, &self.subject)?,
+            )
+            .header(header::CONTENT_ENCODING, "aes128gcm")
+            .header(header::CONTENT_TYPE, "application/octet-stream")
+            .header("TTL", self.ttl_secs.to_string())
+            .header("Urgency", "high")
+            .body(body)
+            .send()
+            .await?;
+
+        match response.status() {
+            status if status.is_success() => Ok(PushOutcomes::Delivered),
+            StatusCode::NOT_FOUND | StatusCode::GONE => Ok(PushOutcomes::Gone),
+            status => Err(anyhow!(
+                "Push service answered {}: {}",
+                status,
+                response.text().await.unwrap_or_default()
+            )),
+        }
+    }
+}
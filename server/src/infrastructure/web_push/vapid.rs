@@ -0,0 +1,179 @@
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::Utc;
+use p256::ecdsa::{
+    Signature, SigningKey, VerifyingKey,
+    signature::{Signer, Verifier},
+};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+/// Push services reject tokens valid for more than a day
+const TOKEN_TTL_HOURS: i64 = 12;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VapidClaims {
+    aud: String,
+    exp: i64,
+    sub: String,
+}
+
+/// The application server key of RFC 8292, used to sign a JWT for every push
+pub struct VapidKey {
+    signing_key: SigningKey,
+    public_key: String,
+}
+
+impl VapidKey {
+    /// `private_key` is the raw 32-byte scalar, base64url, as printed by most VAPID key generators
+    pub fn from_base64url(private_key: &str) -> Result<Self> {
+        let bytes = decode_base64url(private_key)?;
+        let signing_key = SigningKey::from_slice(&bytes)
+            .map_err(|_| anyhow!("VAPID_PRIVATE_KEY is not a P-256 private key"))?;
+        Ok(Self::from_signing_key(signing_key))
+    }
+
+    pub fn generate() -> Self {
+        Self::from_signing_key(SigningKey::random(&mut OsRng))
+    }
+
+    fn from_signing_key(signing_key: SigningKey) -> Self {
+        let public_key = URL_SAFE_NO_PAD.encode(
+            VerifyingKey::from(&signing_key)
+                .to_encoded_point(false)
+                .as_bytes(),
+        );
+        Self {
+            signing_key,
+            public_key,
+        }
+    }
+
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    pub fn private_key(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.signing_key.to_bytes())
+    }
+
+    /// The `Authorization` header for a push to `endpoint`
+    pub fn authorization(&self, endpoint: &str, subject: &str) -> Result<String> {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"typ":"JWT","alg":"ES256"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&VapidClaims {
+            aud: audience(endpoint)?,
+            exp: (Utc::now() + chrono::Duration::hours(TOKEN_TTL_HOURS)).timestamp(),
+            sub: subject.to_string(),
+        })?);
+        let signing_input = format!("{}.{}", header, claims);
+        let signature: Signature = self.signing_key.sign(signing_input.as_bytes());
+
+        Ok(format!(
+            "vapid t={}.{}, k={}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes()),
+            self.public_key
+        ))
+    }
+}
+
+/// Checks an `Authorization: vapid t=..., k=...` header the way a push service would.
+/// Returns the public key it was signed with
+pub fn verify(authorization: &str, endpoint: &str) -> Result<String> {
+    let params = authorization
+        .strip_prefix("vapid ")
+        .ok_or_else(|| anyhow!("Authorization must use the vapid scheme"))?;
+
+    let mut token = None;
+    let mut key = None;
+    for param in params.split(',') {
+        match param.trim().split_once('=') {
+            Some(("t", value)) => token = Some(value),
+            Some(("k", value)) => key = Some(value),
+            _ => {}
+        }
+    }
+    let token = token.ok_or_else(|| anyhow!("Missing VAPID token"))?;
+    let key = key.ok_or_else(|| anyhow!("Missing VAPID key"))?;
+
+    let (signing_input, signature) = token
+        .rsplit_once('.')
+        .ok_or_else(|| anyhow!("Malformed VAPID token"))?;
+    let verifying_key = VerifyingKey::from_sec1_bytes(&decode_base64url(key)?)
+        .map_err(|_| anyhow!("Invalid VAPID key"))?;
+    let signature = Signature::from_slice(&decode_base64url(signature)?)
+        .map_err(|_| anyhow!("Invalid VAPID signature"))?;
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|_| anyhow!("VAPID signature does not match"))?;
+
+    let claims = signing_input
+        .split_once('.')
+        .map(|(_, claims)| claims)
+        .ok_or_else(|| anyhow!("Malformed VAPID token"))?;
+    let claims: VapidClaims = serde_json::from_slice(&decode_base64url(claims)?)?;
+    if claims.aud != audience(endpoint)? {
+        return Err(anyhow!("VAPID audience {} does not match", claims.aud));
+    }
+    if claims.exp < Utc::now().timestamp() {
+        return Err(anyhow!("VAPID token has expired"));
+    }
+
+    Ok(key.to_string())
+}
+
+/// Browsers hand out keys with or without padding
+pub fn decode_base64url(value: &str) -> Result<Vec<u8>> {
+    Ok(URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))?)
+}
+
+/// The origin of the push service, which is what the token is scoped to
+fn audience(endpoint: &str) -> Result<String> {
+    Ok(reqwest::Url::parse(endpoint)?
+        .origin()
+        .ascii_serialization())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDPOINT: &str = "https://push.example.net/send/abc123";
+    const SUBJECT: &str = "mailto:ops@example.com";
+
+    #[test]
+    fn signed_authorization_verifies() {
+        let key = VapidKey::generate();
+        let authorization = key.authorization(ENDPOINT, SUBJECT).unwrap();
+
+        assert_eq!(verify(&authorization, ENDPOINT).unwrap(), key.public_key());
+    }
+
+    #[test]
+    fn key_survives_a_base64url_round_trip() {
+        let key = VapidKey::generate();
+        let restored = VapidKey::from_base64url(&key.private_key()).unwrap();
+
+        assert_eq!(restored.public_key(), key.public_key());
+    }
+
+    #[test]
+    fn rejects_another_origin() {
+        let authorization = VapidKey::generate()
+            .authorization(ENDPOINT, SUBJECT)
+            .unwrap();
+
+        assert!(verify(&authorization, "https://other.example.net/send/abc123").is_err());
+    }
+
+    #[test]
+    fn rejects_a_swapped_key() {
+        let authorization = VapidKey::generate()
+            .authorization(ENDPOINT, SUBJECT)
+            .unwrap();
+        let (token, _) = authorization.split_once(", k=").unwrap();
+        let forged = format!("{}, k={}", token, VapidKey::generate().public_key());
+
+        assert!(verify(&forged, ENDPOINT).is_err());
+    }
+}
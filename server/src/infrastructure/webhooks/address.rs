@@ -0,0 +1,120 @@
+use anyhow::{Result, anyhow};
+use reqwest::Url;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use crate::config::{config_loader::get_stage, stage::Stage};
+
+/// Resolves the url's host and returns the address to connect to. Every address the name
+/// resolves to must be publicly routable, so a webhook or push endpoint can't be pointed at
+/// loopback, the private network or cloud metadata endpoints. The Local stage skips the check so
+/// receivers on the developer's machine work.
+pub async fn resolve_public(url: &Url) -> Result<SocketAddr> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("Url must have a host"))?;
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| anyhow!("Url must have a port"))?;
+
+    // IPv6 literals come back from host_str() in brackets
+    let addrs: Vec<SocketAddr> = match host
+        .trim_matches(|c| c == '[' || c == ']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) => vec![SocketAddr::new(ip, port)],
+        Err(_) => tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|_| anyhow!("Host {} could not be resolved", host))?
+            .collect(),
+    };
+
+    let first = *addrs
+        .first()
+        .ok_or_else(|| anyhow!("Host {} could not be resolved", host))?;
+    if get_stage() != Stage::Local && addrs.iter().any(|addr| !is_public(addr.ip())) {
+        return Err(anyhow!("Url must point to a public address"));
+    }
+
+    Ok(first)
+}
+
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_v4(mapped),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "This network"
+        || a == 0
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (a == 100 && (b & 0xc0) == 64)
+        // IETF protocol assignments, 192.0.0.0/24
+        || (a == 192 && b == 0 && c == 0)
+        // Benchmarking, 198.18.0.0/15
+        || (a == 198 && (b & 0xfe) == 18)
+        // Reserved, 240.0.0.0/4
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local, fc00::/7
+        || (segments[0] & 0xfe00) == 0xfc00
+        // Link-local, fe80::/10
+        || (segments[0] & 0xffc0) == 0xfe80
+        // Documentation, 2001:db8::/32
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        // NAT64 and 6to4 embed an IPv4 address that may be private
+        || (segments[0] == 0x0064 && segments[1] == 0xff9b)
+        || segments[0] == 0x2002
+        // Discard-only, 100::/64
+        || (segments[0] == 0x0100 && segments[1..4] == [0, 0, 0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_internal_addresses() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a00:1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{} should be rejected", ip);
+        }
+    }
+
+    #[test]
+    fn accepts_public_addresses() {
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111"] {
+            assert!(is_public(ip.parse().unwrap()), "{} should be accepted", ip);
+        }
+    }
+}
@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use super::manager::ConnectionManager;
+use crate::application::use_cases::privacy::PrivacyUseCase;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WSMessage {
@@ -19,6 +20,12 @@ pub struct WSMessage {
     pub data: serde_json::Value,
 }
 
+/// Client frame body for `typing_started` / `typing_stopped`
+#[derive(Debug, Deserialize)]
+struct TypingModel {
+    receiver_id: i32,
+}
+
 /// WebSocket handler for mission chat (Room-based)
 pub async fn ws_handler(
     ws: WebSocketUpgrade,
@@ -43,15 +50,8 @@ async fn handle_socket(socket: WebSocket, mission_id: i32, manager: Arc<Connecti
 
     let manager_clone = manager.clone();
     let mut recv_task = tokio::spawn(async move {
-        while let Some(msg) = receiver.next().await {
-            if let Ok(Message::Text(text)) = msg {
-                if let Ok(ws_msg) = serde_json::from_str::<WSMessage>(&text) {
-                    match ws_msg.msg_type.as_str() {
-                        "ping" => {}
-                        _ => {}
-                    }
-                }
-            }
+        while receiver.next().await.is_some() {
+            // Client frames (e.g. "ping") only keep the room connection alive
         }
         manager_clone.unsubscribe(mission_id).await;
     });
@@ -66,21 +66,35 @@ async fn handle_socket(socket: WebSocket, mission_id: i32, manager: Arc<Connecti
 pub async fn global_ws_handler(
     ws: WebSocketUpgrade,
     Extension(user_id): Extension<i32>,
-    State(manager): State<Arc<ConnectionManager>>,
+    State((manager, privacy_use_case)): State<(Arc<ConnectionManager>, Arc<PrivacyUseCase>)>,
 ) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_global_socket(socket, user_id, manager))
+    ws.on_upgrade(move |socket| handle_global_socket(socket, user_id, manager, privacy_use_case))
 }
 
-async fn handle_global_socket(socket: WebSocket, user_id: i32, manager: Arc<ConnectionManager>) {
+async fn handle_global_socket(
+    socket: WebSocket,
+    user_id: i32,
+    manager: Arc<ConnectionManager>,
+    privacy_use_case: Arc<PrivacyUseCase>,
+) {
     let (mut sender, mut receiver) = socket.split();
     let mut rx = manager.subscribe_user(user_id).await;
 
+    // Brawlers this user blocked don't get to see them come and go
+    let blocked_ids = privacy_use_case
+        .list_blocked(user_id)
+        .await
+        .unwrap_or_default();
+
     // Broadcast online status
     manager
-        .broadcast_all(WSMessage {
-            msg_type: "agent_online".to_string(),
-            data: serde_json::json!({ "user_id": user_id }),
-        })
+        .broadcast_all_except(
+            WSMessage {
+                msg_type: "agent_online".to_string(),
+                data: serde_json::json!({ "user_id": user_id }),
+            },
+            &blocked_ids,
+        )
         .await;
 
     let mut send_task = tokio::spawn(async move {
@@ -94,17 +108,56 @@ async fn handle_global_socket(socket: WebSocket, user_id: i32, manager: Arc<Conn
 
     let manager_clone = manager.clone();
     let mut recv_task = tokio::spawn(async move {
-        while let Some(_) = receiver.next().await {
-            // Keep connection alive, can handle client-to-server global msgs here
+        while let Some(frame) = receiver.next().await {
+            // Anything that isn't a known JSON message (e.g. "ping") only keeps the connection alive
+            let Ok(Message::Text(text)) = frame else {
+                continue;
+            };
+            let Ok(msg) = serde_json::from_str::<WSMessage>(&text) else {
+                continue;
+            };
+
+            let started = match msg.msg_type.as_str() {
+                "typing_started" => true,
+                "typing_stopped" => false,
+                _ => continue,
+            };
+            let Ok(typing) = serde_json::from_value::<TypingModel>(msg.data) else {
+                continue;
+            };
+            // Typing follows the same rules as sending a message, checked per signal so a block
+            // or privacy change made mid-session applies right away. Stopping is always allowed,
+            // so an indicator that was already showing can be cleared.
+            if started
+                && privacy_use_case
+                    .ensure_can_message(user_id, typing.receiver_id)
+                    .await
+                    .is_err()
+            {
+                continue;
+            }
+            manager_clone
+                .relay_typing(user_id, typing.receiver_id, started)
+                .await;
         }
+        manager_clone.clear_typing(user_id).await;
         manager_clone.unsubscribe_user(user_id).await;
 
+        // Blocks may have changed while the socket was open
+        let blocked_ids = privacy_use_case
+            .list_blocked(user_id)
+            .await
+            .unwrap_or(blocked_ids);
+
         // Broadcast offline status
         manager_clone
-            .broadcast_all(WSMessage {
-                msg_type: "agent_offline".to_string(),
-                data: serde_json::json!({ "user_id": user_id }),
-            })
+            .broadcast_all_except(
+                WSMessage {
+                    msg_type: "agent_offline".to_string(),
+                    data: serde_json::json!({ "user_id": user_id }),
+                },
+                &blocked_ids,
+            )
             .await;
     });
 
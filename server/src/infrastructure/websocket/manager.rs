@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, broadcast};
 
 use super::handler::WSMessage;
 
+/// Repeated `typing_started` signals to the same peer inside this window are dropped
+const TYPING_THROTTLE: Duration = Duration::from_secs(3);
+
 /// Manages WebSocket connections and broadcasts for each mission and user
 #[derive(Clone)]
 pub struct ConnectionManager {
@@ -11,6 +15,8 @@ pub struct ConnectionManager {
     channels: Arc<RwLock<HashMap<i32, broadcast::Sender<WSMessage>>>>,
     /// Map of user_id -> broadcast channel (for global notifications)
     user_channels: Arc<RwLock<HashMap<i32, broadcast::Sender<WSMessage>>>>,
+    /// (typist_id, peer_id) -> when `typing_started` was last relayed. Memory only, never persisted.
+    typing: Arc<RwLock<HashMap<(i32, i32), Instant>>>,
 }
 
 impl ConnectionManager {
@@ -18,6 +24,7 @@ impl ConnectionManager {
         Self {
             channels: Arc::new(RwLock::new(HashMap::new())),
             user_channels: Arc::new(RwLock::new(HashMap::new())),
+            typing: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -40,10 +47,10 @@ impl ConnectionManager {
     pub async fn unsubscribe(&self, mission_id: i32) {
         let mut channels = self.channels.write().await;
 
-        if let Some(sender) = channels.get(&mission_id) {
-            if sender.receiver_count() == 0 {
-                channels.remove(&mission_id);
-            }
+        if let Some(sender) = channels.get(&mission_id)
+            && sender.receiver_count() == 0
+        {
+            channels.remove(&mission_id);
         }
     }
 
@@ -75,10 +82,10 @@ impl ConnectionManager {
     pub async fn unsubscribe_user(&self, user_id: i32) {
         let mut user_channels = self.user_channels.write().await;
 
-        if let Some(sender) = user_channels.get(&user_id) {
-            if sender.receiver_count() == 0 {
-                user_channels.remove(&user_id);
-            }
+        if let Some(sender) = user_channels.get(&user_id)
+            && sender.receiver_count() == 0
+        {
+            user_channels.remove(&user_id);
         }
     }
 
@@ -100,6 +107,84 @@ impl ConnectionManager {
         }
     }
 
+    /// Broadcast to every user's global channel except the given users
+    pub async fn broadcast_all_except(&self, message: WSMessage, excluded_ids: &[i32]) {
+        let user_channels = self.user_channels.read().await;
+
+        for (user_id, sender) in user_channels.iter() {
+            if !excluded_ids.contains(user_id) {
+                let _ = sender.send(message.clone());
+            }
+        }
+    }
+
+    /// Relays an ephemeral typing signal to `to_id`. Returns whether it was sent
+    /// (throttled `typing_started` and unmatched `typing_stopped` are dropped).
+    /// Callers check that `to_id` may be messaged at all; this only tracks signals to peers
+    /// that are online, and forgets any older than `TYPING_THROTTLE`, so the map stays bounded
+    /// by live conversations. A peer that hears nothing more within the window should treat
+    /// the indicator as stale.
+    pub async fn relay_typing(&self, from_id: i32, to_id: i32, started: bool) -> bool {
+        if started && (from_id == to_id || !self.is_online(to_id).await) {
+            return false;
+        }
+
+        {
+            let mut typing = self.typing.write().await;
+            let now = Instant::now();
+
+            if started {
+                typing.retain(|_, last| now.duration_since(*last) < TYPING_THROTTLE);
+                if typing.contains_key(&(from_id, to_id)) {
+                    return false;
+                }
+                typing.insert((from_id, to_id), now);
+            } else if typing.remove(&(from_id, to_id)).is_none() {
+                return false;
+            }
+        }
+
+        self.notify_user(
+            to_id,
+            WSMessage {
+                msg_type: if started {
+                    "typing_started"
+                } else {
+                    "typing_stopped"
+                }
+                .to_string(),
+                data: serde_json::json!({ "user_id": from_id }),
+            },
+        )
+        .await;
+
+        true
+    }
+
+    /// Stops every typing indicator a user left running, e.g. when they disconnect
+    pub async fn clear_typing(&self, from_id: i32) {
+        let peer_ids: Vec<i32> = {
+            let typing = self.typing.read().await;
+            typing
+                .keys()
+                .filter(|(typist_id, _)| *typist_id == from_id)
+                .map(|(_, peer_id)| *peer_id)
+                .collect()
+        };
+
+        for peer_id in peer_ids {
+            self.relay_typing(from_id, peer_id, false).await;
+        }
+    }
+
+    /// Whether the user has at least one `/ws/global` socket open
+    pub async fn is_online(&self, user_id: i32) -> bool {
+        let user_channels = self.user_channels.read().await;
+        user_channels
+            .get(&user_id)
+            .is_some_and(|sender| sender.receiver_count() > 0)
+    }
+
     pub async fn get_online_users(&self) -> Vec<i32> {
         let user_channels = self.user_channels.read().await;
         user_channels.keys().copied().collect()
@@ -111,3 +196,32 @@ impl Default for ConnectionManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn typing_is_throttled_and_stopped_once() {
+        let manager = ConnectionManager::new();
+        let mut rx = manager.subscribe_user(2).await;
+
+        assert!(manager.relay_typing(1, 2, true).await);
+        assert!(!manager.relay_typing(1, 2, true).await);
+        assert!(manager.relay_typing(1, 2, false).await);
+        assert!(!manager.relay_typing(1, 2, false).await);
+
+        assert_eq!(rx.recv().await.unwrap().msg_type, "typing_started");
+        assert_eq!(rx.recv().await.unwrap().msg_type, "typing_stopped");
+    }
+
+    #[tokio::test]
+    async fn typing_to_offline_peers_is_not_tracked() {
+        let manager = ConnectionManager::new();
+
+        for peer_id in 2..100 {
+            assert!(!manager.relay_typing(1, peer_id, true).await);
+        }
+        assert!(manager.typing.read().await.is_empty());
+    }
+}